@@ -65,6 +65,7 @@ async fn main() -> anyhow::Result<()> {
         .route("/api/auth/register", post(routes::auth::register))
         .route("/api/auth/login", post(routes::auth::login))
         .route("/api/auth/refresh", post(routes::auth::refresh))
+        .route("/api/auth/logout", post(routes::auth::logout))
         // User routes
         .route("/api/users/me", get(routes::users::get_me))
         .route("/api/users/:id", get(routes::users::get_user))
@@ -73,6 +74,10 @@ async fn main() -> anyhow::Result<()> {
         .route("/api/cosmetics/:id", get(routes::cosmetics::get_cosmetic))
         .route("/api/cosmetics/owned", get(routes::cosmetics::get_owned))
         .route("/api/cosmetics/:id/equip", post(routes::cosmetics::equip))
+        .route(
+            "/api/users/:id/cosmetics/equipped",
+            get(routes::cosmetics::get_equipped_for_user),
+        )
         // Friends routes
         .route("/api/friends", get(routes::friends::list_friends))
         .route("/api/friends/requests", get(routes::friends::list_requests))
@@ -1,8 +1,9 @@
 use crate::models::User;
-use crate::services::{hash_password, verify_password, create_token};
+use crate::services::{create_access_token, create_refresh_token, hash_password, verify_password, verify_token};
 use crate::AppState;
 use axum::{extract::State, http::StatusCode, Json};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -25,6 +26,11 @@ pub struct RefreshRequest {
     pub refresh_token: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct LogoutRequest {
+    pub refresh_token: String,
+}
+
 #[derive(Debug, Serialize)]
 pub struct AuthResponse {
     pub access_token: String,
@@ -39,6 +45,52 @@ pub struct UserInfo {
     pub minecraft_uuid: String,
 }
 
+/// How long a minted refresh token is valid for before it must be rotated
+/// via [`refresh`] - matches the TTL [`create_refresh_token`] embeds in the
+/// token itself, so the stored row and the JWT expire together.
+const REFRESH_TOKEN_TTL: chrono::Duration = chrono::Duration::days(30);
+
+/// Hex-encoded SHA-256 of a refresh token, so the `refresh_tokens` table
+/// never stores the bearer-usable token itself - only enough to recognize
+/// it again on refresh/logout.
+fn hash_refresh_token(token: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(token.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Mint a fresh access/refresh token pair for `user` and persist the
+/// refresh token's hash so a later [`refresh`] call can recognize and
+/// rotate it (and [`logout`] can revoke it early).
+async fn issue_tokens(
+    state: &AppState,
+    user: &User,
+) -> Result<(String, String), (StatusCode, String)> {
+    let access_token = create_access_token(&user.id, &user.username, &state.jwt_secret)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let refresh_token = create_refresh_token(&user.id, &user.username, &state.jwt_secret)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let token_hash = hash_refresh_token(&refresh_token);
+    let expires_at = (chrono::Utc::now() + REFRESH_TOKEN_TTL).to_rfc3339();
+
+    sqlx::query(
+        r#"
+        INSERT INTO refresh_tokens (id, user_id, token_hash, expires_at)
+        VALUES (?, ?, ?, ?)
+        "#,
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(&user.id)
+    .bind(&token_hash)
+    .bind(&expires_at)
+    .execute(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok((access_token, refresh_token))
+}
+
 pub async fn register(
     State(state): State<Arc<AppState>>,
     Json(req): Json<RegisterRequest>,
@@ -65,12 +117,7 @@ pub async fn register(
     .await
     .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
 
-    // Create tokens
-    let access_token = create_token(&user.id, &user.username, &state.jwt_secret)
-        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
-
-    let refresh_token = create_token(&user.id, &user.username, &state.jwt_secret)
-        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let (access_token, refresh_token) = issue_tokens(&state, &user).await?;
 
     Ok(Json(AuthResponse {
         access_token,
@@ -108,12 +155,7 @@ pub async fn login(
         return Err((StatusCode::UNAUTHORIZED, "Invalid credentials".to_string()));
     }
 
-    // Create tokens
-    let access_token = create_token(&user.id, &user.username, &state.jwt_secret)
-        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
-
-    let refresh_token = create_token(&user.id, &user.username, &state.jwt_secret)
-        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let (access_token, refresh_token) = issue_tokens(&state, &user).await?;
 
     Ok(Json(AuthResponse {
         access_token,
@@ -130,10 +172,36 @@ pub async fn refresh(
     State(state): State<Arc<AppState>>,
     Json(req): Json<RefreshRequest>,
 ) -> Result<Json<AuthResponse>, (StatusCode, String)> {
-    // Verify refresh token
-    let claims = crate::services::verify_token(&req.refresh_token, &state.jwt_secret)
+    // Verify the token is well-formed and actually a refresh token - an
+    // access token presented here must be rejected, or a stolen access
+    // token would let an attacker mint itself a long-lived refresh token.
+    let claims = verify_token(&req.refresh_token, &state.jwt_secret)
         .map_err(|_| (StatusCode::UNAUTHORIZED, "Invalid refresh token".to_string()))?;
 
+    if claims.typ != "refresh" {
+        return Err((
+            StatusCode::UNAUTHORIZED,
+            "Token is not a refresh token".to_string(),
+        ));
+    }
+
+    // Rotate: the presented token must still be a live, unconsumed row, and
+    // is deleted here so it can't be replayed even if this request's new
+    // token pair is never delivered to the client.
+    let token_hash = hash_refresh_token(&req.refresh_token);
+    let deleted = sqlx::query("DELETE FROM refresh_tokens WHERE token_hash = ?")
+        .bind(&token_hash)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if deleted.rows_affected() == 0 {
+        return Err((
+            StatusCode::UNAUTHORIZED,
+            "Refresh token has already been used or revoked".to_string(),
+        ));
+    }
+
     // Get user
     let user = sqlx::query_as::<_, User>(
         "SELECT * FROM users WHERE id = ?",
@@ -144,12 +212,7 @@ pub async fn refresh(
     .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
     .ok_or_else(|| (StatusCode::UNAUTHORIZED, "User not found".to_string()))?;
 
-    // Create new tokens
-    let access_token = create_token(&user.id, &user.username, &state.jwt_secret)
-        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
-
-    let refresh_token = create_token(&user.id, &user.username, &state.jwt_secret)
-        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let (access_token, refresh_token) = issue_tokens(&state, &user).await?;
 
     Ok(Json(AuthResponse {
         access_token,
@@ -161,3 +224,20 @@ pub async fn refresh(
         },
     }))
 }
+
+/// Revoke a refresh token early (e.g. on explicit user logout) so it can't
+/// be used to mint new access tokens even before its TTL expires.
+pub async fn logout(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<LogoutRequest>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let token_hash = hash_refresh_token(&req.refresh_token);
+
+    sqlx::query("DELETE FROM refresh_tokens WHERE token_hash = ?")
+        .bind(&token_hash)
+        .execute(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
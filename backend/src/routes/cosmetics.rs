@@ -1,4 +1,5 @@
 use crate::models::Cosmetic;
+use crate::routes::AuthUser;
 use crate::AppState;
 use axum::{
     extract::{Path, State},
@@ -6,6 +7,7 @@ use axum::{
     Json,
 };
 use std::sync::Arc;
+use uuid::Uuid;
 
 pub async fn list_cosmetics(
     State(state): State<Arc<AppState>>,
@@ -37,16 +39,135 @@ pub async fn get_cosmetic(
 }
 
 pub async fn get_owned(
-    State(_state): State<Arc<AppState>>,
+    AuthUser(user_id): AuthUser,
+    State(state): State<Arc<AppState>>,
 ) -> Result<Json<Vec<Cosmetic>>, (StatusCode, String)> {
-    // TODO: Get user from JWT and return their owned cosmetics
-    Ok(Json(vec![]))
+    let cosmetics = sqlx::query_as::<_, Cosmetic>(
+        r#"
+        SELECT c.* FROM cosmetics c
+        INNER JOIN user_cosmetics uc ON uc.cosmetic_id = c.id
+        WHERE uc.user_id = ?
+        ORDER BY uc.purchased_at DESC
+        "#,
+    )
+    .bind(&user_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(cosmetics))
 }
 
+/// Equip `id` for the signed-in user, unequipping whatever else occupies
+/// the same slot (`cosmetic_type`) first - `user_cosmetics.equipped` only
+/// makes sense as one-item-per-slot.
 pub async fn equip(
-    State(_state): State<Arc<AppState>>,
-    Path(_id): Path<String>,
+    AuthUser(user_id): AuthUser,
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
 ) -> Result<StatusCode, (StatusCode, String)> {
-    // TODO: Equip cosmetic for user
+    let cosmetic = sqlx::query_as::<_, Cosmetic>("SELECT * FROM cosmetics WHERE id = ?")
+        .bind(&id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or_else(|| (StatusCode::NOT_FOUND, "Cosmetic not found".to_string()))?;
+
+    let owns_it = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM user_cosmetics WHERE user_id = ? AND cosmetic_id = ?",
+    )
+    .bind(&user_id)
+    .bind(&id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        > 0;
+
+    if !owns_it {
+        return Err((
+            StatusCode::FORBIDDEN,
+            "You don't own this cosmetic".to_string(),
+        ));
+    }
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    sqlx::query(
+        r#"
+        UPDATE user_cosmetics
+        SET equipped = 0
+        WHERE user_id = ?
+          AND cosmetic_id IN (SELECT id FROM cosmetics WHERE cosmetic_type = ?)
+        "#,
+    )
+    .bind(&user_id)
+    .bind(&cosmetic.cosmetic_type)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    sqlx::query("UPDATE user_cosmetics SET equipped = 1 WHERE user_id = ? AND cosmetic_id = ?")
+        .bind(&user_id)
+        .bind(&id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
     Ok(StatusCode::OK)
 }
+
+/// Grant `user_id` ownership of `cosmetic_id` (e.g. after a purchase).
+/// Exposed for completeness since nothing currently populates
+/// `user_cosmetics` otherwise - purchase flows will call into this once
+/// they exist.
+#[allow(dead_code)]
+pub async fn grant_cosmetic(
+    state: &AppState,
+    user_id: &str,
+    cosmetic_id: &str,
+) -> Result<(), String> {
+    sqlx::query(
+        r#"
+        INSERT INTO user_cosmetics (id, user_id, cosmetic_id, equipped, purchased_at)
+        VALUES (?, ?, ?, 0, ?)
+        "#,
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(user_id)
+    .bind(cosmetic_id)
+    .bind(chrono::Utc::now().to_rfc3339())
+    .execute(&state.db)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Public view of another player's equipped loadout, for rendering their
+/// cosmetics in-game.
+pub async fn get_equipped_for_user(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<Cosmetic>>, (StatusCode, String)> {
+    let cosmetics = sqlx::query_as::<_, Cosmetic>(
+        r#"
+        SELECT c.* FROM cosmetics c
+        INNER JOIN user_cosmetics uc ON uc.cosmetic_id = c.id
+        WHERE uc.user_id = ? AND uc.equipped = 1
+        "#,
+    )
+    .bind(&id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(cosmetics))
+}
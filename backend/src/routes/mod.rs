@@ -0,0 +1,57 @@
+pub mod auth;
+pub mod cosmetics;
+pub mod friends;
+pub mod updates;
+pub mod users;
+
+use crate::services::verify_token;
+use crate::AppState;
+use axum::{
+    extract::FromRequestParts,
+    http::{header::AUTHORIZATION, request::Parts, StatusCode},
+};
+use std::sync::Arc;
+
+/// Extracts the caller's user id from a `Authorization: Bearer <access token>`
+/// header, for routes that act on behalf of the signed-in user rather than
+/// just reading public data.
+pub struct AuthUser(pub String);
+
+impl FromRequestParts<Arc<AppState>> for AuthUser {
+    type Rejection = (StatusCode, String);
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AppState>,
+    ) -> Result<Self, Self::Rejection> {
+        let header = parts
+            .headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .ok_or_else(|| {
+                (
+                    StatusCode::UNAUTHORIZED,
+                    "Missing Authorization header".to_string(),
+                )
+            })?;
+
+        let token = header.strip_prefix("Bearer ").ok_or_else(|| {
+            (
+                StatusCode::UNAUTHORIZED,
+                "Expected a Bearer token".to_string(),
+            )
+        })?;
+
+        let claims = verify_token(token, &state.jwt_secret)
+            .map_err(|_| (StatusCode::UNAUTHORIZED, "Invalid or expired token".to_string()))?;
+
+        if claims.typ != "access" {
+            return Err((
+                StatusCode::UNAUTHORIZED,
+                "Token is not an access token".to_string(),
+            ));
+        }
+
+        Ok(AuthUser(claims.sub))
+    }
+}
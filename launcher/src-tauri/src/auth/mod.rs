@@ -1,17 +1,23 @@
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use thiserror::Error;
 
 const DEVICE_CODE_URL: &str = "https://login.live.com/oauth20_connect.srf";
+const AUTHORIZE_URL: &str = "https://login.live.com/oauth20_authorize.srf";
 const TOKEN_URL: &str = "https://login.live.com/oauth20_token.srf";
 const XBOX_AUTH_URL: &str = "https://user.auth.xboxlive.com/user/authenticate";
 const XSTS_AUTH_URL: &str = "https://xsts.auth.xboxlive.com/xsts/authorize";
 const MINECRAFT_AUTH_URL: &str = "https://api.minecraftservices.com/authentication/login_with_xbox";
 const MINECRAFT_PROFILE_URL: &str = "https://api.minecraftservices.com/minecraft/profile";
+const MINECRAFT_ENTITLEMENTS_URL: &str = "https://api.minecraftservices.com/entitlements/mcstore";
 
 // Official Minecraft launcher client ID (works without whitelisting)
 const CLIENT_ID: &str = "00000000402b5328";
 
+/// Loopback ports tried, in order, for the PKCE redirect listener.
+const PKCE_LOOPBACK_PORTS: [u16; 5] = [28562, 28563, 28564, 28565, 28566];
+
 #[derive(Error, Debug)]
 pub enum AuthError {
     #[error("HTTP request failed: {0}")]
@@ -26,6 +32,34 @@ pub enum AuthError {
     Timeout,
     #[error("Authentication cancelled")]
     Cancelled,
+    #[error("This Microsoft account has no Xbox profile. Create one at xbox.com, then try again.")]
+    NoXboxAccount,
+    #[error("Xbox Live isn't available in this account's country/region.")]
+    XboxLiveRegionRestricted,
+    #[error("This Xbox account requires adult verification before it can be used.")]
+    AdultVerificationRequired,
+    #[error("This is a child account and must be added to a Family by an adult before it can sign in.")]
+    ChildAccountNeedsFamily,
+    #[error("Refresh token expired or was revoked; sign in again")]
+    RefreshExpired,
+}
+
+/// Xbox's structured XSTS error body: `{"Identity": "...", "XErr": 2148916233, "Message": "...", "Redirect": "..."}`
+#[derive(Debug, Deserialize)]
+struct XstsErrorBody {
+    #[serde(rename = "XErr")]
+    x_err: Option<u64>,
+}
+
+/// Map a known XSTS `XErr` code to the `AuthError` variant that explains it.
+fn xsts_error_for_code(x_err: u64) -> Option<AuthError> {
+    match x_err {
+        2148916233 => Some(AuthError::NoXboxAccount),
+        2148916235 => Some(AuthError::XboxLiveRegionRestricted),
+        2148916236 | 2148916237 => Some(AuthError::AdultVerificationRequired),
+        2148916238 => Some(AuthError::ChildAccountNeedsFamily),
+        _ => None,
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -38,6 +72,26 @@ pub struct MinecraftProfile {
     pub expires_at: u64,
 }
 
+impl MinecraftProfile {
+    /// Seconds remaining before `expires_at`; negative once it's passed.
+    pub fn expires_in(&self) -> i64 {
+        (self.expires_at as i64 - now_millis() as i64) / 1000
+    }
+
+    /// Whether the access token is expired, or within the refresh skew
+    /// margin `get_valid_profile` treats as "expiring soon".
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= now_millis() + TOKEN_EXPIRY_SKEW_MS
+    }
+}
+
+fn now_millis() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeviceCodeResponse {
     pub device_code: String,
@@ -58,6 +112,84 @@ fn default_interval() -> u64 {
     5
 }
 
+/// How close to `expires_at` we'll still treat a token as valid. Refreshing
+/// a little early avoids racing a request that starts just before expiry.
+const TOKEN_EXPIRY_SKEW_MS: u64 = 60_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct StoredTokens {
+    /// Stored profiles keyed by Minecraft account ID
+    profiles: std::collections::HashMap<String, MinecraftProfile>,
+}
+
+/// Persists `MinecraftProfile`s (including refresh tokens) to disk, keyed by
+/// account ID, so a session can resume across launches without the
+/// device-code dance every time.
+pub struct TokenStore {
+    path: std::path::PathBuf,
+}
+
+impl TokenStore {
+    pub fn new() -> Self {
+        let path = dirs::data_dir()
+            .unwrap_or_else(|| std::path::PathBuf::from("."))
+            .join("MiracleClient")
+            .join("auth")
+            .join("tokens.json");
+
+        Self { path }
+    }
+
+    fn load(&self) -> StoredTokens {
+        std::fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self, tokens: &StoredTokens) -> Result<(), String> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create token directory: {}", e))?;
+        }
+
+        let contents = serde_json::to_string_pretty(tokens)
+            .map_err(|e| format!("Failed to serialize tokens: {}", e))?;
+
+        std::fs::write(&self.path, contents).map_err(|e| format!("Failed to write tokens: {}", e))
+    }
+
+    /// Get a stored profile by account ID
+    pub fn get(&self, id: &str) -> Option<MinecraftProfile> {
+        self.load().profiles.get(id).cloned()
+    }
+
+    /// Store or update a profile's tokens
+    pub fn put(&self, profile: &MinecraftProfile) -> Result<(), String> {
+        let mut tokens = self.load();
+        tokens.profiles.insert(profile.id.clone(), profile.clone());
+        self.save(&tokens)
+    }
+
+    /// Remove a stored profile, e.g. on logout
+    pub fn remove(&self, id: &str) -> Result<(), String> {
+        let mut tokens = self.load();
+        tokens.profiles.remove(id);
+        self.save(&tokens)
+    }
+
+    /// List every stored profile, e.g. for the periodic refresh sweep.
+    pub fn all(&self) -> Vec<MinecraftProfile> {
+        self.load().profiles.into_values().collect()
+    }
+}
+
+impl Default for TokenStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Parse URL-encoded response from Microsoft OAuth endpoints
 fn parse_form_response(text: &str) -> Result<std::collections::HashMap<String, String>, AuthError> {
     let mut map = std::collections::HashMap::new();
@@ -148,6 +280,16 @@ struct MinecraftProfileResponse {
     name: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct EntitlementsResponse {
+    items: Vec<EntitlementItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EntitlementItem {
+    name: String,
+}
+
 pub struct AuthManager {
     client: Client,
 }
@@ -289,7 +431,7 @@ impl AuthManager {
             // Try parsing as JSON first
             if let Ok(token_response) = serde_json::from_str::<TokenResponse>(&text) {
                 tracing::info!("Token parsed as JSON, got access token");
-                return self.complete_authentication(token_response).await;
+                return self.complete_authentication(token_response, None).await;
             }
 
             // Try parsing as JSON error
@@ -343,7 +485,7 @@ impl AuthManager {
                     user_id: data.get("user_id").cloned(),
                 };
                 tracing::info!("Got access token (form), proceeding to Xbox auth");
-                return self.complete_authentication(token).await;
+                return self.complete_authentication(token, None).await;
             }
 
             return Err(AuthError::ParseError(format!(
@@ -353,10 +495,14 @@ impl AuthManager {
         }
     }
 
-    /// Complete authentication after getting MS token
+    /// Complete authentication after getting MS token. `fallback_refresh_token`
+    /// is threaded through from a prior `refresh` call: Microsoft doesn't
+    /// always rotate the refresh token, and without this the next refresh
+    /// would be attempted with an empty one.
     async fn complete_authentication(
         &self,
         ms_token: TokenResponse,
+        fallback_refresh_token: Option<&str>,
     ) -> Result<MinecraftProfile, AuthError> {
         tracing::info!("Step 1: Authenticating with Xbox Live...");
         let xbox_token = self.authenticate_xbox(&ms_token.access_token).await?;
@@ -373,26 +519,56 @@ impl AuthManager {
             .await?;
         tracing::info!("Minecraft auth successful");
 
-        tracing::info!("Step 4: Getting Minecraft profile...");
+        tracing::info!("Step 4: Checking Minecraft entitlements...");
+        self.check_entitlements(&mc_token.access_token).await?;
+
+        tracing::info!("Step 5: Getting Minecraft profile...");
         let profile = self.get_minecraft_profile(&mc_token.access_token).await?;
         tracing::info!("Got profile: {}", profile.name);
 
-        let expires_at = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64
-            + (mc_token.expires_in * 1000);
+        let expires_at = now_millis() + (mc_token.expires_in * 1000);
+
+        let refresh_token = ms_token
+            .refresh_token
+            .or_else(|| fallback_refresh_token.map(str::to_string))
+            .unwrap_or_default();
 
-        Ok(MinecraftProfile {
+        let profile = MinecraftProfile {
             id: profile.id,
             name: profile.name,
             access_token: mc_token.access_token,
-            refresh_token: ms_token.refresh_token.unwrap_or_default(),
+            refresh_token,
             expires_at,
-        })
+        };
+
+        if let Err(e) = TokenStore::new().put(&profile) {
+            tracing::warn!("Failed to persist session for {}: {}", profile.id, e);
+        }
+
+        Ok(profile)
+    }
+
+    /// Load the stored profile for `id`, transparently refreshing it first
+    /// if it's expired or about to expire, and persisting the refreshed
+    /// tokens back to the store.
+    pub async fn get_valid_profile(&self, id: &str) -> Result<MinecraftProfile, AuthError> {
+        let store = TokenStore::new();
+        let profile = store.get(id).ok_or_else(|| {
+            AuthError::AuthFailed(format!("No stored session for account {}", id))
+        })?;
+
+        if !profile.is_expired() {
+            return Ok(profile);
+        }
+
+        tracing::info!("Session for {} is expiring, refreshing", id);
+        self.refresh(&profile.refresh_token).await
     }
 
-    /// Refresh an existing token
+    /// Refresh an existing token. If Microsoft has rotated or revoked the
+    /// refresh token (`invalid_grant`/`invalid_token`), returns
+    /// `AuthError::RefreshExpired` so the caller can restart the device-code
+    /// (or PKCE) flow instead of treating it as a generic failure.
     pub async fn refresh(&self, refresh_token: &str) -> Result<MinecraftProfile, AuthError> {
         let params = [
             ("client_id", CLIENT_ID),
@@ -401,16 +577,57 @@ impl AuthManager {
             ("scope", "service::user.auth.xboxlive.com::MBI_SSL"),
         ];
 
-        let response = self
-            .client
-            .post(TOKEN_URL)
-            .form(&params)
-            .send()
-            .await?
-            .json::<TokenResponse>()
-            .await?;
+        let response = self.client.post(TOKEN_URL).form(&params).send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            if let Ok(error) = serde_json::from_str::<TokenErrorResponse>(&text) {
+                if error.error == "invalid_grant" || error.error == "invalid_token" {
+                    return Err(AuthError::RefreshExpired);
+                }
+            }
+            return Err(AuthError::AuthFailed(format!(
+                "Token refresh failed: {}",
+                text
+            )));
+        }
 
-        self.complete_authentication(response).await
+        let token_response: TokenResponse = serde_json::from_str(&text).map_err(|e| {
+            AuthError::ParseError(format!("Failed to parse refresh response: {} - {}", e, text))
+        })?;
+
+        self.complete_authentication(token_response, Some(refresh_token))
+            .await
+    }
+
+    /// Refresh every stored profile that's expired or within the skew
+    /// window, so long-lived sessions get renewed before a launch or the
+    /// periodic refresh timer hits a token that's already gone stale.
+    /// Accounts whose refresh grant comes back `invalid_grant`/
+    /// `invalid_token` are dropped from the store entirely rather than left
+    /// with tokens that will never work again.
+    pub async fn refresh_expiring_profiles(&self) -> Vec<(String, Result<MinecraftProfile, AuthError>)> {
+        let store = TokenStore::new();
+        let mut results = Vec::new();
+
+        for profile in store.all() {
+            if !profile.is_expired() {
+                continue;
+            }
+
+            let id = profile.id.clone();
+            match self.refresh(&profile.refresh_token).await {
+                Ok(refreshed) => results.push((id, Ok(refreshed))),
+                Err(AuthError::RefreshExpired) => {
+                    let _ = store.remove(&id);
+                    results.push((id, Err(AuthError::RefreshExpired)));
+                }
+                Err(e) => results.push((id, Err(e))),
+            }
+        }
+
+        results
     }
 
     async fn authenticate_xbox(&self, access_token: &str) -> Result<XboxAuthResponse, AuthError> {
@@ -471,6 +688,13 @@ impl AuthManager {
         tracing::info!("XSTS response (status {}): {}", status, text);
 
         if !status.is_success() {
+            if let Ok(error_body) = serde_json::from_str::<XstsErrorBody>(&text) {
+                if let Some(x_err) = error_body.x_err {
+                    if let Some(err) = xsts_error_for_code(x_err) {
+                        return Err(err);
+                    }
+                }
+            }
             return Err(AuthError::AuthFailed(format!("XSTS auth failed: {}", text)));
         }
 
@@ -533,4 +757,288 @@ impl AuthManager {
         let profile = response.json::<MinecraftProfileResponse>().await?;
         Ok(profile)
     }
+
+    /// Check whether the account actually owns Minecraft via the
+    /// entitlements endpoint. Relying on the profile 404 alone misses
+    /// accounts with an Xbox/MSA login but no Minecraft entitlement (e.g.
+    /// Game Pass not activated, or demo accounts).
+    async fn check_entitlements(&self, access_token: &str) -> Result<(), AuthError> {
+        let response = self
+            .client
+            .get(MINECRAFT_ENTITLEMENTS_URL)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(AuthError::NoMinecraft);
+        }
+
+        let entitlements = response.json::<EntitlementsResponse>().await?;
+        let owns_minecraft = entitlements.items.iter().any(|item| {
+            item.name == "product_minecraft" || item.name == "game_minecraft"
+        });
+
+        if owns_minecraft {
+            Ok(())
+        } else {
+            Err(AuthError::NoMinecraft)
+        }
+    }
+
+    /// Sign in via the OAuth2 authorization-code + PKCE flow with a
+    /// loopback redirect, as a one-click alternative to the device-code
+    /// flow: opens the system browser to the MSA authorize page, waits for
+    /// the single redirect back to a local listener, then exchanges the
+    /// code for tokens through the existing `complete_authentication` path.
+    pub async fn authenticate_with_pkce(&self) -> Result<MinecraftProfile, AuthError> {
+        let verifier = generate_code_verifier();
+        let challenge = code_challenge_for(&verifier);
+        let state = generate_state();
+
+        let (listener, port) = bind_loopback_listener()?;
+        let redirect_uri = format!("http://localhost:{}", port);
+
+        let authorize_url = format!(
+            "{}?client_id={}&response_type=code&redirect_uri={}&scope={}&code_challenge={}&code_challenge_method=S256&state={}",
+            AUTHORIZE_URL,
+            CLIENT_ID,
+            urlencode(&redirect_uri),
+            urlencode("XboxLive.signin offline_access"),
+            challenge,
+            state,
+        );
+
+        tracing::info!("Opening browser for PKCE sign-in on redirect port {}", port);
+        open_in_browser(&authorize_url)?;
+
+        let code = tokio::task::spawn_blocking(move || {
+            wait_for_redirect_code(listener, std::time::Duration::from_secs(180), state)
+        })
+        .await
+        .map_err(|e| AuthError::AuthFailed(format!("PKCE listener task failed: {}", e)))??;
+
+        let params = [
+            ("client_id", CLIENT_ID),
+            ("grant_type", "authorization_code"),
+            ("code", code.as_str()),
+            ("redirect_uri", redirect_uri.as_str()),
+            ("code_verifier", verifier.as_str()),
+        ];
+
+        let response = self.client.post(TOKEN_URL).form(&params).send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            return Err(AuthError::AuthFailed(format!(
+                "PKCE token exchange failed: {}",
+                text
+            )));
+        }
+
+        let token_response: TokenResponse = serde_json::from_str(&text).map_err(|e| {
+            AuthError::ParseError(format!("Failed to parse token response: {} - {}", e, text))
+        })?;
+
+        self.complete_authentication(token_response, None).await
+    }
+}
+
+/// Generate a random 96-char PKCE code_verifier (valid range is 43-128).
+fn generate_code_verifier() -> String {
+    use rand::Rng;
+    const CHARSET: &[u8] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-._~";
+    let mut rng = rand::thread_rng();
+    (0..96)
+        .map(|_| CHARSET[rng.gen_range(0..CHARSET.len())] as char)
+        .collect()
+}
+
+/// Generate a random 32-char `state` value to pair the authorize request
+/// with its redirect, so a redirect we didn't initiate (e.g. a malicious
+/// local process or page driving the browser to our loopback listener with
+/// its own `code`) gets rejected instead of silently authenticated.
+fn generate_state() -> String {
+    use rand::Rng;
+    const CHARSET: &[u8] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+    let mut rng = rand::thread_rng();
+    (0..32)
+        .map(|_| CHARSET[rng.gen_range(0..CHARSET.len())] as char)
+        .collect()
+}
+
+/// Derive the PKCE code_challenge as base64url(SHA-256(verifier)).
+fn code_challenge_for(verifier: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(verifier.as_bytes());
+    base64_url_encode(&hasher.finalize())
+}
+
+/// Minimal unpadded base64url encoder, just enough for a PKCE challenge.
+fn base64_url_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0] as u32;
+        let b1 = *chunk.get(1).unwrap_or(&0) as u32;
+        let b2 = *chunk.get(2).unwrap_or(&0) as u32;
+        let n = (b0 << 16) | (b1 << 8) | b2;
+
+        out.push(ALPHABET[((n >> 18) & 0x3F) as usize] as char);
+        out.push(ALPHABET[((n >> 12) & 0x3F) as usize] as char);
+        if chunk.len() > 1 {
+            out.push(ALPHABET[((n >> 6) & 0x3F) as usize] as char);
+        }
+        if chunk.len() > 2 {
+            out.push(ALPHABET[(n & 0x3F) as usize] as char);
+        }
+    }
+
+    out
+}
+
+/// Percent-encode a string for safe use as a URL query parameter value.
+fn urlencode(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for byte in input.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(byte as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    out
+}
+
+/// Decode `%XX` percent-escapes in a URL query parameter value.
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let Ok(byte) = u8::from_str_radix(&input[i + 1..i + 3], 16) {
+                out.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Bind the first available loopback port from `PKCE_LOOPBACK_PORTS`.
+fn bind_loopback_listener() -> Result<(std::net::TcpListener, u16), AuthError> {
+    for port in PKCE_LOOPBACK_PORTS {
+        if let Ok(listener) = std::net::TcpListener::bind(("127.0.0.1", port)) {
+            return Ok((listener, port));
+        }
+    }
+
+    Err(AuthError::AuthFailed(
+        "No loopback port available for the PKCE redirect".to_string(),
+    ))
+}
+
+/// Open `url` in the system's default browser.
+fn open_in_browser(url: &str) -> Result<(), AuthError> {
+    #[cfg(target_os = "windows")]
+    let result = std::process::Command::new("cmd")
+        .args(["/C", "start", "", url])
+        .spawn();
+
+    #[cfg(target_os = "macos")]
+    let result = std::process::Command::new("open").arg(url).spawn();
+
+    #[cfg(target_os = "linux")]
+    let result = std::process::Command::new("xdg-open").arg(url).spawn();
+
+    result
+        .map(|_| ())
+        .map_err(|e| AuthError::AuthFailed(format!("Failed to open browser: {}", e)))
+}
+
+/// Block (on a dedicated thread) until the single OAuth redirect request
+/// arrives, extract its `code` query parameter, and reply with a small
+/// confirmation page before closing the connection. Any request whose
+/// `state` doesn't match `expected_state` is rejected rather than treated
+/// as our redirect - without this, another local process or page could
+/// drive the browser to our loopback listener with its own `code` and get
+/// the user signed into the attacker's account.
+fn wait_for_redirect_code(
+    listener: std::net::TcpListener,
+    timeout: std::time::Duration,
+    expected_state: String,
+) -> Result<String, AuthError> {
+    use std::io::{Read, Write};
+
+    listener
+        .set_nonblocking(true)
+        .map_err(|e| AuthError::AuthFailed(e.to_string()))?;
+
+    let deadline = std::time::Instant::now() + timeout;
+
+    loop {
+        match listener.accept() {
+            Ok((mut stream, _)) => {
+                let mut buf = [0u8; 4096];
+                let n = stream.read(&mut buf).unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..n]);
+
+                let query = request
+                    .lines()
+                    .next()
+                    .and_then(|line| line.split_whitespace().nth(1))
+                    .and_then(|target| target.split_once('?').map(|(_, query)| query.to_string()));
+
+                let code = query.as_deref().and_then(|query| {
+                    query
+                        .split('&')
+                        .find_map(|pair| pair.strip_prefix("code="))
+                        .map(percent_decode)
+                });
+                let state = query.as_deref().and_then(|query| {
+                    query
+                        .split('&')
+                        .find_map(|pair| pair.strip_prefix("state="))
+                        .map(percent_decode)
+                });
+
+                let body = "<html><body>Signed in \u{2014} you can close this window.</body></html>";
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes());
+
+                match (code, state) {
+                    (Some(code), Some(state)) if state == expected_state => return Ok(code),
+                    (Some(_), Some(_)) => {
+                        tracing::warn!(
+                            "Rejecting PKCE redirect with mismatched state parameter"
+                        );
+                    }
+                    _ => {
+                        // Not the redirect we expected (e.g. a favicon request); keep waiting.
+                    }
+                }
+            }
+            Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                if std::time::Instant::now() > deadline {
+                    return Err(AuthError::Timeout);
+                }
+                std::thread::sleep(std::time::Duration::from_millis(150));
+            }
+            Err(e) => return Err(AuthError::AuthFailed(e.to_string())),
+        }
+    }
 }
@@ -1,7 +1,12 @@
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
-use tauri::{AppHandle, Manager};
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::sync::Semaphore;
+
+/// How many ffprobe/ffmpeg jobs run at once when backfilling clip metadata.
+const MAX_CONCURRENT_THUMBNAILS: usize = 4;
 
 /// Metadata for a saved clip
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -251,3 +256,182 @@ pub async fn open_clips_folder() -> Result<(), String> {
 pub async fn refresh_clips() -> Result<Vec<ClipInfo>, String> {
     list_clips().await
 }
+
+/// Metadata for a clip that just finished being processed, emitted as the
+/// `clip_processed` event so the gallery can update progressively.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClipProcessed {
+    pub clip_id: String,
+    pub duration_ms: u64,
+    pub width: u32,
+    pub height: u32,
+    pub thumbnail_path: Option<String>,
+}
+
+/// For any clip missing a thumbnail or sidecar `.json`, shell out to
+/// ffprobe/ffmpeg to read its real duration and dimensions and extract a
+/// representative frame (at 10% of duration) as a thumbnail, then cache the
+/// result in the sidecar so future `list_clips` calls stay cheap. Runs
+/// lazily across a bounded worker pool and emits `clip_processed` as each
+/// clip finishes. Falls back to the existing 0/1920x1080 defaults if
+/// ffprobe/ffmpeg aren't available on PATH.
+#[tauri::command]
+pub async fn process_clip_thumbnails(app: AppHandle) -> Result<(), String> {
+    let clips_dir = get_clips_dir();
+    if !clips_dir.exists() {
+        return Ok(());
+    }
+
+    let entries =
+        fs::read_dir(&clips_dir).map_err(|e| format!("Failed to read clips directory: {}", e))?;
+
+    let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_THUMBNAILS));
+    let mut tasks = Vec::new();
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let is_video = path
+            .extension()
+            .map(|e| {
+                let e = e.to_string_lossy().to_lowercase();
+                e == "mp4" || e == "webm" || e == "mkv"
+            })
+            .unwrap_or(false);
+        if !is_video {
+            continue;
+        }
+
+        let has_metadata = path.with_extension("json").exists();
+        let has_thumbnail =
+            path.with_extension("jpg").exists() || path.with_extension("png").exists();
+        if has_metadata && has_thumbnail {
+            continue;
+        }
+
+        let clip_id = path
+            .file_stem()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        let app = app.clone();
+        let semaphore = semaphore.clone();
+        tasks.push(tokio::spawn(async move {
+            let _permit = semaphore.acquire().await;
+
+            match process_single_clip(&path).await {
+                Ok((duration_ms, width, height, thumbnail_path)) => {
+                    app.emit(
+                        "clip_processed",
+                        ClipProcessed {
+                            clip_id,
+                            duration_ms,
+                            width,
+                            height,
+                            thumbnail_path,
+                        },
+                    )
+                    .ok();
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to process clip {}: {}", clip_id, e);
+                }
+            }
+        }));
+    }
+
+    for task in tasks {
+        let _ = task.await;
+    }
+
+    Ok(())
+}
+
+/// Probe a single video with ffprobe for duration/dimensions, extract a
+/// thumbnail with ffmpeg, and write the sidecar `.json` so the next call
+/// to `list_clips` can read it straight off disk.
+async fn process_single_clip(path: &PathBuf) -> Result<(u64, u32, u32, Option<String>), String> {
+    let (duration_ms, width, height) = match tokio::process::Command::new("ffprobe")
+        .args([
+            "-v",
+            "error",
+            "-select_streams",
+            "v:0",
+            "-show_entries",
+            "stream=width,height:format=duration",
+            "-of",
+            "json",
+        ])
+        .arg(path)
+        .output()
+        .await
+    {
+        Ok(output) if output.status.success() => {
+            parse_ffprobe_output(&output.stdout).unwrap_or((0, 1920, 1080))
+        }
+        Ok(_) => {
+            tracing::warn!("ffprobe failed for {:?}, using default metadata", path);
+            (0, 1920, 1080)
+        }
+        Err(e) => {
+            tracing::warn!("ffprobe unavailable ({}), using default metadata", e);
+            (0, 1920, 1080)
+        }
+    };
+
+    let thumbnail_path = if duration_ms > 0 {
+        generate_thumbnail(path, duration_ms).await
+    } else {
+        None
+    };
+
+    let metadata = serde_json::json!({
+        "duration_ms": duration_ms,
+        "width": width,
+        "height": height,
+    });
+
+    fs::write(
+        path.with_extension("json"),
+        serde_json::to_string_pretty(&metadata).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| format!("Failed to write metadata sidecar: {}", e))?;
+
+    Ok((duration_ms, width, height, thumbnail_path))
+}
+
+/// Parse the `ffprobe -of json` output into (duration_ms, width, height).
+fn parse_ffprobe_output(stdout: &[u8]) -> Option<(u64, u32, u32)> {
+    let json: serde_json::Value = serde_json::from_slice(stdout).ok()?;
+    let stream = json.get("streams")?.as_array()?.first()?;
+    let width = stream.get("width")?.as_u64()? as u32;
+    let height = stream.get("height")?.as_u64()? as u32;
+    let duration_secs: f64 = json.get("format")?.get("duration")?.as_str()?.parse().ok()?;
+
+    Some(((duration_secs * 1000.0).round() as u64, width, height))
+}
+
+/// Extract a representative frame at 10% of the clip's duration as a jpg
+/// thumbnail alongside the source file. Returns `None` if ffmpeg isn't
+/// available or the extraction fails.
+async fn generate_thumbnail(path: &PathBuf, duration_ms: u64) -> Option<String> {
+    let thumb_path = path.with_extension("jpg");
+    let seek_secs = (duration_ms as f64 / 1000.0) * 0.1;
+
+    let output = tokio::process::Command::new("ffmpeg")
+        .arg("-y")
+        .arg("-ss")
+        .arg(format!("{:.3}", seek_secs))
+        .arg("-i")
+        .arg(path)
+        .args(["-frames:v", "1", "-q:v", "2"])
+        .arg(&thumb_path)
+        .output()
+        .await
+        .ok()?;
+
+    if output.status.success() && thumb_path.exists() {
+        Some(thumb_path.to_string_lossy().to_string())
+    } else {
+        None
+    }
+}
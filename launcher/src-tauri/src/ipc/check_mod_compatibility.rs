@@ -1,4 +1,17 @@
+use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+use super::modrinth::{
+    create_client, get_modrinth_projects_bulk, ModrinthVersion, ModrinthVersionDependency,
+    MODRINTH_API_BASE,
+};
+use super::retry::{send_with_retry, RetryConfig};
+
+/// How many Modrinth version lookups run at once while checking compatibility.
+const MAX_CONCURRENT_CHECKS: usize = 5;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ModCompatibility {
@@ -6,76 +19,180 @@ pub struct ModCompatibility {
     pub mod_name: String,
     pub slug: String,
     pub compatible: bool,
+    pub latest_version: Option<String>,
+    pub download_url: Option<String>,
+    pub required_dependencies: Vec<String>,
+    /// Whether this mod and all of its required dependencies (recursively)
+    /// have a version compatible with `minecraft_version`.
+    pub installable: bool,
 }
 
 pub async fn check_mods_compatibility(
     mod_slugs: Vec<String>,
     minecraft_version: &str,
 ) -> Result<Vec<ModCompatibility>, String> {
-    let client = reqwest::Client::builder()
-        .timeout(std::time::Duration::from_secs(120))
-        .user_agent("MiracleClient/1.0")
-        .build()
-        .map_err(|e| e.to_string())?;
-
-    let mut results = Vec::new();
-
-    for mod_slug in mod_slugs {
-        tracing::info!(
-            "Checking compatibility for {} on {}",
-            mod_slug,
-            minecraft_version
-        );
-
-        // Add delay to avoid rate limiting (300ms between requests = ~3 per second)
-        tokio::time::sleep(std::time::Duration::from_millis(300)).await;
-
-        let versions_url = format!(
-            "https://api.modrinth.com/v2/project/{}/version?game_versions=[\"{}\"]&loaders=[\"fabric\"]",
-            mod_slug, minecraft_version
-        );
-
-        match client.get(&versions_url).send().await {
-            Ok(response) => {
-                if response.status().is_success() {
-                    match response.json::<Vec<serde_json::Value>>().await {
-                        Ok(versions) => {
-                            let compatible = !versions.is_empty();
-                            results.push(ModCompatibility {
-                                mod_id: mod_slug.clone(),
-                                mod_name: mod_slug.clone(),
-                                slug: mod_slug.clone(),
-                                compatible,
-                            });
-                        }
-                        Err(_) => {
-                            results.push(ModCompatibility {
-                                mod_id: mod_slug.clone(),
-                                mod_name: mod_slug.clone(),
-                                slug: mod_slug.clone(),
-                                compatible: false,
-                            });
-                        }
-                    }
-                } else {
-                    results.push(ModCompatibility {
-                        mod_id: mod_slug.clone(),
-                        mod_name: mod_slug.clone(),
-                        slug: mod_slug.clone(),
-                        compatible: false,
-                    });
-                }
+    // Resolve all project titles in a single bulk request instead of one per mod.
+    let titles_by_slug: HashMap<String, String> = get_modrinth_projects_bulk(mod_slugs.clone())
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .map(|p| (p.slug, p.title))
+        .collect();
+
+    let client = Arc::new(create_client()?);
+    let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_CHECKS));
+    let mut tasks = Vec::new();
+
+    for (index, mod_slug) in mod_slugs.into_iter().enumerate() {
+        let client = client.clone();
+        let semaphore = semaphore.clone();
+        let minecraft_version = minecraft_version.to_string();
+        let mod_name = titles_by_slug
+            .get(&mod_slug)
+            .cloned()
+            .unwrap_or_else(|| mod_slug.clone());
+
+        tasks.push(tokio::spawn(async move {
+            let _permit = semaphore.acquire().await;
+            let result = check_single_mod(&client, mod_slug, mod_name, &minecraft_version).await;
+            (index, result)
+        }));
+    }
+
+    let mut results = Vec::with_capacity(tasks.len());
+    for task in tasks {
+        if let Ok(item) = task.await {
+            results.push(item);
+        }
+    }
+    results.sort_by_key(|(index, _)| *index);
+
+    Ok(results.into_iter().map(|(_, result)| result).collect())
+}
+
+async fn check_single_mod(
+    client: &Client,
+    mod_slug: String,
+    mod_name: String,
+    minecraft_version: &str,
+) -> ModCompatibility {
+    tracing::info!(
+        "Checking compatibility for {} on {}",
+        mod_slug,
+        minecraft_version
+    );
+
+    let versions = fetch_versions_with_backoff(client, &mod_slug, minecraft_version)
+        .await
+        .unwrap_or_default();
+
+    let Some(version) = versions.first() else {
+        return ModCompatibility {
+            mod_id: mod_slug.clone(),
+            mod_name,
+            slug: mod_slug,
+            compatible: false,
+            latest_version: None,
+            download_url: None,
+            required_dependencies: Vec::new(),
+            installable: false,
+        };
+    };
+
+    let download_url = version
+        .files
+        .iter()
+        .find(|f| f.primary)
+        .or_else(|| version.files.first())
+        .map(|f| f.url.clone());
+
+    let required_dependencies: Vec<String> = version
+        .dependencies
+        .iter()
+        .filter(|d| d.dependency_type == "required")
+        .filter_map(|d| d.project_id.clone())
+        .collect();
+
+    let mut visited = HashSet::new();
+    visited.insert(version.project_id.clone());
+    let installable =
+        dependencies_installable(client, &version.dependencies, minecraft_version, &mut visited)
+            .await;
+
+    ModCompatibility {
+        mod_id: version.project_id.clone(),
+        mod_name,
+        slug: mod_slug,
+        compatible: true,
+        latest_version: Some(version.version_number.clone()),
+        download_url,
+        required_dependencies,
+        installable,
+    }
+}
+
+/// Recursively verify that every `required` dependency (and its own
+/// required dependencies) has a version compatible with `minecraft_version`.
+/// `visited` prevents re-checking (or cycling on) the same project twice.
+fn dependencies_installable<'a>(
+    client: &'a Client,
+    dependencies: &'a [ModrinthVersionDependency],
+    minecraft_version: &'a str,
+    visited: &'a mut HashSet<String>,
+) -> std::pin::Pin<Box<dyn std::future::Future<Output = bool> + Send + 'a>> {
+    Box::pin(async move {
+        for dep in dependencies {
+            if dep.dependency_type != "required" {
+                continue;
             }
-            Err(_) => {
-                results.push(ModCompatibility {
-                    mod_id: mod_slug.clone(),
-                    mod_name: mod_slug.clone(),
-                    slug: mod_slug.clone(),
-                    compatible: false,
-                });
+
+            let Some(project_id) = dep.project_id.clone() else {
+                continue;
+            };
+
+            if !visited.insert(project_id.clone()) {
+                continue;
+            }
+
+            let versions = match fetch_versions_with_backoff(client, &project_id, minecraft_version).await
+            {
+                Ok(v) => v,
+                Err(_) => return false,
+            };
+
+            let Some(version) = versions.first() else {
+                return false;
+            };
+
+            if !dependencies_installable(client, &version.dependencies, minecraft_version, visited)
+                .await
+            {
+                return false;
             }
         }
-    }
 
-    Ok(results)
+        true
+    })
+}
+
+/// Fetch the compatible versions for a project, using the shared retry
+/// wrapper to ride out Modrinth's rate limiting and transient 5xx errors.
+async fn fetch_versions_with_backoff(
+    client: &Client,
+    project_id: &str,
+    minecraft_version: &str,
+) -> Result<Vec<ModrinthVersion>, String> {
+    let url = format!(
+        "{}/project/{}/version?game_versions=[\"{}\"]&loaders=[\"fabric\"]",
+        MODRINTH_API_BASE, project_id, minecraft_version
+    );
+
+    let response = send_with_retry(|| client.get(&url), &RetryConfig::default())
+        .await
+        .map_err(|e| format!("Failed to fetch versions for {}: {}", project_id, e))?;
+
+    response
+        .json::<Vec<ModrinthVersion>>()
+        .await
+        .map_err(|e| format!("Failed to parse versions for {}: {}", project_id, e))
 }
@@ -0,0 +1,301 @@
+use futures::future::BoxFuture;
+
+use super::curseforge;
+use super::modrinth::{
+    self, ModrinthFile, ModrinthHashes, ModrinthProject, ModrinthSearchResponse,
+    ModrinthSearchResult, ModrinthVersion,
+};
+
+/// Where a piece of downloadable content (mod/resourcepack/shader/datapack)
+/// actually lives. Every implementation maps its own API's shapes into the
+/// crate's existing Modrinth-flavored structs, so search results, version
+/// listings, and download resolution look the same to callers no matter
+/// which backend answered.
+pub trait ContentSource: Send + Sync {
+    fn search<'a>(
+        &'a self,
+        query: String,
+        content_type: String,
+        version: String,
+        loader: String,
+        offset: u32,
+        limit: u32,
+    ) -> BoxFuture<'a, Result<ModrinthSearchResponse, String>>;
+
+    fn get_project<'a>(
+        &'a self,
+        project_id: String,
+    ) -> BoxFuture<'a, Result<ModrinthProject, String>>;
+
+    fn get_versions<'a>(
+        &'a self,
+        project_id: String,
+        game_version: Option<String>,
+        loader: Option<String>,
+    ) -> BoxFuture<'a, Result<Vec<ModrinthVersion>, String>>;
+
+    /// Pick the file to actually download from an already-resolved version.
+    /// Trivial for every source since `get_versions` already normalizes
+    /// files into [`ModrinthFile`] — this exists so callers never have to
+    /// know that.
+    fn resolve_download<'a>(
+        &'a self,
+        version: &'a ModrinthVersion,
+    ) -> BoxFuture<'a, Result<ModrinthFile, String>> {
+        Box::pin(async move {
+            version
+                .files
+                .iter()
+                .find(|f| f.primary)
+                .or_else(|| version.files.first())
+                .cloned()
+                .ok_or_else(|| "No download file found".to_string())
+        })
+    }
+}
+
+/// Return the [`ContentSource`] named by a `ModMetadata.source` /
+/// search-command `source` string. Unrecognized values fall back to
+/// Modrinth, the default source everywhere else in the crate.
+pub fn content_source_for(source: &str) -> Box<dyn ContentSource> {
+    match source {
+        "curseforge" => Box::new(CurseForgeSource),
+        _ => Box::new(ModrinthSource),
+    }
+}
+
+pub struct ModrinthSource;
+
+impl ContentSource for ModrinthSource {
+    fn search<'a>(
+        &'a self,
+        query: String,
+        content_type: String,
+        version: String,
+        loader: String,
+        offset: u32,
+        limit: u32,
+    ) -> BoxFuture<'a, Result<ModrinthSearchResponse, String>> {
+        Box::pin(async move {
+            modrinth::search_modrinth(
+                query,
+                content_type,
+                Vec::new(),
+                "relevance".to_string(),
+                version,
+                loader,
+                offset,
+                limit,
+            )
+            .await
+        })
+    }
+
+    fn get_project<'a>(
+        &'a self,
+        project_id: String,
+    ) -> BoxFuture<'a, Result<ModrinthProject, String>> {
+        Box::pin(async move { modrinth::get_modrinth_project(project_id).await })
+    }
+
+    fn get_versions<'a>(
+        &'a self,
+        project_id: String,
+        game_version: Option<String>,
+        loader: Option<String>,
+    ) -> BoxFuture<'a, Result<Vec<ModrinthVersion>, String>> {
+        Box::pin(
+            async move { modrinth::get_modrinth_versions(project_id, game_version, loader).await },
+        )
+    }
+}
+
+pub struct CurseForgeSource;
+
+impl CurseForgeSource {
+    /// CurseForge file hash entries carry an `algo` discriminator (`1` =
+    /// sha1, `2` = md5); the crate's hash verification only understands
+    /// sha1/sha512, so that's the only one worth pulling out. sha512 is left
+    /// empty — `verify_modrinth_hashes` already falls back to sha1 when
+    /// sha512 doesn't match.
+    fn file_hashes(file: &curseforge::CurseForgeFile) -> ModrinthHashes {
+        let sha1 = file
+            .hashes
+            .iter()
+            .find(|h| h.algo == 1)
+            .map(|h| h.value.clone())
+            .unwrap_or_default();
+        ModrinthHashes {
+            sha1,
+            sha512: String::new(),
+        }
+    }
+
+    fn to_modrinth_version(project_id: &str, file: curseforge::CurseForgeFile) -> ModrinthVersion {
+        let hashes = Self::file_hashes(&file);
+        ModrinthVersion {
+            id: file.id.to_string(),
+            project_id: project_id.to_string(),
+            name: file.file_name.clone(),
+            version_number: file.file_name.clone(),
+            changelog: None,
+            game_versions: file.game_versions.clone(),
+            loaders: vec!["fabric".to_string()],
+            files: vec![ModrinthFile {
+                url: file.download_url.clone().unwrap_or_default(),
+                filename: file.file_name.clone(),
+                primary: true,
+                size: 0,
+                hashes,
+            }],
+            downloads: 0,
+            date_published: String::new(),
+            dependencies: Vec::new(),
+        }
+    }
+
+    fn to_search_result(
+        content_type: &str,
+        result: curseforge::CurseForgeSearchResult,
+    ) -> ModrinthSearchResult {
+        let categories: Vec<String> = result.categories.iter().map(|c| c.name.clone()).collect();
+        ModrinthSearchResult {
+            slug: result.slug,
+            title: result.name,
+            description: result.summary,
+            categories: categories.clone(),
+            client_side: "unknown".to_string(),
+            server_side: "unknown".to_string(),
+            project_type: content_type.to_string(),
+            downloads: result.downloads,
+            icon_url: result.logo.map(|l| l.url),
+            color: None,
+            author: result
+                .authors
+                .first()
+                .map(|a| a.name.clone())
+                .unwrap_or_default(),
+            display_categories: categories,
+            versions: Vec::new(),
+            follows: 0,
+            date_created: result.date_created.unwrap_or_default(),
+            date_modified: result.date_modified.unwrap_or_default(),
+            latest_version: None,
+            license: String::new(),
+            gallery: Vec::new(),
+            project_id: result.id.to_string(),
+        }
+    }
+}
+
+impl ContentSource for CurseForgeSource {
+    fn search<'a>(
+        &'a self,
+        query: String,
+        content_type: String,
+        version: String,
+        _loader: String,
+        offset: u32,
+        limit: u32,
+    ) -> BoxFuture<'a, Result<ModrinthSearchResponse, String>> {
+        Box::pin(async move {
+            let response = curseforge::search_curseforge(
+                query,
+                content_type.clone(),
+                None,
+                "downloads".to_string(),
+                version,
+                offset,
+                limit,
+            )
+            .await?;
+
+            Ok(ModrinthSearchResponse {
+                hits: response
+                    .data
+                    .into_iter()
+                    .map(|r| Self::to_search_result(&content_type, r))
+                    .collect(),
+                offset: response.pagination.index,
+                limit: response.pagination.page_size,
+                total_hits: response.pagination.total_count,
+            })
+        })
+    }
+
+    fn get_project<'a>(
+        &'a self,
+        project_id: String,
+    ) -> BoxFuture<'a, Result<ModrinthProject, String>> {
+        Box::pin(async move {
+            let id: i32 = project_id
+                .parse()
+                .map_err(|_| format!("Invalid CurseForge project id: {}", project_id))?;
+            let result = curseforge::get_project(id).await?;
+
+            Ok(ModrinthProject {
+                id: result.id.to_string(),
+                slug: result.slug,
+                title: result.name,
+                description: result.summary,
+                categories: result.categories.iter().map(|c| c.name.clone()).collect(),
+                client_side: "unknown".to_string(),
+                server_side: "unknown".to_string(),
+                project_type: "mod".to_string(),
+                downloads: result.downloads,
+                icon_url: result.logo.map(|l| l.url),
+                color: None,
+                author: result
+                    .authors
+                    .first()
+                    .map(|a| a.name.clone())
+                    .unwrap_or_default(),
+                display_categories: Vec::new(),
+                versions: Vec::new(),
+                follows: 0,
+                date_created: result.date_created.unwrap_or_default(),
+                date_modified: result.date_modified.unwrap_or_default(),
+                latest_version: None,
+                license: String::new(),
+                gallery: Vec::new(),
+            })
+        })
+    }
+
+    fn get_versions<'a>(
+        &'a self,
+        project_id: String,
+        game_version: Option<String>,
+        _loader: Option<String>,
+    ) -> BoxFuture<'a, Result<Vec<ModrinthVersion>, String>> {
+        Box::pin(async move {
+            let id: i32 = project_id
+                .parse()
+                .map_err(|_| format!("Invalid CurseForge project id: {}", project_id))?;
+            let game_version = game_version.unwrap_or_default();
+            let files = curseforge::get_mod_files(id, &game_version).await?;
+
+            Ok(files
+                .into_iter()
+                .map(|f| Self::to_modrinth_version(&project_id, f))
+                .collect())
+        })
+    }
+}
+
+/// Search a content source by name (`"modrinth"` | `"curseforge"`) and
+/// return results in the crate's native, Modrinth-shaped response.
+#[tauri::command]
+pub async fn search_content(
+    source: String,
+    query: String,
+    content_type: String,
+    version: String,
+    loader: String,
+    offset: u32,
+    limit: u32,
+) -> Result<ModrinthSearchResponse, String> {
+    content_source_for(&source)
+        .search(query, content_type, version, loader, offset, limit)
+        .await
+}
@@ -2,11 +2,30 @@ use reqwest::header::{HeaderMap, HeaderValue, ACCEPT};
 use serde::{Deserialize, Serialize};
 use tauri::Manager;
 
+use super::retry::{send_with_retry, RetryConfig};
+
 const CURSEFORGE_API_KEY: &str = "$2a$10$JerFj3jTqK5z2SJlzO4i.e0/7O3wSdh27GyM4vHIRinf7VJvuJnfe";
 const CURSEFORGE_API_BASE: &str = "https://api.curseforge.com/v1";
 const MINECRAFT_GAME_ID: i32 = 432;
 const FABRIC_MOD_LOADER_TYPE: i32 = 4;
 
+// CurseForge's `ModLoaderType` enum, for filtering files by loader.
+const FORGE_MOD_LOADER_TYPE: i32 = 1;
+const QUILT_MOD_LOADER_TYPE: i32 = 5;
+const NEOFORGE_MOD_LOADER_TYPE: i32 = 6;
+
+/// Map this launcher's loader names to CurseForge's `ModLoaderType`, falling
+/// back to Fabric (the only loader the rest of this module assumed until
+/// now) for anything unrecognized.
+fn loader_to_curseforge_type(loader: &str) -> i32 {
+    match loader.to_lowercase().as_str() {
+        "forge" => FORGE_MOD_LOADER_TYPE,
+        "quilt" => QUILT_MOD_LOADER_TYPE,
+        "neoforge" => NEOFORGE_MOD_LOADER_TYPE,
+        _ => FABRIC_MOD_LOADER_TYPE,
+    }
+}
+
 // CurseForge class IDs for different content types
 const CLASS_MODS: i32 = 6;
 const CLASS_RESOURCE_PACKS: i32 = 12;
@@ -87,6 +106,8 @@ pub struct CurseForgePagination {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CurseForgeFile {
     pub id: i32,
+    #[serde(rename = "modId", default)]
+    pub mod_id: i32,
     #[serde(rename = "fileName")]
     pub file_name: String,
     #[serde(rename = "downloadUrl")]
@@ -95,6 +116,40 @@ pub struct CurseForgeFile {
     pub game_versions: Vec<String>,
     #[serde(rename = "modLoader")]
     pub mod_loader: Option<i32>,
+    #[serde(rename = "fileFingerprint")]
+    pub file_fingerprint: i64,
+    #[serde(rename = "fileDate", default)]
+    pub file_date: String,
+    #[serde(default)]
+    pub hashes: Vec<CurseForgeFileHash>,
+    #[serde(default)]
+    pub dependencies: Vec<CurseForgeFileDependency>,
+}
+
+/// One entry of a file's `dependencies` array. `relation_type` is `3` for
+/// `RequiredDependency` and `2` for `Optional` - only required dependencies
+/// are followed by [`resolve_curseforge_dependencies`].
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CurseForgeFileDependency {
+    #[serde(rename = "modId")]
+    pub mod_id: i32,
+    #[serde(rename = "relationType")]
+    pub relation_type: i32,
+}
+
+const RELATION_REQUIRED_DEPENDENCY: i32 = 3;
+
+/// How many dependency levels [`resolve_curseforge_dependencies`] will
+/// follow before giving up, in case of a malformed or cyclic graph.
+const MAX_DEPENDENCY_DEPTH: u32 = 5;
+
+/// One entry of CurseForge's per-file `hashes` array. `algo` is `1` for
+/// sha1 and `2` for md5 — sha1 is the only one the rest of the pipeline
+/// understands, so everything else is just carried along unused.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CurseForgeFileHash {
+    pub value: String,
+    pub algo: i32,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -137,16 +192,10 @@ pub async fn get_file_by_id(project_id: i64, file_id: i64) -> Result<CurseForgeF
         file_id
     );
 
-    let response = client
-        .get(&url)
-        .send()
+    let response = send_with_retry(|| client.get(&url), &RetryConfig::default())
         .await
         .map_err(|e| format!("Failed to fetch file: {}", e))?;
 
-    if !response.status().is_success() {
-        return Err(format!("Failed to fetch file: HTTP {}", response.status()));
-    }
-
     #[derive(Deserialize)]
     struct FileResponse {
         data: CurseForgeFile,
@@ -160,20 +209,136 @@ pub async fn get_file_by_id(project_id: i64, file_id: i64) -> Result<CurseForgeF
     Ok(file_response.data)
 }
 
-/// Download a file directly by URL, returns the bytes
-pub async fn download_file_bytes(url: &str) -> Result<Vec<u8>, String> {
+/// Get a project's listing metadata by its CurseForge mod id
+pub async fn get_project(project_id: i32) -> Result<CurseForgeSearchResult, String> {
     let client = create_curseforge_client()?;
+    let url = format!("{}/mods/{}", CURSEFORGE_API_BASE, project_id);
 
-    let response = client
-        .get(url)
-        .send()
+    let response = send_with_retry(|| client.get(&url), &RetryConfig::default())
         .await
-        .map_err(|e| format!("Failed to download: {}", e))?;
+        .map_err(|e| format!("Failed to get project: {}", e))?;
 
-    if !response.status().is_success() {
-        return Err(format!("Download failed: HTTP {}", response.status()));
+    #[derive(Deserialize)]
+    struct ProjectResponse {
+        data: CurseForgeSearchResult,
+    }
+
+    let project_response: ProjectResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse project: {}", e))?;
+
+    Ok(project_response.data)
+}
+
+/// CurseForge's variant of Murmur2 (seed 1): whitespace bytes (tab, newline,
+/// carriage return, space) are stripped from the buffer before hashing, since
+/// that's what their own fingerprinting service does to normalize mod jars.
+pub fn murmur2_fingerprint(bytes: &[u8]) -> i32 {
+    const M: u32 = 0x5bd1e995;
+    const SEED: u32 = 1;
+
+    let filtered: Vec<u8> = bytes
+        .iter()
+        .copied()
+        .filter(|&b| !matches!(b, 0x9 | 0xa | 0xd | 0x20))
+        .collect();
+
+    let mut hash: u32 = SEED ^ (filtered.len() as u32);
+    let mut chunks = filtered.chunks_exact(4);
+
+    for chunk in &mut chunks {
+        let mut k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        k = k.wrapping_mul(M);
+        k ^= k >> 24;
+        k = k.wrapping_mul(M);
+        hash = hash.wrapping_mul(M);
+        hash ^= k;
+    }
+
+    let remainder = chunks.remainder();
+    match remainder.len() {
+        3 => {
+            hash ^= (remainder[2] as u32) << 16;
+            hash ^= (remainder[1] as u32) << 8;
+            hash ^= remainder[0] as u32;
+            hash = hash.wrapping_mul(M);
+        }
+        2 => {
+            hash ^= (remainder[1] as u32) << 8;
+            hash ^= remainder[0] as u32;
+            hash = hash.wrapping_mul(M);
+        }
+        1 => {
+            hash ^= remainder[0] as u32;
+            hash = hash.wrapping_mul(M);
+        }
+        _ => {}
     }
 
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(M);
+    hash ^= hash >> 15;
+
+    hash as i32
+}
+
+#[derive(Debug, Deserialize)]
+struct FingerprintMatchesResponse {
+    data: FingerprintMatchesData,
+}
+
+#[derive(Debug, Deserialize)]
+struct FingerprintMatchesData {
+    #[serde(rename = "exactMatches")]
+    exact_matches: Vec<FingerprintMatch>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FingerprintMatch {
+    id: i32,
+    file: CurseForgeFile,
+}
+
+/// Look up mods by Murmur2 fingerprint in bulk, the same mechanism
+/// CurseForge's own launcher uses to identify jars it didn't download
+/// itself. Returns `(project_id, file)` for every exact match; unmatched
+/// fingerprints are simply absent from the result.
+pub async fn get_files_by_fingerprints(
+    fingerprints: &[i64],
+) -> Result<Vec<(i32, CurseForgeFile)>, String> {
+    let client = create_curseforge_client()?;
+    let url = format!("{}/fingerprints", CURSEFORGE_API_BASE);
+    let body = serde_json::json!({ "fingerprints": fingerprints });
+
+    let response = send_with_retry(
+        || client.post(&url).json(&body),
+        &RetryConfig::default(),
+    )
+    .await
+    .map_err(|e| format!("Failed to match fingerprints: {}", e))?;
+
+    let parsed: FingerprintMatchesResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse fingerprint matches: {}", e))?;
+
+    Ok(parsed
+        .data
+        .exact_matches
+        .into_iter()
+        .map(|m| (m.id, m.file))
+        .collect())
+}
+
+/// Download a file directly by URL, returns the bytes
+pub async fn download_file_bytes(url: &str) -> Result<Vec<u8>, String> {
+    let client = create_curseforge_client()?;
+
+    let response = send_with_retry(|| client.get(url), &RetryConfig::default())
+        .await
+        .map_err(|e| format!("Download failed: {}", e))?;
+
     let bytes = response
         .bytes()
         .await
@@ -196,37 +361,84 @@ pub async fn get_mod_files(
         minecraft_version
     );
 
-    let response = client
-        .get(&url)
-        .send()
-        .await
-        .map_err(|e| format!("Failed to fetch mod files: {}", e))?;
+    // This endpoint frequently returns a successful response with an empty
+    // `data` array transiently, which `send_with_retry` (status/transport
+    // retries only) wouldn't catch - so retry the whole request-and-parse
+    // on an empty result too, up to the same attempt budget.
+    let config = RetryConfig::default();
+
+    for attempt in 0..config.max_attempts {
+        let response = send_with_retry(|| client.get(&url), &config)
+            .await
+            .map_err(|e| format!("Failed to fetch mod files: {}", e))?;
+
+        let files_response: CurseForgeFilesResponse = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse files response: {}", e))?;
+
+        if files_response.data.is_empty() && attempt + 1 < config.max_attempts {
+            tracing::warn!(
+                "CurseForge returned no files for project {} (attempt {}/{}), retrying",
+                project_id,
+                attempt + 1,
+                config.max_attempts
+            );
+            let backoff = config
+                .base_delay
+                .saturating_mul(2u32.saturating_pow(attempt))
+                .min(config.max_delay);
+            tokio::time::sleep(backoff).await;
+            continue;
+        }
 
-    if !response.status().is_success() {
-        return Err(format!(
-            "Failed to fetch mod files: HTTP {}",
-            response.status()
-        ));
+        // Filter files that match the Minecraft version and are for Fabric
+        let compatible_files: Vec<CurseForgeFile> = files_response
+            .data
+            .into_iter()
+            .filter(|file| {
+                file.game_versions.contains(&minecraft_version.to_string())
+                    && file
+                        .mod_loader
+                        .map_or(false, |loader| loader == FABRIC_MOD_LOADER_TYPE)
+            })
+            .collect();
+
+        return Ok(compatible_files);
     }
 
+    Ok(Vec::new())
+}
+
+/// Like [`get_mod_files`] but filtered by an arbitrary loader instead of
+/// being hardcoded to Fabric, for callers (the update checker) that need to
+/// respect the profile's actual loader.
+pub async fn get_mod_files_for_loader(
+    project_id: i32,
+    minecraft_version: &str,
+    loader: &str,
+) -> Result<Vec<CurseForgeFile>, String> {
+    let client = create_curseforge_client()?;
+    let url = format!("{}/mods/{}/files", CURSEFORGE_API_BASE, project_id);
+    let loader_type = loader_to_curseforge_type(loader);
+
+    let response = send_with_retry(|| client.get(&url), &RetryConfig::default())
+        .await
+        .map_err(|e| format!("Failed to fetch mod files: {}", e))?;
+
     let files_response: CurseForgeFilesResponse = response
         .json()
         .await
         .map_err(|e| format!("Failed to parse files response: {}", e))?;
 
-    // Filter files that match the Minecraft version and are for Fabric
-    let compatible_files: Vec<CurseForgeFile> = files_response
+    Ok(files_response
         .data
         .into_iter()
         .filter(|file| {
             file.game_versions.contains(&minecraft_version.to_string())
-                && file
-                    .mod_loader
-                    .map_or(false, |loader| loader == FABRIC_MOD_LOADER_TYPE)
+                && file.mod_loader.map_or(false, |l| l == loader_type)
         })
-        .collect();
-
-    Ok(compatible_files)
+        .collect())
 }
 
 pub async fn check_mod_compatibility(
@@ -336,6 +548,22 @@ pub async fn search_curseforge(
     Ok(search_result)
 }
 
+/// Result of [`download_curseforge_mod`]: the message describing the
+/// requested install, plus the filenames of any `RequiredDependency` files
+/// [`resolve_curseforge_dependencies`] had to pull in alongside it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurseForgeInstallResult {
+    pub message: String,
+    #[serde(default)]
+    pub dependencies_installed: Vec<String>,
+    /// The installed file's name and CurseForge file id, so callers can pin
+    /// it in the `ModMetadataIndex` for later update checks.
+    #[serde(default)]
+    pub file_name: String,
+    #[serde(default)]
+    pub file_id: i32,
+}
+
 /// Download and install content from CurseForge
 #[tauri::command]
 pub async fn download_curseforge_content(
@@ -344,11 +572,13 @@ pub async fn download_curseforge_content(
     content_type: String,
     game_version: String,
     profile_id: Option<String>,
-) -> Result<String, String> {
+) -> Result<CurseForgeInstallResult, String> {
     let game_dir = dirs::data_dir()
         .unwrap_or_else(|| std::path::PathBuf::from("."))
         .join("MiracleClient");
 
+    let metadata_profile_id = profile_id.clone();
+
     let dest_dir = match content_type.as_str() {
         "mod" => {
             if let Some(pid) = profile_id {
@@ -395,14 +625,53 @@ pub async fn download_curseforge_content(
         _ => return Err(format!("Unknown content type: {}", content_type)),
     };
 
-    download_curseforge_mod(project_id, &game_version, &dest_dir).await
+    let result = download_curseforge_mod(project_id, &game_version, &dest_dir).await?;
+
+    // Pin it in the ModMetadataIndex for mods, same as the Modrinth install
+    // path, so it shows up in update checks (see chunk16-5's fingerprint
+    // matching in check_mod_updates).
+    if content_type == "mod" {
+        if let Some(pid) = metadata_profile_id {
+            let loader = {
+                let state = app.state::<super::AppState>();
+                let manager = state.profile_manager.lock().unwrap();
+                manager
+                    .get_profile(&pid)
+                    .map(|p| p.loader.clone())
+                    .unwrap_or_else(|| "fabric".to_string())
+            };
+
+            let metadata = super::mod_updates::ModMetadata {
+                source: "curseforge".to_string(),
+                project_slug: project_id.to_string(),
+                project_id: project_id.to_string(),
+                installed_version: result.file_name.clone(),
+                version_id: result.file_id.to_string(),
+                installed_at: chrono::Utc::now().to_rfc3339(),
+                repo_base: None,
+                asset_pattern: None,
+                loader_fallback: None,
+                loader,
+            };
+            if let Err(e) = super::mod_updates::update_mod_metadata(
+                &game_version,
+                &pid,
+                &result.file_name,
+                metadata,
+            ) {
+                tracing::warn!("Failed to save CurseForge mod metadata: {}", e);
+            }
+        }
+    }
+
+    Ok(result)
 }
 
 pub async fn download_curseforge_mod(
     project_id: i32,
     minecraft_version: &str,
     mods_dir: &std::path::Path,
-) -> Result<String, String> {
+) -> Result<CurseForgeInstallResult, String> {
     let files = get_mod_files(project_id, minecraft_version).await?;
 
     if files.is_empty() {
@@ -424,7 +693,15 @@ pub async fn download_curseforge_mod(
 
     // Check if already downloaded
     if mod_path.exists() {
-        return Ok(format!("Mod already installed: {}", latest_file.file_name));
+        let dependencies_installed =
+            resolve_curseforge_dependencies(latest_file, minecraft_version, mods_dir, project_id)
+                .await;
+        return Ok(CurseForgeInstallResult {
+            message: format!("Mod already installed: {}", latest_file.file_name),
+            dependencies_installed,
+            file_name: latest_file.file_name.clone(),
+            file_id: latest_file.id,
+        });
     }
 
     tracing::info!("Downloading CurseForge mod: {}", latest_file.file_name);
@@ -460,5 +737,110 @@ pub async fn download_curseforge_mod(
         "CurseForge mod downloaded successfully: {}",
         latest_file.file_name
     );
-    Ok(format!("Successfully installed: {}", latest_file.file_name))
+
+    let dependencies_installed =
+        resolve_curseforge_dependencies(latest_file, minecraft_version, mods_dir, project_id).await;
+
+    Ok(CurseForgeInstallResult {
+        message: format!("Successfully installed: {}", latest_file.file_name),
+        dependencies_installed,
+        file_name: latest_file.file_name.clone(),
+        file_id: latest_file.id,
+    })
+}
+
+/// Walk `file`'s `RequiredDependency` graph breadth-first, fetching each
+/// dependency's latest compatible file for `minecraft_version` via
+/// [`get_mod_files`] and downloading it into `mods_dir` alongside `file`
+/// itself. Dedupes by project id (starting from `root_project_id`) and
+/// caps recursion at [`MAX_DEPENDENCY_DEPTH`] levels, so a cyclic or
+/// unexpectedly deep dependency graph can't recurse forever. Failures on
+/// an individual dependency are logged and skipped rather than failing
+/// the whole install.
+async fn resolve_curseforge_dependencies(
+    file: &CurseForgeFile,
+    minecraft_version: &str,
+    mods_dir: &std::path::Path,
+    root_project_id: i32,
+) -> Vec<String> {
+    let mut visited = std::collections::HashSet::new();
+    visited.insert(root_project_id);
+
+    let mut queue: std::collections::VecDeque<(i32, u32)> = file
+        .dependencies
+        .iter()
+        .filter(|d| d.relation_type == RELATION_REQUIRED_DEPENDENCY)
+        .map(|d| (d.mod_id, 1))
+        .collect();
+
+    let mut installed = Vec::new();
+
+    while let Some((project_id, depth)) = queue.pop_front() {
+        if depth > MAX_DEPENDENCY_DEPTH || !visited.insert(project_id) {
+            continue;
+        }
+
+        let files = match get_mod_files(project_id, minecraft_version).await {
+            Ok(files) => files,
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to fetch CurseForge dependency {}: {}",
+                    project_id,
+                    e
+                );
+                continue;
+            }
+        };
+
+        let Some(dep_file) = files.first() else {
+            tracing::warn!(
+                "No compatible file found for CurseForge dependency {}",
+                project_id
+            );
+            continue;
+        };
+
+        let Some(download_url) = &dep_file.download_url else {
+            tracing::warn!(
+                "No download URL for CurseForge dependency file {}",
+                dep_file.file_name
+            );
+            continue;
+        };
+
+        let dep_path = mods_dir.join(&dep_file.file_name);
+        if !dep_path.exists() {
+            match download_file_bytes(download_url).await {
+                Ok(bytes) => {
+                    if let Err(e) = tokio::fs::write(&dep_path, &bytes).await {
+                        tracing::warn!(
+                            "Failed to write CurseForge dependency {}: {}",
+                            dep_file.file_name,
+                            e
+                        );
+                        continue;
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to download CurseForge dependency {}: {}",
+                        dep_file.file_name,
+                        e
+                    );
+                    continue;
+                }
+            }
+        }
+
+        tracing::info!("Installed CurseForge dependency: {}", dep_file.file_name);
+        installed.push(dep_file.file_name.clone());
+
+        for dep in &dep_file.dependencies {
+            if dep.relation_type == RELATION_REQUIRED_DEPENDENCY {
+                queue.push_back((dep.mod_id, depth + 1));
+            }
+        }
+    }
+
+    installed
 }
@@ -1,9 +1,13 @@
+use futures::future::BoxFuture;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::Path;
+use tauri::{AppHandle, Emitter};
 use zip::ZipArchive;
 
+use super::retry::{send_with_retry, RetryConfig};
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct ModDependency {
     pub mod_id: String,
@@ -17,12 +21,45 @@ pub struct FabricModJson {
     pub name: Option<String>,
     pub depends: Option<HashMap<String, String>>,
     pub recommends: Option<HashMap<String, String>>,
+    pub breaks: Option<HashMap<String, String>>,
+    /// Mutually-incompatible ids, same predicate format as `breaks` - Fabric
+    /// treats the two fields identically (both flag a version range of
+    /// another mod as incompatible), so they're evaluated the same way in
+    /// [`resolve_dependencies`].
+    #[serde(default)]
+    pub conflicts: Option<HashMap<String, String>>,
+    /// Extra ids this jar also satisfies (e.g. a rebrand or merged mod).
+    #[serde(default)]
+    pub provides: Option<Vec<String>>,
+    /// Jars embedded in this one (Fabric's jar-in-jar mechanism, e.g. how
+    /// Fabric API ships its sub-modules), each resolvable by `file` path
+    /// inside this jar's own zip.
+    #[serde(default)]
+    pub jars: Option<Vec<FabricEmbeddedJar>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FabricEmbeddedJar {
+    pub file: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DependencyResolutionResult {
     pub missing_dependencies: Vec<ModDependency>,
     pub installed_mods: Vec<String>,
+    /// `(mod_id, offending_id)` pairs where `mod_id`'s `breaks`/`conflicts`
+    /// predicate matches the version of `offending_id` actually installed.
+    pub conflicts: Vec<(String, String)>,
+}
+
+/// Read and parse `fabric.mod.json` out of an already-open zip archive.
+fn read_fabric_mod_json<R: std::io::Read + std::io::Seek>(
+    archive: &mut ZipArchive<R>,
+) -> Option<FabricModJson> {
+    let mut mod_json_file = archive.by_name("fabric.mod.json").ok()?;
+    let mut contents = String::new();
+    std::io::Read::read_to_string(&mut mod_json_file, &mut contents).ok()?;
+    serde_json::from_str(&contents).ok()
 }
 
 /// Parse fabric.mod.json from a jar file
@@ -30,19 +67,48 @@ pub fn parse_mod_metadata(jar_path: &Path) -> Result<FabricModJson, String> {
     let file = fs::File::open(jar_path).map_err(|e| format!("Failed to open jar: {}", e))?;
     let mut archive = ZipArchive::new(file).map_err(|e| format!("Failed to read zip: {}", e))?;
 
-    // Try to find fabric.mod.json
-    let mut mod_json_file = archive
-        .by_name("fabric.mod.json")
-        .map_err(|_| "fabric.mod.json not found in jar".to_string())?;
+    read_fabric_mod_json(&mut archive).ok_or_else(|| "fabric.mod.json not found in jar".to_string())
+}
 
-    let mut contents = String::new();
-    std::io::Read::read_to_string(&mut mod_json_file, &mut contents)
-        .map_err(|e| format!("Failed to read fabric.mod.json: {}", e))?;
+/// Parse a jar's `fabric.mod.json`, plus every id it satisfies as a
+/// dependency target: its own `id`, its `provides` aliases, and the
+/// `id`/`provides` of any jar embedded via its `jars` array - so a library
+/// bundled inside another mod (e.g. Fabric API sub-modules) isn't reported
+/// as a missing dependency just because it has no jar of its own.
+fn scan_mod_jar(jar_path: &Path) -> Option<(FabricModJson, HashSet<String>)> {
+    let file = fs::File::open(jar_path).ok()?;
+    let mut archive = ZipArchive::new(file).ok()?;
+    let metadata = read_fabric_mod_json(&mut archive)?;
+
+    let mut ids = HashSet::new();
+    ids.insert(metadata.id.clone());
+    ids.extend(metadata.provides.iter().flatten().cloned());
+
+    for embedded in metadata.jars.iter().flatten() {
+        let bytes = {
+            let Ok(mut nested_file) = archive.by_name(&embedded.file) else {
+                continue;
+            };
+            let mut buf = Vec::new();
+            if std::io::Read::read_to_end(&mut nested_file, &mut buf).is_err() {
+                continue;
+            }
+            buf
+        };
+
+        if let Ok(mut nested_archive) = ZipArchive::new(std::io::Cursor::new(bytes)) {
+            if let Some(nested_metadata) = read_fabric_mod_json(&mut nested_archive) {
+                ids.insert(nested_metadata.id);
+                ids.extend(nested_metadata.provides.into_iter().flatten());
+            }
+        }
+    }
 
-    serde_json::from_str(&contents).map_err(|e| format!("Failed to parse fabric.mod.json: {}", e))
+    Some((metadata, ids))
 }
 
-/// Get all installed mod IDs from a directory
+/// Get all installed mod IDs from a directory, including `provides` aliases
+/// and embedded jar-in-jar ids (see [`scan_mod_jar`]).
 pub fn get_installed_mod_ids(mods_dir: &Path) -> Result<HashSet<String>, String> {
     let mut installed = HashSet::new();
 
@@ -58,8 +124,8 @@ pub fn get_installed_mod_ids(mods_dir: &Path) -> Result<HashSet<String>, String>
         let path = entry.path();
 
         if path.extension().and_then(|s| s.to_str()) == Some("jar") {
-            if let Ok(metadata) = parse_mod_metadata(&path) {
-                installed.insert(metadata.id);
+            if let Some((_, ids)) = scan_mod_jar(&path) {
+                installed.extend(ids);
             }
         }
     }
@@ -67,12 +133,24 @@ pub fn get_installed_mod_ids(mods_dir: &Path) -> Result<HashSet<String>, String>
     Ok(installed)
 }
 
-/// Resolve dependencies for all mods in a directory
+/// Resolve dependencies for all mods in a directory. A dependency counts as
+/// missing when its id (or an alias it's provided/embedded under, see
+/// [`scan_mod_jar`]) isn't installed at all, or when it's installed under
+/// its own id but that jar's `FabricModJson.version` doesn't satisfy the
+/// declared `version_requirement` (see [`version_satisfies`]) - an
+/// installed-but-too-old mod is not a satisfied dependency. Aliased ids have
+/// no version of their own to check, so presence alone satisfies them.
 pub fn resolve_dependencies(mods_dir: &Path) -> Result<DependencyResolutionResult, String> {
-    let installed_ids = get_installed_mod_ids(mods_dir)?;
+    let mut installed_versions: HashMap<String, String> = HashMap::new();
+    let mut installed_ids: HashSet<String> = HashSet::new();
     let mut all_required_deps = HashSet::new();
+    // (mod_id, offending_id, version_requirement) for every breaks/conflicts
+    // entry declared by an installed mod, checked against the final
+    // installed_versions/installed_ids once the scan is complete.
+    let mut incompatibilities: Vec<(String, String, String)> = Vec::new();
 
-    // Scan all mods and collect their dependencies
+    // Scan all mods, recording each one's version/provided ids and
+    // collecting their dependencies, in a single pass.
     let entries =
         fs::read_dir(mods_dir).map_err(|e| format!("Failed to read mods directory: {}", e))?;
 
@@ -81,9 +159,12 @@ pub fn resolve_dependencies(mods_dir: &Path) -> Result<DependencyResolutionResul
         let path = entry.path();
 
         if path.extension().and_then(|s| s.to_str()) == Some("jar") {
-            if let Ok(metadata) = parse_mod_metadata(&path) {
+            if let Some((metadata, ids)) = scan_mod_jar(&path) {
+                installed_versions.insert(metadata.id.clone(), metadata.version.clone());
+                installed_ids.extend(ids);
+
                 // Add required dependencies
-                if let Some(depends) = metadata.depends {
+                if let Some(depends) = &metadata.depends {
                     for (dep_id, version_req) in depends {
                         // Skip minecraft, java, and fabricloader as these are handled separately
                         if dep_id == "minecraft" || dep_id == "java" || dep_id == "fabricloader" {
@@ -91,144 +172,620 @@ pub fn resolve_dependencies(mods_dir: &Path) -> Result<DependencyResolutionResul
                         }
 
                         all_required_deps.insert(ModDependency {
-                            mod_id: dep_id,
-                            version_requirement: Some(version_req),
+                            mod_id: dep_id.clone(),
+                            version_requirement: Some(version_req.clone()),
                         });
                     }
                 }
+
+                for (offending_id, version_req) in metadata
+                    .breaks
+                    .iter()
+                    .chain(metadata.conflicts.iter())
+                    .flatten()
+                {
+                    incompatibilities.push((
+                        metadata.id.clone(),
+                        offending_id.clone(),
+                        version_req.clone(),
+                    ));
+                }
             }
         }
     }
 
-    // Find missing dependencies
+    // Find missing dependencies: not installed under any id/alias, or
+    // installed under its own id at a version that doesn't satisfy the
+    // requirement.
     let missing_dependencies: Vec<ModDependency> = all_required_deps
         .into_iter()
-        .filter(|dep| !installed_ids.contains(&dep.mod_id))
+        .filter(|dep| match installed_versions.get(&dep.mod_id) {
+            Some(installed_version) => match &dep.version_requirement {
+                Some(req) => !version_satisfies(installed_version, req),
+                None => false,
+            },
+            None => !installed_ids.contains(&dep.mod_id),
+        })
+        .collect();
+
+    // A breaks/conflicts entry fires when the offending id is actually
+    // installed and (if it has a version of its own) that version matches
+    // the declared predicate - an aliased/provided id with no version of
+    // its own is treated as an unconditional match, same as `depends`.
+    let conflicts: Vec<(String, String)> = incompatibilities
+        .into_iter()
+        .filter(|(mod_id, offending_id, version_req)| {
+            mod_id != offending_id
+                && match installed_versions.get(offending_id) {
+                    Some(installed_version) => version_satisfies(installed_version, version_req),
+                    None => installed_ids.contains(offending_id),
+                }
+        })
+        .map(|(mod_id, offending_id, _)| (mod_id, offending_id))
         .collect();
 
     Ok(DependencyResolutionResult {
         missing_dependencies,
+        conflicts,
         installed_mods: installed_ids.into_iter().collect(),
     })
 }
 
-/// Download and install a mod from Modrinth
-pub async fn install_dependency(
-    mod_id: &str,
-    minecraft_version: &str,
-    mods_dir: &Path,
-) -> Result<(), String> {
-    let client = reqwest::Client::builder()
-        .timeout(std::time::Duration::from_secs(120))
-        .user_agent("MiracleClient/1.0")
-        .build()
-        .map_err(|e| e.to_string())?;
+/// A single component of a parsed Fabric version string: a concrete number,
+/// or an `x`/`X`/`*` wildcard that matches any value in that position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VersionComponent {
+    Num(u32),
+    Wildcard,
+}
 
-    tracing::info!(
-        "Resolving dependency: {} for Minecraft {}",
-        mod_id,
-        minecraft_version
-    );
+fn parse_component(s: &str) -> VersionComponent {
+    if s.eq_ignore_ascii_case("x") || s == "*" {
+        VersionComponent::Wildcard
+    } else {
+        VersionComponent::Num(s.parse().unwrap_or(0))
+    }
+}
 
-    // Search for the mod by ID
-    let search_url = format!("https://api.modrinth.com/v2/project/{}", mod_id);
+/// Split a version string into its numeric `major.minor.patch...` release
+/// components (with wildcards where a component is `x`/`X`/`*`), dropping
+/// any pre-release/build suffix (the part from the first `-` onward) -
+/// Fabric predicates only ever constrain the release portion.
+fn parse_release(v: &str) -> Vec<VersionComponent> {
+    v.split('-')
+        .next()
+        .unwrap_or(v)
+        .split('.')
+        .map(parse_component)
+        .collect()
+}
 
-    let project_response = client
-        .get(&search_url)
-        .send()
-        .await
-        .map_err(|e| format!("Failed to fetch project info: {}", e))?;
+/// Read the component at `index`, treating a missing trailing component (or
+/// a wildcard, for comparison purposes) as `0`.
+fn component_at(components: &[VersionComponent], index: usize) -> u32 {
+    match components.get(index) {
+        Some(VersionComponent::Num(n)) => *n,
+        _ => 0,
+    }
+}
 
-    if !project_response.status().is_success() {
-        return Err(format!(
-            "Mod '{}' not found on Modrinth (status: {})",
-            mod_id,
-            project_response.status()
-        ));
+/// Compare two release cores component-by-component, treating a missing
+/// trailing component as `0`.
+fn compare_release(a: &[VersionComponent], b: &[VersionComponent]) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    for i in 0..a.len().max(b.len()) {
+        match component_at(a, i).cmp(&component_at(b, i)) {
+            Ordering::Equal => continue,
+            other => return other,
+        }
     }
+    Ordering::Equal
+}
 
-    let project: serde_json::Value = project_response
-        .json()
-        .await
-        .map_err(|e| format!("Failed to parse project response: {}", e))?;
+/// Does `actual` equal `pattern`, where any `x`/`X`/`*` component in
+/// `pattern` matches any value in that position?
+fn matches_pattern(actual: &[VersionComponent], pattern: &[VersionComponent]) -> bool {
+    for i in 0..actual.len().max(pattern.len()) {
+        if let Some(VersionComponent::Wildcard) = pattern.get(i) {
+            continue;
+        }
+        if component_at(actual, i) != component_at(pattern, i) {
+            return false;
+        }
+    }
+    true
+}
 
-    let project_slug = project["slug"]
-        .as_str()
-        .ok_or_else(|| format!("Invalid project data for {}", mod_id))?;
+/// Evaluate one Fabric version-predicate term - `"*"`, an exact/wildcard
+/// version (`"1.2.3"`, `"1.2.x"`), or a comparator-prefixed version (`">="`,
+/// `">"`, `"<="`, `"<"`, `"~"` same major.minor with patch `>=`, `"^"` same
+/// major with the rest `>=`) - against an actual version string.
+fn term_satisfies(actual: &str, term: &str) -> bool {
+    if term.is_empty() || term == "*" {
+        return true;
+    }
 
-    // Get versions for this Minecraft version
-    let versions_url = format!(
-        "https://api.modrinth.com/v2/project/{}/version?game_versions=[\"{}\"]&loaders=[\"fabric\"]",
-        project_slug, minecraft_version
-    );
+    let (op, rest) = if let Some(r) = term.strip_prefix(">=") {
+        (">=", r)
+    } else if let Some(r) = term.strip_prefix("<=") {
+        ("<=", r)
+    } else if let Some(r) = term.strip_prefix('>') {
+        (">", r)
+    } else if let Some(r) = term.strip_prefix('<') {
+        ("<", r)
+    } else if let Some(r) = term.strip_prefix('~') {
+        ("~", r)
+    } else if let Some(r) = term.strip_prefix('^') {
+        ("^", r)
+    } else {
+        ("=", term)
+    };
+
+    let actual_release = parse_release(actual);
+    let pattern_release = parse_release(rest.trim());
+
+    match op {
+        "=" => matches_pattern(&actual_release, &pattern_release),
+        ">=" => compare_release(&actual_release, &pattern_release) != std::cmp::Ordering::Less,
+        ">" => compare_release(&actual_release, &pattern_release) == std::cmp::Ordering::Greater,
+        "<=" => compare_release(&actual_release, &pattern_release) != std::cmp::Ordering::Greater,
+        "<" => compare_release(&actual_release, &pattern_release) == std::cmp::Ordering::Less,
+        "~" => {
+            component_at(&actual_release, 0) == component_at(&pattern_release, 0)
+                && component_at(&actual_release, 1) == component_at(&pattern_release, 1)
+                && component_at(&actual_release, 2) >= component_at(&pattern_release, 2)
+        }
+        "^" => {
+            component_at(&actual_release, 0) == component_at(&pattern_release, 0)
+                && compare_release(&actual_release, &pattern_release) != std::cmp::Ordering::Less
+        }
+        _ => true,
+    }
+}
 
-    let versions_response = client
-        .get(&versions_url)
-        .send()
-        .await
-        .map_err(|e| format!("Failed to fetch versions: {}", e))?;
+/// Check a version string against a Fabric `version_requirement` predicate:
+/// a comma- or space-separated list of terms (`"*"`, exact, `>=`/`>`/`<=`/`<`/
+/// `~`/`^`, with `x`/`X`/`*` wildcard components), all of which must match
+/// (logical AND) - e.g. `">=1.2.0 <2.0.0"` or `"1.2.x"`.
+pub fn version_satisfies(actual: &str, requirement: &str) -> bool {
+    requirement
+        .split([',', ' '])
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .all(|term| term_satisfies(actual, term))
+}
 
-    let versions: Vec<serde_json::Value> = versions_response
-        .json()
-        .await
-        .map_err(|e| format!("Failed to parse versions: {}", e))?;
+/// Where to fetch a resolved dependency's bytes from: a URL to download (the
+/// common case), or bytes a source already had to fetch itself as part of
+/// resolving the coordinate (Maven has no separate "resolve" step).
+pub enum DownloadPayload {
+    Url(String),
+    Bytes(Vec<u8>),
+}
+
+/// One source's answer to "here's the jar for this dependency id", handed
+/// back to the shared verify/write step in [`download_target`].
+pub struct DownloadTarget {
+    pub filename: String,
+    pub sha512: Option<String>,
+    pub payload: DownloadPayload,
+}
 
-    if versions.is_empty() {
-        return Err(format!(
-            "No compatible version found for {} (Minecraft {})",
-            mod_id, minecraft_version
-        ));
+/// A place `install_dependency_from` can look up a dependency id. Modrinth
+/// and CurseForge can resolve a bare id on their own (both are searchable by
+/// slug); GitHub and Maven can't - there's no registry mapping a Fabric mod
+/// id to a repo or coordinate, so [`ExternalSource`] only ever answers for
+/// the one pinned coordinate it was built with, ignoring `mod_id` entirely.
+/// Wiring a GitHub/Maven fallback up therefore means the caller already
+/// knows which dependency id it applies to (e.g. from a manifest or
+/// previously-recorded `ModMetadata`), not automatic discovery.
+pub trait ModSource: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn resolve<'a>(
+        &'a self,
+        mod_id: &'a str,
+        minecraft_version: &'a str,
+        loader: &'a str,
+    ) -> BoxFuture<'a, Result<DownloadTarget, String>>;
+}
+
+/// Resolves a bare mod id against Modrinth's project/version API, picking
+/// the newest version whose `version_number` satisfies `version_requirement`
+/// (a Fabric predicate, see [`version_satisfies`]) instead of blindly taking
+/// the newest version overall. A `version_requirement` of `None` behaves
+/// like `"*"` - any version is acceptable.
+pub struct ModrinthSource {
+    pub version_requirement: Option<String>,
+}
+
+impl ModSource for ModrinthSource {
+    fn name(&self) -> &'static str {
+        "modrinth"
     }
 
-    // Get the latest version
-    let latest_version = &versions[0];
-    let files = latest_version["files"]
-        .as_array()
-        .ok_or_else(|| "No files found".to_string())?;
+    fn resolve<'a>(
+        &'a self,
+        mod_id: &'a str,
+        minecraft_version: &'a str,
+        loader: &'a str,
+    ) -> BoxFuture<'a, Result<DownloadTarget, String>> {
+        Box::pin(async move {
+            let client = reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(120))
+                .user_agent("MiracleClient/1.0")
+                .build()
+                .map_err(|e| e.to_string())?;
+
+            let search_url = format!("https://api.modrinth.com/v2/project/{}", mod_id);
+            let project_response =
+                send_with_retry(|| client.get(&search_url), &RetryConfig::default())
+                    .await
+                    .map_err(|e| format!("Mod '{}' not found on Modrinth ({})", mod_id, e))?;
+
+            let project: serde_json::Value = project_response
+                .json()
+                .await
+                .map_err(|e| format!("Failed to parse project response: {}", e))?;
+
+            let project_slug = project["slug"]
+                .as_str()
+                .ok_or_else(|| format!("Invalid project data for {}", mod_id))?;
+
+            let versions_url = format!(
+                "https://api.modrinth.com/v2/project/{}/version?game_versions=[\"{}\"]&loaders=[\"{}\"]",
+                project_slug, minecraft_version, loader
+            );
+
+            let versions_response =
+                send_with_retry(|| client.get(&versions_url), &RetryConfig::default())
+                    .await
+                    .map_err(|e| format!("Failed to fetch versions: {}", e))?;
+
+            let versions: Vec<serde_json::Value> = versions_response
+                .json()
+                .await
+                .map_err(|e| format!("Failed to parse versions: {}", e))?;
+
+            if versions.is_empty() {
+                return Err(format!(
+                    "No compatible version found for {} (Minecraft {})",
+                    mod_id, minecraft_version
+                ));
+            }
 
-    let primary_file = files
-        .iter()
-        .find(|f| f["primary"].as_bool().unwrap_or(false))
-        .or_else(|| files.first())
-        .ok_or_else(|| "No downloadable file found".to_string())?;
+            // Modrinth returns versions newest-first; take the newest one
+            // whose version_number satisfies the predicate, falling back to
+            // the newest overall if the requirement can't be read off any.
+            let chosen_version = self
+                .version_requirement
+                .as_deref()
+                .and_then(|req| {
+                    versions.iter().find(|v| {
+                        v["version_number"]
+                            .as_str()
+                            .is_some_and(|vn| version_satisfies(vn, req))
+                    })
+                })
+                .unwrap_or(&versions[0]);
+
+            let files = chosen_version["files"]
+                .as_array()
+                .ok_or_else(|| "No files found".to_string())?;
+
+            let primary_file = files
+                .iter()
+                .find(|f| f["primary"].as_bool().unwrap_or(false))
+                .or_else(|| files.first())
+                .ok_or_else(|| "No downloadable file found".to_string())?;
+
+            let url = primary_file["url"]
+                .as_str()
+                .ok_or_else(|| "No download URL".to_string())?
+                .to_string();
+            let filename = primary_file["filename"]
+                .as_str()
+                .ok_or_else(|| "No filename".to_string())?
+                .to_string();
+            let sha512 = primary_file["hashes"]["sha512"]
+                .as_str()
+                .map(str::to_string);
+
+            Ok(DownloadTarget {
+                filename,
+                sha512,
+                payload: DownloadPayload::Url(url),
+            })
+        })
+    }
+}
 
-    let download_url = primary_file["url"]
-        .as_str()
-        .ok_or_else(|| "No download URL".to_string())?;
+/// Resolves a bare mod id against CurseForge by matching it to a project's
+/// own slug, like `manifest.rs`'s `resolve_via_curseforge`. Only usable for
+/// Fabric, since `curseforge::get_mod_files` (like the rest of this
+/// launcher's CurseForge browsing) is itself Fabric-only.
+pub struct CurseForgeSource;
 
-    let filename = primary_file["filename"]
-        .as_str()
-        .ok_or_else(|| "No filename".to_string())?;
+impl ModSource for CurseForgeSource {
+    fn name(&self) -> &'static str {
+        "curseforge"
+    }
 
-    tracing::info!("Downloading dependency {} from {}", mod_id, download_url);
+    fn resolve<'a>(
+        &'a self,
+        mod_id: &'a str,
+        minecraft_version: &'a str,
+        loader: &'a str,
+    ) -> BoxFuture<'a, Result<DownloadTarget, String>> {
+        Box::pin(async move {
+            if loader != "fabric" {
+                return Err("CurseForge fallback is Fabric-only".to_string());
+            }
 
-    // Download the file
-    let file_response = client
-        .get(download_url)
-        .send()
-        .await
-        .map_err(|e| format!("Failed to download: {}", e))?;
+            let results = super::curseforge::search_curseforge(
+                mod_id.to_string(),
+                "mod".to_string(),
+                None,
+                "relevancy".to_string(),
+                minecraft_version.to_string(),
+                0,
+                10,
+            )
+            .await?;
+
+            let project = results
+                .data
+                .into_iter()
+                .find(|p| p.slug == mod_id)
+                .ok_or_else(|| format!("No CurseForge project found for slug {}", mod_id))?;
+
+            let file = super::curseforge::get_mod_files(project.id, minecraft_version)
+                .await?
+                .into_iter()
+                .next()
+                .ok_or_else(|| format!("No compatible file found for {}", mod_id))?;
+
+            let url = file
+                .download_url
+                .clone()
+                .ok_or_else(|| "CurseForge disallows third-party downloads for this file".to_string())?;
+
+            Ok(DownloadTarget {
+                filename: file.file_name,
+                sha512: None,
+                payload: DownloadPayload::Url(url),
+            })
+        })
+    }
+}
 
-    let file_bytes = file_response
-        .bytes()
-        .await
-        .map_err(|e| format!("Failed to read file bytes: {}", e))?;
+/// Resolves to a single pinned GitHub Release asset or Maven coordinate
+/// regardless of `mod_id`/`minecraft_version`/`loader` - see [`ModSource`]
+/// for why these can't be discovered automatically.
+pub struct ExternalSource(pub ExternalModSource);
+
+impl ModSource for ExternalSource {
+    fn name(&self) -> &'static str {
+        match self.0 {
+            ExternalModSource::Github { .. } => "github",
+            ExternalModSource::Maven { .. } => "maven",
+        }
+    }
+
+    fn resolve<'a>(
+        &'a self,
+        _mod_id: &'a str,
+        _minecraft_version: &'a str,
+        _loader: &'a str,
+    ) -> BoxFuture<'a, Result<DownloadTarget, String>> {
+        Box::pin(async move {
+            match &self.0 {
+                ExternalModSource::Github {
+                    owner_repo,
+                    asset_pattern,
+                } => {
+                    let (_, asset) =
+                        super::github::find_latest_release_asset(owner_repo, asset_pattern)
+                            .await?;
+                    Ok(DownloadTarget {
+                        filename: asset.name,
+                        sha512: None,
+                        payload: DownloadPayload::Url(asset.download_url),
+                    })
+                }
+                ExternalModSource::Maven {
+                    repo_bases,
+                    group,
+                    artifact,
+                    version,
+                    classifier,
+                } => {
+                    let resolved = super::maven::resolve_version_from_repos(
+                        repo_bases, group, artifact, version,
+                    )
+                    .await?;
+                    let coordinate = super::maven::MavenCoordinate {
+                        group: group.clone(),
+                        artifact: artifact.clone(),
+                        version: resolved,
+                        classifier: classifier.clone(),
+                    };
+                    let (filename, bytes, _repo_base) =
+                        super::maven::download_from_repos(repo_bases, &coordinate, true).await?;
+                    Ok(DownloadTarget {
+                        filename,
+                        sha512: None,
+                        payload: DownloadPayload::Bytes(bytes),
+                    })
+                }
+            }
+        })
+    }
+}
+
+/// Verify (when a hash is known) and write a resolved [`DownloadTarget`]
+/// into `mods_dir`, skipping the download entirely when a jar already sits
+/// at the destination with a matching hash - this is what makes
+/// `resolve_and_install_dependencies` idempotent across re-runs.
+async fn download_target(target: &DownloadTarget, mods_dir: &Path) -> Result<(), String> {
+    let output_path = mods_dir.join(&target.filename);
+
+    if let Some(expected) = &target.sha512 {
+        if output_path.exists()
+            && sha512_hex(&fs::read(&output_path).unwrap_or_default()).eq_ignore_ascii_case(expected)
+        {
+            tracing::info!(
+                "{} already installed and verified, skipping download",
+                target.filename
+            );
+            return Ok(());
+        }
+    }
 
-    // Save to mods directory
-    let output_path = mods_dir.join(filename);
-    fs::write(&output_path, file_bytes).map_err(|e| format!("Failed to write file: {}", e))?;
+    let bytes = match &target.payload {
+        DownloadPayload::Bytes(bytes) => bytes.clone(),
+        DownloadPayload::Url(url) => {
+            let client = reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(120))
+                .user_agent("MiracleClient/1.0")
+                .build()
+                .map_err(|e| e.to_string())?;
+
+            let response = send_with_retry(|| client.get(url), &RetryConfig::default())
+                .await
+                .map_err(|e| format!("Failed to download: {}", e))?;
+
+            response
+                .bytes()
+                .await
+                .map_err(|e| format!("Failed to read file bytes: {}", e))?
+                .to_vec()
+        }
+    };
+
+    if let Some(expected) = &target.sha512 {
+        let actual = sha512_hex(&bytes);
+        if !actual.eq_ignore_ascii_case(expected) {
+            return Err(format!(
+                "Downloaded {} failed hash verification (expected {}, got {})",
+                target.filename, expected, actual
+            ));
+        }
+    }
 
-    tracing::info!("Successfully installed dependency: {}", mod_id);
+    if let Err(e) = fs::write(&output_path, &bytes) {
+        let _ = fs::remove_file(&output_path);
+        return Err(format!("Failed to write file: {}", e));
+    }
 
     Ok(())
 }
 
-/// Resolve and install all missing dependencies
+/// Resolve and download `mod_id` by trying each of `sources` in priority
+/// order, returning the name of whichever one satisfied it (see
+/// [`ModSource::name`]) so callers can record which source a dependency
+/// actually came from.
+pub async fn install_dependency_from(
+    mod_id: &str,
+    minecraft_version: &str,
+    loader: &str,
+    mods_dir: &Path,
+    sources: &[Box<dyn ModSource>],
+) -> Result<String, String> {
+    let mut errors = Vec::new();
+
+    for source in sources {
+        tracing::info!(
+            "Resolving dependency {} via {} for Minecraft {}",
+            mod_id,
+            source.name(),
+            minecraft_version
+        );
+        match source.resolve(mod_id, minecraft_version, loader).await {
+            Ok(target) => {
+                download_target(&target, mods_dir).await?;
+                tracing::info!("Installed dependency {} via {}", mod_id, source.name());
+                return Ok(source.name().to_string());
+            }
+            Err(e) => errors.push(format!("{}: {}", source.name(), e)),
+        }
+    }
+
+    Err(format!(
+        "No source could resolve {} ({})",
+        mod_id,
+        errors.join("; ")
+    ))
+}
+
+/// Download and install a mod by id, trying Modrinth then CurseForge (the
+/// only two sources that can resolve a bare id on their own - see
+/// [`ModSource`]). `version_requirement` of `None` behaves like `"*"`.
+pub async fn install_dependency(
+    mod_id: &str,
+    minecraft_version: &str,
+    loader: &str,
+    version_requirement: Option<&str>,
+    mods_dir: &Path,
+) -> Result<(), String> {
+    let sources: Vec<Box<dyn ModSource>> = vec![
+        Box::new(ModrinthSource {
+            version_requirement: version_requirement.map(str::to_string),
+        }),
+        Box::new(CurseForgeSource),
+    ];
+
+    install_dependency_from(mod_id, minecraft_version, loader, mods_dir, &sources)
+        .await
+        .map(|_| ())
+}
+
+/// Hex-encoded SHA-512 of `bytes`, used to verify a downloaded dependency
+/// jar against the `hashes.sha512` Modrinth includes on each version file.
+fn sha512_hex(bytes: &[u8]) -> String {
+    use sha2::{Digest, Sha512};
+    let mut hasher = Sha512::new();
+    hasher.update(bytes);
+    format!("{:x}", hasher.finalize())
+}
+
+/// Emit a `dependency_conflict_warning` event plus a `tracing::warn!` for
+/// each `(mod_id, offending_id)` pair, used by [`resolve_and_install_dependencies`]
+/// to flag `breaks`/`conflicts` hits without aborting the install.
+fn warn_conflicts(conflicts: &[(String, String)], app: Option<&AppHandle>) {
+    for (mod_id, offending_id) in conflicts {
+        tracing::warn!(
+            "{} is incompatible with installed mod {}",
+            mod_id,
+            offending_id
+        );
+        if let Some(app) = app {
+            let _ = app.emit(
+                "dependency_conflict_warning",
+                serde_json::json!({
+                    "mod_id": mod_id,
+                    "conflicts_with": offending_id,
+                }),
+            );
+        }
+    }
+}
+
+/// Resolve and install all missing dependencies. `app`, when given, is used
+/// to emit a `dependency_resolve_warning` event for any dependency that's
+/// still unresolved after `install_dependency`'s own retries are exhausted,
+/// so the launch can continue with whatever did resolve instead of aborting,
+/// and a `dependency_conflict_warning` event for any `breaks`/`conflicts`
+/// pair (see [`resolve_dependencies`]) among the mods that end up installed.
 pub async fn resolve_and_install_dependencies(
     mods_dir: &Path,
     minecraft_version: &str,
+    loader: &str,
+    app: Option<&AppHandle>,
 ) -> Result<Vec<String>, String> {
     let resolution = resolve_dependencies(mods_dir)?;
+    warn_conflicts(&resolution.conflicts, app);
 
     if resolution.missing_dependencies.is_empty() {
         tracing::info!("All dependencies are satisfied!");
@@ -245,12 +802,29 @@ pub async fn resolve_and_install_dependencies(
     for dep in resolution.missing_dependencies {
         tracing::info!("Installing missing dependency: {}", dep.mod_id);
 
-        match install_dependency(&dep.mod_id, minecraft_version, mods_dir).await {
+        match install_dependency(
+            &dep.mod_id,
+            minecraft_version,
+            loader,
+            dep.version_requirement.as_deref(),
+            mods_dir,
+        )
+        .await
+        {
             Ok(_) => {
                 installed.push(dep.mod_id.clone());
             }
             Err(e) => {
                 tracing::warn!("Failed to install dependency {}: {}", dep.mod_id, e);
+                if let Some(app) = app {
+                    let _ = app.emit(
+                        "dependency_resolve_warning",
+                        serde_json::json!({
+                            "mod_id": dep.mod_id,
+                            "error": e,
+                        }),
+                    );
+                }
                 // Continue with other dependencies even if one fails
             }
         }
@@ -259,12 +833,27 @@ pub async fn resolve_and_install_dependencies(
         tokio::time::sleep(std::time::Duration::from_millis(300)).await;
     }
 
+    // Newly pulled dependencies can themselves break an already-installed
+    // mod (or vice versa) - re-check after installing and flag anything
+    // that wasn't already a conflict before this round.
+    if !installed.is_empty() {
+        let post_install = resolve_dependencies(mods_dir)?;
+        let new_conflicts: Vec<(String, String)> = post_install
+            .conflicts
+            .into_iter()
+            .filter(|pair| !resolution.conflicts.contains(pair))
+            .collect();
+        warn_conflicts(&new_conflicts, app);
+    }
+
     // Recursively check for dependencies of newly installed mods
     if !installed.is_empty() {
         tracing::info!("Checking for transitive dependencies...");
         let additional = Box::pin(resolve_and_install_dependencies(
             mods_dir,
             minecraft_version,
+            loader,
+            app,
         ))
         .await?;
         installed.extend(additional);
@@ -272,3 +861,74 @@ pub async fn resolve_and_install_dependencies(
 
     Ok(installed)
 }
+
+/// A mod pinned from somewhere other than Modrinth/CurseForge. Kept
+/// alongside the regular `ModMetadata` entry for a mod (as `source` +
+/// `repo_base`/`asset_pattern`) so `mod_updates` can later re-resolve it.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum ExternalModSource {
+    /// `owner/repo` plus a `*`-wildcard asset name pattern, e.g. `*-fabric-*.jar`.
+    Github {
+        owner_repo: String,
+        asset_pattern: String,
+    },
+    /// A `group:artifact:version` coordinate (version may be `latest`/
+    /// `release`, resolved against `maven-metadata.xml`), tried against each
+    /// of `repo_bases` in order until one has it.
+    Maven {
+        repo_bases: Vec<String>,
+        group: String,
+        artifact: String,
+        version: String,
+        #[serde(default)]
+        classifier: Option<String>,
+    },
+}
+
+/// Download a mod pinned from GitHub Releases or a Maven repository into
+/// `mods_dir`. Returns the installed filename and, for a Maven source, the
+/// repo base that actually served it (so the caller can pin future
+/// re-resolution to the repo that's known to have it).
+pub async fn install_from_external_source(
+    source: &ExternalModSource,
+    mods_dir: &Path,
+) -> Result<(String, Option<String>), String> {
+    match source {
+        ExternalModSource::Github {
+            owner_repo,
+            asset_pattern,
+        } => {
+            let (_, asset) =
+                super::github::find_latest_release_asset(owner_repo, asset_pattern).await?;
+            let bytes = super::github::download_asset(&asset.download_url).await?;
+
+            fs::create_dir_all(mods_dir)
+                .map_err(|e| format!("Failed to create mods directory: {}", e))?;
+            fs::write(mods_dir.join(&asset.name), &bytes)
+                .map_err(|e| format!("Failed to write {}: {}", asset.name, e))?;
+
+            Ok((asset.name, None))
+        }
+        ExternalModSource::Maven {
+            repo_bases,
+            group,
+            artifact,
+            version,
+            classifier,
+        } => {
+            let resolved =
+                super::maven::resolve_version_from_repos(repo_bases, group, artifact, version)
+                    .await?;
+            let coordinate = super::maven::MavenCoordinate {
+                group: group.clone(),
+                artifact: artifact.clone(),
+                version: resolved,
+                classifier: classifier.clone(),
+            };
+            let (filename, repo_base) =
+                super::maven::download_to_dir(repo_bases, &coordinate, mods_dir, true).await?;
+
+            Ok((filename, Some(repo_base)))
+        }
+    }
+}
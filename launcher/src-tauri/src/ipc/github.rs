@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+
+use super::retry::{send_with_retry, RetryConfig};
+
+const GITHUB_API_BASE: &str = "https://api.github.com";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GithubReleaseAsset {
+    pub name: String,
+    #[serde(rename = "browser_download_url")]
+    pub download_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubRelease {
+    tag_name: String,
+    #[serde(default)]
+    prerelease: bool,
+    assets: Vec<GithubReleaseAsset>,
+}
+
+fn create_client() -> Result<reqwest::Client, String> {
+    reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(30))
+        .user_agent("MiracleClient/1.0")
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+/// Find the newest release of `owner/repo` with an asset matching
+/// `asset_pattern` (a simple `*`-wildcard glob, e.g. `*-fabric-1.21.*.jar`).
+/// Releases are returned newest-first by the GitHub API, and pre-releases
+/// are skipped in favor of the newest stable match.
+pub async fn find_latest_release_asset(
+    owner_repo: &str,
+    asset_pattern: &str,
+) -> Result<(String, GithubReleaseAsset), String> {
+    let client = create_client()?;
+    let url = format!("{}/repos/{}/releases", GITHUB_API_BASE, owner_repo);
+
+    let response = send_with_retry(|| client.get(&url), &RetryConfig::default())
+        .await
+        .map_err(|e| format!("Failed to fetch releases for {}: {}", owner_repo, e))?;
+
+    let releases: Vec<GithubRelease> = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse releases for {}: {}", owner_repo, e))?;
+
+    for release in releases.iter().filter(|r| !r.prerelease) {
+        if let Some(asset) = release
+            .assets
+            .iter()
+            .find(|a| glob_match(asset_pattern, &a.name))
+        {
+            return Ok((release.tag_name.clone(), asset.clone()));
+        }
+    }
+
+    Err(format!(
+        "No release of {} has an asset matching '{}'",
+        owner_repo, asset_pattern
+    ))
+}
+
+pub async fn download_asset(url: &str) -> Result<Vec<u8>, String> {
+    let client = create_client()?;
+
+    let response = send_with_retry(|| client.get(url), &RetryConfig::default())
+        .await
+        .map_err(|e| format!("Failed to download {}: {}", url, e))?;
+
+    response
+        .bytes()
+        .await
+        .map(|b| b.to_vec())
+        .map_err(|e| format!("Failed to read asset bytes: {}", e))
+}
+
+/// Minimal `*`-only glob matcher, sufficient for asset name patterns like
+/// `*-fabric-1.21.*.jar`.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let parts: Vec<&str> = pattern.split('*').collect();
+    if parts.len() == 1 {
+        return pattern == text;
+    }
+
+    let mut pos = 0;
+    let last = parts.len() - 1;
+    for (i, part) in parts.iter().enumerate() {
+        if part.is_empty() {
+            continue;
+        }
+        if i == 0 {
+            if !text[pos..].starts_with(part) {
+                return false;
+            }
+            pos += part.len();
+        } else if i == last {
+            if !text[pos..].ends_with(part) {
+                return false;
+            }
+        } else {
+            match text[pos..].find(part) {
+                Some(idx) => pos += idx + part.len(),
+                None => return false,
+            }
+        }
+    }
+
+    true
+}
@@ -0,0 +1,134 @@
+//! Game log and crash-report retrieval: surfaces the per-launch output
+//! captured by `minecraft::launch` (see `LogCapture`) alongside Minecraft's
+//! own `logs/latest.log` and `crash-reports/`, which live under the shared
+//! `--gameDir` rather than a per-profile folder.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+
+use super::AppState;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogType {
+    Info,
+    Crash,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogEntry {
+    pub filename: String,
+    pub log_type: LogType,
+    pub size: u64,
+    pub modified: Option<String>,
+}
+
+fn game_dir() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("MiracleClient")
+}
+
+fn entry_from_path(path: &std::path::Path, log_type: LogType) -> Option<LogEntry> {
+    let metadata = std::fs::metadata(path).ok()?;
+    let modified = metadata
+        .modified()
+        .ok()
+        .map(chrono::DateTime::<chrono::Utc>::from)
+        .map(|dt| dt.to_rfc3339());
+
+    Some(LogEntry {
+        filename: path.file_name()?.to_string_lossy().to_string(),
+        log_type,
+        size: metadata.len(),
+        modified,
+    })
+}
+
+/// List every captured launch log for this profile (`Info`) plus every
+/// crash report Minecraft itself wrote to the shared game directory
+/// (`Crash`) - crash reports aren't separated per profile since `--gameDir`
+/// is shared across all of them.
+#[tauri::command]
+pub async fn get_logs_for_profile(
+    app: AppHandle,
+    minecraft_version: String,
+    profile_id: String,
+) -> Result<Vec<LogEntry>, String> {
+    let state = app.state::<AppState>();
+    let profile_dir = super::get_profile_dir_name(&state, &profile_id);
+
+    let mut entries = Vec::new();
+
+    let logs_dir = super::get_logs_directory(Some(&minecraft_version), Some(&profile_dir));
+    if let Ok(read_dir) = std::fs::read_dir(&logs_dir) {
+        for entry in read_dir.flatten() {
+            if let Some(log) = entry_from_path(&entry.path(), LogType::Info) {
+                entries.push(log);
+            }
+        }
+    }
+
+    let crash_dir = game_dir().join("crash-reports");
+    if let Ok(read_dir) = std::fs::read_dir(&crash_dir) {
+        for entry in read_dir.flatten() {
+            if let Some(log) = entry_from_path(&entry.path(), LogType::Crash) {
+                entries.push(log);
+            }
+        }
+    }
+
+    let latest_log = game_dir().join("logs").join("latest.log");
+    if let Some(log) = entry_from_path(&latest_log, LogType::Info) {
+        entries.push(log);
+    }
+
+    entries.sort_by(|a, b| b.modified.cmp(&a.modified));
+    Ok(entries)
+}
+
+fn resolve_log_path(
+    state: &AppState,
+    minecraft_version: &str,
+    profile_id: &str,
+    log_type: LogType,
+    filename: &str,
+) -> PathBuf {
+    match log_type {
+        LogType::Crash => game_dir().join("crash-reports").join(filename),
+        LogType::Info if filename == "latest.log" => game_dir().join("logs").join(filename),
+        LogType::Info => {
+            let profile_dir = super::get_profile_dir_name(state, profile_id);
+            super::get_logs_directory(Some(minecraft_version), Some(&profile_dir)).join(filename)
+        }
+    }
+}
+
+/// Read one log/crash-report's full text contents.
+#[tauri::command]
+pub async fn get_log_by_filename(
+    app: AppHandle,
+    minecraft_version: String,
+    profile_id: String,
+    log_type: LogType,
+    filename: String,
+) -> Result<String, String> {
+    let state = app.state::<AppState>();
+    let path = resolve_log_path(&state, &minecraft_version, &profile_id, log_type, &filename);
+    std::fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", filename, e))
+}
+
+/// Delete a captured log or crash report.
+#[tauri::command]
+pub async fn delete_log(
+    app: AppHandle,
+    minecraft_version: String,
+    profile_id: String,
+    log_type: LogType,
+    filename: String,
+) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let path = resolve_log_path(&state, &minecraft_version, &profile_id, log_type, &filename);
+    std::fs::remove_file(&path).map_err(|e| format!("Failed to delete {}: {}", filename, e))
+}
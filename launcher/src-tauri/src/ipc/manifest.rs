@@ -0,0 +1,446 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha512};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use tauri::Emitter;
+
+use crate::profiles::{Profile, ProfileManager};
+
+use super::modrinth;
+
+/// Declarative manifest stored as `miracle.toml` alongside a profile's mods
+/// directory, mirroring the "Hopfile" approach: it pins the Minecraft
+/// version, loader, and mod slugs a profile wants (with an optional pinned
+/// version per mod), so the mod list can be version-controlled and shared as
+/// a single small text file instead of toggling jars by hand. It's purely
+/// user-authored intent - resolved versions/hashes live in the lockfile.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ProfileManifest {
+    pub minecraft_version: String,
+    #[serde(default = "default_loader")]
+    pub loader: String,
+    #[serde(default)]
+    pub mods: Vec<ManifestMod>,
+}
+
+fn default_loader() -> String {
+    "fabric".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestMod {
+    pub slug: String,
+    /// Pinned Modrinth version ID or version number; when absent, the
+    /// newest version compatible with `minecraft_version`/`loader` is used.
+    #[serde(default)]
+    pub version: Option<String>,
+}
+
+/// One mod's resolved, reproducible install state, recorded in
+/// `miracle.lock.toml` after a successful resolve/download so a later
+/// `sync_profile` can skip both the API round-trip and the download
+/// entirely when the installed jar still matches.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockedMod {
+    pub slug: String,
+    pub version_id: String,
+    pub filename: String,
+    pub sha512: String,
+    /// The URL the jar was actually downloaded from. Recorded only:
+    /// `apply_manifest` never downloads from it, and re-resolves the
+    /// slug/version through Modrinth or CurseForge whenever the jar's hash
+    /// no longer matches.
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ProfileLockfile {
+    #[serde(default)]
+    pub mods: Vec<LockedMod>,
+}
+
+fn manifest_path(mods_dir: &Path) -> PathBuf {
+    mods_dir.join("miracle.toml")
+}
+
+fn lockfile_path(mods_dir: &Path) -> PathBuf {
+    mods_dir.join("miracle.lock.toml")
+}
+
+/// Load a profile's `miracle.toml`, if one exists.
+pub fn load_manifest(mods_dir: &Path) -> Result<Option<ProfileManifest>, String> {
+    let path = manifest_path(mods_dir);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+    toml::from_str(&contents)
+        .map(Some)
+        .map_err(|e| format!("Failed to parse miracle.toml: {}", e))
+}
+
+pub(crate) fn save_manifest(mods_dir: &Path, manifest: &ProfileManifest) -> Result<(), String> {
+    let path = manifest_path(mods_dir);
+
+    let contents = toml::to_string_pretty(manifest)
+        .map_err(|e| format!("Failed to serialize miracle.toml: {}", e))?;
+
+    std::fs::write(&path, contents)
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Load a profile's `miracle.lock.toml`, if one exists.
+pub fn load_lockfile(mods_dir: &Path) -> Result<Option<ProfileLockfile>, String> {
+    let path = lockfile_path(mods_dir);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+    toml::from_str(&contents)
+        .map(Some)
+        .map_err(|e| format!("Failed to parse miracle.lock.toml: {}", e))
+}
+
+pub(crate) fn save_lockfile(mods_dir: &Path, lockfile: &ProfileLockfile) -> Result<(), String> {
+    let path = lockfile_path(mods_dir);
+
+    let contents = toml::to_string_pretty(lockfile)
+        .map_err(|e| format!("Failed to serialize miracle.lock.toml: {}", e))?;
+
+    std::fs::write(&path, contents)
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Result of diffing + applying a profile's manifest against its mods folder.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileSyncResult {
+    pub installed: Vec<String>,
+    pub removed: Vec<String>,
+    pub failed: Vec<String>,
+}
+
+async fn file_matches_hash(path: &Path, expected_sha512: &str) -> bool {
+    let Ok(bytes) = tokio::fs::read(path).await else {
+        return false;
+    };
+
+    let mut hasher = Sha512::new();
+    hasher.update(&bytes);
+    format!("{:x}", hasher.finalize()).eq_ignore_ascii_case(expected_sha512)
+}
+
+/// Resolve a manifest entry against Modrinth, honoring a pinned version if
+/// one is declared.
+async fn resolve_via_modrinth(
+    entry: &ManifestMod,
+    game_version: &str,
+    loader: &str,
+    dest_dir: &Path,
+) -> Result<LockedMod, String> {
+    let versions = modrinth::get_modrinth_versions(
+        entry.slug.clone(),
+        Some(game_version.to_string()),
+        Some(loader.to_string()),
+    )
+    .await?;
+
+    let version = match &entry.version {
+        Some(pinned) => versions
+            .iter()
+            .find(|v| &v.id == pinned || &v.version_number == pinned)
+            .ok_or_else(|| format!("Pinned version {} not found for {}", pinned, entry.slug))?,
+        None => versions.first().ok_or_else(|| {
+            format!("No compatible version found for {} on {}", entry.slug, game_version)
+        })?,
+    };
+
+    let file = version
+        .files
+        .iter()
+        .find(|f| f.primary)
+        .or_else(|| version.files.first())
+        .ok_or("No download file found")?;
+
+    let client = modrinth::create_client()?;
+    let response = client
+        .get(&file.url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to download {}: {}", entry.slug, e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Download failed: HTTP {}", response.status()));
+    }
+
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| format!("Failed to read download: {}", e))?;
+
+    modrinth::verify_modrinth_hashes(&bytes, &file.hashes)
+        .map_err(|e| format!("{} failed verification: {}", file.filename, e))?;
+
+    let dest_path = dest_dir.join(&file.filename);
+    tokio::fs::write(&dest_path, &bytes)
+        .await
+        .map_err(|e| format!("Failed to write {}: {}", file.filename, e))?;
+
+    let mut hasher = Sha512::new();
+    hasher.update(&bytes);
+
+    Ok(LockedMod {
+        slug: entry.slug.clone(),
+        version_id: version.id.clone(),
+        filename: file.filename.clone(),
+        sha512: format!("{:x}", hasher.finalize()),
+        url: file.url.clone(),
+    })
+}
+
+/// Resolve a manifest entry against CurseForge by matching the manifest
+/// slug to a project's own slug. Only usable for Fabric packs, since
+/// `curseforge::get_mod_files` (like the rest of this launcher's CurseForge
+/// browsing) is itself Fabric-only.
+async fn resolve_via_curseforge(
+    entry: &ManifestMod,
+    game_version: &str,
+    dest_dir: &Path,
+) -> Result<LockedMod, String> {
+    use super::curseforge;
+
+    let results = curseforge::search_curseforge(
+        entry.slug.clone(),
+        "mod".to_string(),
+        None,
+        "relevancy".to_string(),
+        game_version.to_string(),
+        0,
+        10,
+    )
+    .await?;
+
+    let project = results
+        .data
+        .into_iter()
+        .find(|p| p.slug == entry.slug)
+        .ok_or_else(|| format!("No CurseForge project found for slug {}", entry.slug))?;
+
+    let files = curseforge::get_mod_files(project.id, game_version).await?;
+    let file = match &entry.version {
+        Some(pinned) => files
+            .into_iter()
+            .find(|f| &f.id.to_string() == pinned || &f.file_name == pinned)
+            .ok_or_else(|| format!("Pinned version {} not found for {}", pinned, entry.slug))?,
+        None => files
+            .into_iter()
+            .next()
+            .ok_or_else(|| format!("No compatible file found for {}", entry.slug))?,
+    };
+
+    let url = file
+        .download_url
+        .as_ref()
+        .ok_or("CurseForge disallows third-party downloads for this file")?;
+    let bytes = curseforge::download_file_bytes(url).await?;
+
+    let dest_path = dest_dir.join(&file.file_name);
+    tokio::fs::write(&dest_path, &bytes)
+        .await
+        .map_err(|e| format!("Failed to write {}: {}", file.file_name, e))?;
+
+    let mut hasher = Sha512::new();
+    hasher.update(&bytes);
+
+    Ok(LockedMod {
+        slug: entry.slug.clone(),
+        version_id: file.id.to_string(),
+        filename: file.file_name.clone(),
+        sha512: format!("{:x}", hasher.finalize()),
+        url: url.clone(),
+    })
+}
+
+/// Resolve one manifest entry, trying Modrinth first and falling back to
+/// CurseForge by slug if Modrinth has no match.
+async fn resolve_mod(
+    entry: &ManifestMod,
+    game_version: &str,
+    loader: &str,
+    dest_dir: &Path,
+) -> Result<LockedMod, String> {
+    match resolve_via_modrinth(entry, game_version, loader, dest_dir).await {
+        Ok(locked) => Ok(locked),
+        Err(modrinth_err) => {
+            if loader != "fabric" {
+                return Err(modrinth_err);
+            }
+            resolve_via_curseforge(entry, game_version, dest_dir)
+                .await
+                .map_err(|cf_err| {
+                    format!(
+                        "{} (CurseForge fallback also failed: {})",
+                        modrinth_err, cf_err
+                    )
+                })
+        }
+    }
+}
+
+/// Resolve every entry in `manifest` against its previous lockfile, skipping
+/// both the API lookup and the download for anything whose installed jar
+/// still matches the recorded hash, then prune any `.jar` the manifest no
+/// longer lists. `.jar.disabled` files are never touched, so a user's manual
+/// disable toggles survive an update.
+async fn apply_manifest(
+    mods_dir: &Path,
+    manifest: &ProfileManifest,
+) -> Result<(ProfileLockfile, ProfileSyncResult), String> {
+    let existing_lock = load_lockfile(mods_dir)?.unwrap_or_default();
+
+    let mut locked_mods = Vec::new();
+    let mut installed = Vec::new();
+    let mut failed = Vec::new();
+
+    for entry in &manifest.mods {
+        let previously_locked = existing_lock.mods.iter().find(|locked| {
+            locked.slug == entry.slug
+                && entry
+                    .version
+                    .as_deref()
+                    .map_or(true, |pinned| pinned == locked.version_id)
+        });
+
+        if let Some(locked) = previously_locked {
+            if file_matches_hash(&mods_dir.join(&locked.filename), &locked.sha512).await {
+                locked_mods.push(locked.clone());
+                continue;
+            }
+        }
+
+        match resolve_mod(entry, &manifest.minecraft_version, &manifest.loader, mods_dir).await {
+            Ok(locked) => {
+                tracing::info!("update_profile: installed {} ({})", locked.filename, entry.slug);
+                installed.push(locked.filename.clone());
+                locked_mods.push(locked);
+            }
+            Err(e) => {
+                tracing::warn!("update_profile: failed to resolve {}: {}", entry.slug, e);
+                failed.push(entry.slug.clone());
+            }
+        }
+    }
+
+    let desired_filenames: HashSet<String> =
+        locked_mods.iter().map(|m| m.filename.clone()).collect();
+
+    let mut removed = Vec::new();
+    let mut dir_entries = tokio::fs::read_dir(mods_dir)
+        .await
+        .map_err(|e| format!("Failed to read mods directory: {}", e))?;
+
+    while let Some(entry) = dir_entries
+        .next_entry()
+        .await
+        .map_err(|e| format!("Failed to read mods directory: {}", e))?
+    {
+        let filename = entry.file_name().to_string_lossy().to_string();
+
+        if !filename.ends_with(".jar") || desired_filenames.contains(&filename) {
+            continue;
+        }
+
+        if tokio::fs::remove_file(entry.path()).await.is_ok() {
+            removed.push(filename);
+        }
+    }
+
+    tracing::info!(
+        "update_profile: {} installed, {} removed, {} failed",
+        installed.len(),
+        removed.len(),
+        failed.len()
+    );
+
+    Ok((
+        ProfileLockfile { mods: locked_mods },
+        ProfileSyncResult {
+            installed,
+            removed,
+            failed,
+        },
+    ))
+}
+
+/// Create a new profile from a user-authored manifest (the declarative
+/// `minecraft_version`/`loader`/`[[mods]]` TOML described in
+/// `ProfileManifest`, each `[[mods]]` entry holding a `slug` and an optional
+/// `version`) and resolve every mod it lists, writing both the manifest and
+/// the resulting lockfile into the profile's mods directory. A top-level
+/// `version` key or `[mods.<slug>]` tables are not recognized.
+#[tauri::command]
+pub async fn install_from_manifest(name: String, manifest_toml: String) -> Result<Profile, String> {
+    let manifest: ProfileManifest =
+        toml::from_str(&manifest_toml).map_err(|e| format!("Failed to parse manifest: {}", e))?;
+
+    let mut profile_manager = ProfileManager::new();
+    let profile =
+        profile_manager.create_modpack_profile(&name, &manifest.minecraft_version, "manifest")?;
+    profile_manager.set_profile_loader(&profile.id, manifest.loader.clone())?;
+
+    let mods_dir = profile_manager.get_mods_dir(&manifest.minecraft_version, &profile.id);
+    tokio::fs::create_dir_all(&mods_dir)
+        .await
+        .map_err(|e| format!("Failed to create mods directory: {}", e))?;
+
+    save_manifest(&mods_dir, &manifest)?;
+
+    let (lockfile, _result) = apply_manifest(&mods_dir, &manifest).await?;
+    save_lockfile(&mods_dir, &lockfile)?;
+
+    Ok(profile)
+}
+
+/// Re-resolve a profile's `miracle.toml` against its mods directory:
+/// download anything newly listed (or whose pin changed), prune jars no
+/// longer listed, and rewrite `miracle.lock.toml` to match. Emits
+/// `profile_sync_progress` (`{ stage: "syncing" | "complete" }`) so the UI
+/// can show a spinner while the diff/download/prune pass runs.
+#[tauri::command]
+pub async fn sync_profile(
+    app: tauri::AppHandle,
+    profile_id: String,
+) -> Result<ProfileSyncResult, String> {
+    let profile_manager = ProfileManager::new();
+    let profile = profile_manager
+        .get_profile(&profile_id)
+        .ok_or_else(|| format!("Profile {} not found", profile_id))?;
+    let mods_dir = profile_manager.get_mods_dir(&profile.version, &profile_id);
+
+    tokio::fs::create_dir_all(&mods_dir)
+        .await
+        .map_err(|e| format!("Failed to create mods directory: {}", e))?;
+
+    let manifest = load_manifest(&mods_dir)?
+        .ok_or_else(|| "No miracle.toml manifest found for this profile".to_string())?;
+
+    let _ = app.emit(
+        "profile_sync_progress",
+        serde_json::json!({ "stage": "syncing" }),
+    );
+
+    let (lockfile, result) = apply_manifest(&mods_dir, &manifest).await?;
+    save_lockfile(&mods_dir, &lockfile)?;
+
+    let _ = app.emit(
+        "profile_sync_progress",
+        serde_json::json!({ "stage": "complete" }),
+    );
+
+    Ok(result)
+}
@@ -0,0 +1,299 @@
+use super::retry::{send_with_retry, RetryConfig};
+use sha1::{Digest as _, Sha1};
+
+fn create_client() -> Result<reqwest::Client, String> {
+    reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(30))
+        .user_agent("MiracleClient/1.0")
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+fn group_path(group: &str) -> String {
+    group.replace('.', "/")
+}
+
+/// Resolve `version` against `maven-metadata.xml` when it's `latest` or
+/// `release`, otherwise return it unchanged.
+pub async fn resolve_version(
+    repo_base: &str,
+    group: &str,
+    artifact: &str,
+    version: &str,
+) -> Result<String, String> {
+    if version != "latest" && version != "release" {
+        return Ok(version.to_string());
+    }
+
+    let client = create_client()?;
+    let url = format!(
+        "{}/{}/{}/maven-metadata.xml",
+        repo_base.trim_end_matches('/'),
+        group_path(group),
+        artifact
+    );
+
+    let response = send_with_retry(|| client.get(&url), &RetryConfig::default())
+        .await
+        .map_err(|e| format!("Failed to fetch maven-metadata.xml: {}", e))?;
+
+    let xml = response
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read maven-metadata.xml: {}", e))?;
+
+    let tag = if version == "latest" {
+        "latest"
+    } else {
+        "release"
+    };
+
+    extract_xml_tag(&xml, tag)
+        .or_else(|| extract_xml_tag(&xml, "release"))
+        .or_else(|| extract_xml_tag(&xml, "latest"))
+        .ok_or_else(|| format!("No <{}> version found in maven-metadata.xml", tag))
+}
+
+/// Resolve `version` against whichever of `repo_bases` publishes
+/// `maven-metadata.xml` for this artifact first, trying each in order.
+pub async fn resolve_version_from_repos(
+    repo_bases: &[String],
+    group: &str,
+    artifact: &str,
+    version: &str,
+) -> Result<String, String> {
+    if version != "latest" && version != "release" {
+        return Ok(version.to_string());
+    }
+
+    let mut last_error = "No Maven repositories configured".to_string();
+    for repo_base in repo_bases {
+        match resolve_version(repo_base, group, artifact, version).await {
+            Ok(resolved) => return Ok(resolved),
+            Err(e) => last_error = e,
+        }
+    }
+
+    Err(format!(
+        "Could not resolve {} version for {}:{} against any configured repository: {}",
+        version, group, artifact, last_error
+    ))
+}
+
+/// A parsed `group:artifact:version[:classifier]` Maven coordinate.
+pub struct MavenCoordinate {
+    pub group: String,
+    pub artifact: String,
+    pub version: String,
+    pub classifier: Option<String>,
+}
+
+impl MavenCoordinate {
+    /// Parse `group:artifact:version` or `group:artifact:version:classifier`.
+    pub fn parse(coordinate: &str) -> Result<Self, String> {
+        let parts: Vec<&str> = coordinate.split(':').collect();
+        match parts.as_slice() {
+            [group, artifact, version] => Ok(Self {
+                group: group.to_string(),
+                artifact: artifact.to_string(),
+                version: version.to_string(),
+                classifier: None,
+            }),
+            [group, artifact, version, classifier] => Ok(Self {
+                group: group.to_string(),
+                artifact: artifact.to_string(),
+                version: version.to_string(),
+                classifier: Some(classifier.to_string()),
+            }),
+            _ => Err(format!(
+                "Invalid Maven coordinate '{}', expected group:artifact:version[:classifier]",
+                coordinate
+            )),
+        }
+    }
+
+    fn filename(&self) -> String {
+        match &self.classifier {
+            Some(classifier) => format!("{}-{}-{}.jar", self.artifact, self.version, classifier),
+            None => format!("{}-{}.jar", self.artifact, self.version),
+        }
+    }
+
+    /// `group/artifact/version/artifact-version[-classifier].jar`, relative
+    /// to a repo base.
+    fn path(&self) -> String {
+        format!(
+            "{}/{}/{}/{}",
+            group_path(&self.group),
+            self.artifact,
+            self.version,
+            self.filename()
+        )
+    }
+}
+
+/// Download `group:artifact:version` as `artifact-version.jar` from `repo_base`.
+pub async fn download_artifact(
+    repo_base: &str,
+    group: &str,
+    artifact: &str,
+    version: &str,
+) -> Result<(String, Vec<u8>), String> {
+    download_coordinate(
+        repo_base,
+        &MavenCoordinate {
+            group: group.to_string(),
+            artifact: artifact.to_string(),
+            version: version.to_string(),
+            classifier: None,
+        },
+        false,
+    )
+    .await
+}
+
+/// Download a parsed coordinate from a single repo, optionally verifying it
+/// against the repo's adjacent `.sha1` file (not all repos publish one, so a
+/// missing checksum is not itself treated as a failure).
+pub async fn download_coordinate(
+    repo_base: &str,
+    coordinate: &MavenCoordinate,
+    verify_sha1: bool,
+) -> Result<(String, Vec<u8>), String> {
+    let client = create_client()?;
+    let filename = coordinate.filename();
+    let url = format!("{}/{}", repo_base.trim_end_matches('/'), coordinate.path());
+
+    let response = send_with_retry(|| client.get(&url), &RetryConfig::default())
+        .await
+        .map_err(|e| {
+            format!(
+                "Failed to download {}:{}:{}: {}",
+                coordinate.group, coordinate.artifact, coordinate.version, e
+            )
+        })?;
+
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| format!("Failed to read artifact bytes: {}", e))?
+        .to_vec();
+
+    if verify_sha1 {
+        verify_against_sha1_file(&client, &url, &bytes).await?;
+    }
+
+    Ok((filename, bytes))
+}
+
+/// Try `repo_bases` in order, returning the first one that serves the
+/// coordinate successfully (so a coordinate only published to a mirror, or
+/// to a smaller mod-specific repo, still resolves). Returns the bytes and
+/// the repo base that served them, so the caller can remember which repo
+/// worked.
+pub async fn download_from_repos(
+    repo_bases: &[String],
+    coordinate: &MavenCoordinate,
+    verify_sha1: bool,
+) -> Result<(String, Vec<u8>, String), String> {
+    let mut last_error = "No Maven repositories configured".to_string();
+
+    for repo_base in repo_bases {
+        match download_coordinate(repo_base, coordinate, verify_sha1).await {
+            Ok((filename, bytes)) => return Ok((filename, bytes, repo_base.clone())),
+            Err(e) => {
+                tracing::warn!(
+                    "Maven repo {} does not have {}:{}:{}: {}",
+                    repo_base,
+                    coordinate.group,
+                    coordinate.artifact,
+                    coordinate.version,
+                    e
+                );
+                last_error = e;
+            }
+        }
+    }
+
+    Err(format!(
+        "Could not resolve {}:{}:{} against any configured repository: {}",
+        coordinate.group, coordinate.artifact, coordinate.version, last_error
+    ))
+}
+
+/// Resolve and download a coordinate from the first of `repo_bases` that
+/// has it, writing the jar into `mods_dir`. Returns the written filename
+/// and the repo base it was fetched from.
+pub async fn download_to_dir(
+    repo_bases: &[String],
+    coordinate: &MavenCoordinate,
+    mods_dir: &std::path::Path,
+    verify_sha1: bool,
+) -> Result<(String, String), String> {
+    let (filename, bytes, repo_base) =
+        download_from_repos(repo_bases, coordinate, verify_sha1).await?;
+
+    tokio::fs::create_dir_all(mods_dir)
+        .await
+        .map_err(|e| format!("Failed to create mods directory: {}", e))?;
+    tokio::fs::write(mods_dir.join(&filename), &bytes)
+        .await
+        .map_err(|e| format!("Failed to write {}: {}", filename, e))?;
+
+    Ok((filename, repo_base))
+}
+
+/// Fetch `{artifact_url}.sha1` and verify `bytes` hashes to it. A missing or
+/// unreadable `.sha1` file is not an error - plenty of repos don't publish
+/// one - only an actual mismatch is.
+async fn verify_against_sha1_file(
+    client: &reqwest::Client,
+    artifact_url: &str,
+    bytes: &[u8],
+) -> Result<(), String> {
+    let sha1_url = format!("{}.sha1", artifact_url);
+
+    let response = match client.get(&sha1_url).send().await {
+        Ok(r) if r.status().is_success() => r,
+        _ => return Ok(()),
+    };
+
+    let text = match response.text().await {
+        Ok(t) => t,
+        Err(_) => return Ok(()),
+    };
+
+    let expected = text
+        .split_whitespace()
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_lowercase();
+    if expected.is_empty() || expected.len() != 40 {
+        return Ok(());
+    }
+
+    let mut hasher = Sha1::new();
+    hasher.update(bytes);
+    let actual = format!("{:x}", hasher.finalize());
+
+    if actual.eq_ignore_ascii_case(&expected) {
+        Ok(())
+    } else {
+        Err(format!(
+            "SHA1 mismatch for {}: expected {}, got {}",
+            artifact_url, expected, actual
+        ))
+    }
+}
+
+/// Pull the text content of `<tag>...</tag>` out of a small XML document
+/// without pulling in a full XML parser, mirroring the manual `instance.cfg`
+/// parsing already done for launcher-instance import.
+fn extract_xml_tag(xml: &str, tag: &str) -> Option<String> {
+    let open = format!("<{}>", tag);
+    let close = format!("</{}>", tag);
+    let start = xml.find(&open)? + open.len();
+    let end = xml[start..].find(&close)? + start;
+    Some(xml[start..end].trim().to_string())
+}
@@ -1,21 +1,40 @@
 mod check_mod_compatibility;
 mod dependency_resolver;
+mod retry;
 
+pub mod content_source;
 pub mod curseforge;
+pub mod github;
+pub mod logs;
+pub mod manifest;
+pub mod maven;
 pub mod mod_updates;
 pub mod modpack;
 pub mod modrinth;
+pub mod modrinth_auth;
+pub mod packwiz;
 
 use crate::auth::{AuthManager, DeviceCodeResponse, MinecraftProfile};
-use crate::minecraft::{GameVersion, MinecraftManager};
+use crate::java::JavaManager;
+use crate::minecraft::{GameVersion, IntegrityReport, LoaderKind, MinecraftManager};
+use crate::notifications;
 use crate::profiles::{
     sanitize_profile_name, Profile, ProfileExport, ProfileManager, PERFORMANCE_MODS,
 };
-use crate::supabase::{Friend, FriendRequestResult, ModUpdateInfo, SupabaseClient, User};
+use crate::realtime::SupabaseRealtimeClient;
+use crate::supabase::{
+    Friend, FriendRequestResult, ModUpdateInfo, SupabaseClient, SupabaseConfig, User,
+};
 use crate::updater::{UpdateInfo, UpdateManager};
 use check_mod_compatibility::{check_mods_compatibility, ModCompatibility};
-use dependency_resolver::{resolve_and_install_dependencies, resolve_dependencies};
+use dependency_resolver::{
+    install_from_external_source, resolve_and_install_dependencies, resolve_dependencies,
+    ExternalModSource,
+};
 use serde::{Deserialize, Serialize};
+use sha1::{Digest as _, Sha1};
+use sha2::{Sha256, Sha512};
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Mutex;
 use tauri::{AppHandle, Emitter, Manager};
@@ -38,11 +57,21 @@ fn get_mod_version_for_minecraft(mc_version: &str) -> &'static str {
 pub struct AppState {
     pub auth_manager: AuthManager,
     pub minecraft_manager: MinecraftManager,
+    pub java_manager: JavaManager,
     pub update_manager: UpdateManager,
     pub supabase: SupabaseClient,
     pub profile_manager: Mutex<ProfileManager>,
     pub game_process: Mutex<Option<std::process::Child>>,
     pub current_player: Mutex<Option<(String, String)>>, // (uuid, username) of current player
+    /// Set by `cancel_launch` and polled by `launch_game` between stages.
+    /// Reset at the start of every launch, like `game_process` there's only
+    /// ever one in-flight launch at a time.
+    pub launch_cancel: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    /// Server address the current player is connected to, if any. Kept
+    /// current by `friends_update_status` and read by the friend-activity
+    /// notification subscription to tell "a friend joined your server"
+    /// apart from "a friend joined some other server".
+    pub current_server: std::sync::Arc<Mutex<Option<String>>>,
 }
 
 impl Default for AppState {
@@ -50,11 +79,14 @@ impl Default for AppState {
         Self {
             auth_manager: AuthManager::new(),
             minecraft_manager: MinecraftManager::new(),
+            java_manager: JavaManager::new(),
             update_manager: UpdateManager::new("1.0.0"),
             supabase: SupabaseClient::new(),
             profile_manager: Mutex::new(ProfileManager::new()),
             game_process: Mutex::new(None),
             current_player: Mutex::new(None),
+            launch_cancel: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            current_server: std::sync::Arc::new(Mutex::new(None)),
         }
     }
 }
@@ -90,10 +122,38 @@ pub async fn auth_poll_device_flow(
         .map_err(|e| e.to_string())
 }
 
+/// One-click sign-in via authorization-code + PKCE with a loopback
+/// redirect, as an alternative to the device-code flow.
 #[tauri::command]
-pub async fn auth_logout() -> Result<(), String> {
-    // Clear stored credentials
-    Ok(())
+pub async fn auth_start_pkce_flow(app: AppHandle) -> Result<MinecraftProfile, String> {
+    let state = app.state::<AppState>();
+
+    state
+        .auth_manager
+        .authenticate_with_pkce()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn auth_logout(account_id: String) -> Result<(), String> {
+    crate::auth::TokenStore::new().remove(&account_id)
+}
+
+/// Load the stored session for an account, transparently refreshing it if
+/// it's expired or close to it, so the caller always gets a valid token.
+#[tauri::command]
+pub async fn auth_get_valid_profile(
+    app: AppHandle,
+    account_id: String,
+) -> Result<MinecraftProfile, String> {
+    let state = app.state::<AppState>();
+
+    state
+        .auth_manager
+        .get_valid_profile(&account_id)
+        .await
+        .map_err(|e| e.to_string())
 }
 
 /// Write accounts list for mod (called before game launch)
@@ -102,6 +162,43 @@ pub async fn write_accounts_for_game(accounts: Vec<AccountForMod>) -> Result<(),
     write_accounts_for_mod(&accounts).await
 }
 
+/// Sweep every stored account for tokens that are expired or close to it
+/// and refresh them, mirroring the device-flow `account_added` path so the
+/// frontend sees renewed tokens immediately. Accounts that fail
+/// re-authentication instead emit `account_needs_reauth` so the UI can
+/// prompt the user to sign in again. Called both before a launch and on a
+/// periodic background timer (see `lib.rs`).
+pub async fn refresh_expiring_accounts(app: &AppHandle) {
+    let state = match app.try_state::<AppState>() {
+        Some(s) => s,
+        None => return,
+    };
+
+    for (id, result) in state.auth_manager.refresh_expiring_profiles().await {
+        match result {
+            Ok(profile) => {
+                tracing::info!("[Auth] Refreshed token for {}", profile.name);
+                app.emit(
+                    "account_added",
+                    serde_json::json!({
+                        "id": profile.id,
+                        "name": profile.name,
+                        "accessToken": profile.access_token,
+                        "refreshToken": profile.refresh_token,
+                        "expiresAt": profile.expires_at
+                    }),
+                )
+                .ok();
+            }
+            Err(e) => {
+                tracing::warn!("[Auth] Failed to refresh token for {}: {}", id, e);
+                app.emit("account_needs_reauth", serde_json::json!({ "id": id }))
+                    .ok();
+            }
+        }
+    }
+}
+
 #[tauri::command]
 pub async fn auth_refresh(
     app: AppHandle,
@@ -129,6 +226,111 @@ pub async fn get_minecraft_versions(app: AppHandle) -> Result<Vec<GameVersion>,
         .map_err(|e| e.to_string())
 }
 
+/// A coarse-grained stage of `launch_game`, reported on the `launch_progress`
+/// event (see [`LaunchProgress`]) instead of the old bare `launch_state` strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LaunchStage {
+    Checking,
+    DownloadingMinecraft,
+    DownloadingFabric,
+    DownloadingMods,
+    UpdatingMod,
+    ResolvingDependencies,
+    ResolvingJava,
+    Launching,
+    Running,
+    Cancelled,
+}
+
+/// A single report on the `launch_progress` event. `launch_game` threads one
+/// `mpsc` sender through every download callback and the Supabase
+/// update/mod-install steps, so they all report through this one ordered
+/// stream instead of racing separate `app.emit` calls.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LaunchProgress {
+    pub stage: LaunchStage,
+    pub current: u64,
+    pub total: u64,
+    pub file: Option<String>,
+    pub detail: Option<String>,
+}
+
+impl LaunchProgress {
+    fn stage(stage: LaunchStage) -> Self {
+        Self {
+            stage,
+            current: 0,
+            total: 0,
+            file: None,
+            detail: None,
+        }
+    }
+}
+
+/// Bails out of `launch_game` if `cancel_launch` was called for this launch:
+/// reports the `Cancelled` stage and removes every `miracle-client*.jar` in
+/// `mods_dir`, whether or not an interrupted mod update left it partially
+/// replaced, since that's the one file this function rewrites in place rather
+/// than downloading atomically into a fresh path. The next launch reinstalls it.
+fn check_launch_cancelled(
+    cancel: &std::sync::atomic::AtomicBool,
+    tx: &tokio::sync::mpsc::UnboundedSender<LaunchProgress>,
+    mods_dir: &std::path::Path,
+) -> Result<(), String> {
+    if !cancel.load(std::sync::atomic::Ordering::SeqCst) {
+        return Ok(());
+    }
+    let _ = tx.send(LaunchProgress::stage(LaunchStage::Cancelled));
+    if let Ok(entries) = std::fs::read_dir(mods_dir) {
+        for entry in entries.flatten() {
+            let filename = entry.file_name().to_string_lossy().to_string();
+            if filename.starts_with("miracle-client") && filename.ends_with(".jar") {
+                let _ = std::fs::remove_file(entry.path());
+            }
+        }
+    }
+    Err("Launch cancelled".to_string())
+}
+
+/// Run a profile's `pre_launch_command`/`post_exit_command` hook, if set,
+/// with `mods_dir` as the working directory and `{version}`, `{profile_dir}`,
+/// `{username}`, `{uuid}` exposed as environment variables.
+fn run_profile_hook(
+    command: &str,
+    mods_dir: &std::path::Path,
+    version: &str,
+    profile_dir: &str,
+    username: &str,
+    uuid: &str,
+) -> Result<(), String> {
+    let shell = if cfg!(target_os = "windows") {
+        ("cmd", "/C")
+    } else {
+        ("sh", "-c")
+    };
+
+    let status = std::process::Command::new(shell.0)
+        .arg(shell.1)
+        .arg(command)
+        .current_dir(mods_dir)
+        .env("version", version)
+        .env("profile_dir", profile_dir)
+        .env("username", username)
+        .env("uuid", uuid)
+        .status()
+        .map_err(|e| format!("Failed to run hook command: {}", e))?;
+
+    if !status.success() {
+        return Err(format!(
+            "Hook command exited with status {}",
+            status.code().unwrap_or(-1)
+        ));
+    }
+
+    Ok(())
+}
+
 #[tauri::command]
 #[allow(non_snake_case)]
 pub async fn launch_game(
@@ -143,6 +345,8 @@ pub async fn launch_game(
     profileId: Option<String>,
     theme: Option<String>,
 ) -> Result<(), String> {
+    refresh_expiring_accounts(&app).await;
+
     let state = app.state::<AppState>();
 
     // Get active profile ID and name (use provided or fetch from manager)
@@ -170,6 +374,20 @@ pub async fn launch_game(
         profile_dir_name
     );
 
+    let (pre_launch_command, post_exit_command, profile_java_path) = {
+        let manager = state.profile_manager.lock().unwrap();
+        manager
+            .get_profile(&active_profile_id)
+            .map(|p| {
+                (
+                    p.pre_launch_command.clone(),
+                    p.post_exit_command.clone(),
+                    p.java_path.clone(),
+                )
+            })
+            .unwrap_or((None, None, None))
+    };
+
     // Migrate old UUID-based folder to name-based folder if needed
     let game_dir = dirs::data_dir()
         .unwrap_or_else(|| PathBuf::from("."))
@@ -198,58 +416,89 @@ pub async fn launch_game(
         }
     }
 
-    // Emit progress events to frontend
-    let app_clone = app.clone();
-    let progress_callback = move |current: u64, total: u64, file: &str| {
-        let _ = app_clone.emit(
-            "download_progress",
-            serde_json::json!({
-                "current": current,
-                "total": total,
-                "file": file,
-            }),
-        );
-    };
+    // Reset the cancel flag for this launch and set up the ordered progress
+    // stream: every stage below sends a `LaunchProgress` onto `progress_tx`,
+    // and this forwarding task re-emits each one as a single `launch_progress`
+    // event in the order it was sent, instead of racing separate `app.emit`s.
+    state
+        .launch_cancel
+        .store(false, std::sync::atomic::Ordering::SeqCst);
+    let cancel_flag = state.launch_cancel.clone();
+    let (progress_tx, mut progress_rx) = tokio::sync::mpsc::unbounded_channel::<LaunchProgress>();
+    let emit_app = app.clone();
+    tokio::spawn(async move {
+        while let Some(progress) = progress_rx.recv().await {
+            let _ = emit_app.emit("launch_progress", &progress);
+        }
+    });
+
+    // Same forwarding pattern for captured game stdout/stderr, so the UI can
+    // tail the log live via a single ordered `game_log_line` event stream.
+    let (log_tx, mut log_rx) =
+        tokio::sync::mpsc::unbounded_channel::<crate::minecraft::GameLogLine>();
+    let emit_app_logs = app.clone();
+    tokio::spawn(async move {
+        while let Some(line) = log_rx.recv().await {
+            let _ = emit_app_logs.emit("game_log_line", &line);
+        }
+    });
 
     // Check if Minecraft is downloaded
-    app.emit("launch_state", "checking").ok();
+    let _ = progress_tx.send(LaunchProgress::stage(LaunchStage::Checking));
 
     // Download Minecraft if needed
-    app.emit("launch_state", "downloading_minecraft").ok();
+    let _ = progress_tx.send(LaunchProgress::stage(LaunchStage::DownloadingMinecraft));
+    let tx = progress_tx.clone();
+    let progress_callback = move |current: u64, total: u64, file: &str| {
+        let _ = tx.send(LaunchProgress {
+            stage: LaunchStage::DownloadingMinecraft,
+            current,
+            total,
+            file: Some(file.to_string()),
+            detail: None,
+        });
+    };
     state
         .minecraft_manager
         .download_minecraft(&version, progress_callback)
         .await
         .map_err(|e| e.to_string())?;
 
-    // Download Fabric
-    app.emit("launch_state", "downloading_fabric").ok();
-    let fabric_version = state
+    // Download the profile's mod loader
+    let _ = progress_tx.send(LaunchProgress::stage(LaunchStage::DownloadingFabric));
+    let loader = state
+        .profile_manager
+        .lock()
+        .unwrap()
+        .get_profile(&active_profile_id)
+        .map(|p| p.loader.clone())
+        .unwrap_or_else(|| "fabric".to_string());
+    let loader_kind = LoaderKind::parse(&loader);
+    let loader_version = state
         .minecraft_manager
-        .get_fabric_loader(&version)
+        .get_loader_version(loader_kind, &version)
         .await
         .map_err(|e| e.to_string())?;
 
-    let app_clone = app.clone();
+    let tx = progress_tx.clone();
     let progress_callback = move |current: u64, total: u64, file: &str| {
-        let _ = app_clone.emit(
-            "download_progress",
-            serde_json::json!({
-                "current": current,
-                "total": total,
-                "file": file,
-            }),
-        );
+        let _ = tx.send(LaunchProgress {
+            stage: LaunchStage::DownloadingFabric,
+            current,
+            total,
+            file: Some(file.to_string()),
+            detail: None,
+        });
     };
 
     state
         .minecraft_manager
-        .download_fabric(&version, &fabric_version, progress_callback)
+        .download_loader(loader_kind, &version, &loader_version, progress_callback)
         .await
         .map_err(|e| e.to_string())?;
 
     // Check for Miracle Client updates and install mod
-    app.emit("launch_state", "downloading_mods").ok();
+    let _ = progress_tx.send(LaunchProgress::stage(LaunchStage::DownloadingMods));
 
     // Map Minecraft version to mod version (1.21.4 or 1.21.8)
     // This is important because Supabase stores releases by mod version, not raw MC version
@@ -300,10 +549,10 @@ pub async fn launch_game(
                     }
 
                     // Download the update
-                    app.emit("launch_state", "updating_mod").ok();
+                    let _ = progress_tx.send(LaunchProgress::stage(LaunchStage::UpdatingMod));
                     match state
                         .supabase
-                        .download_mod_update(&update_info, &mods_dir)
+                        .download_and_verify(&update_info, &mods_dir)
                         .await
                     {
                         Ok(_) => {
@@ -320,7 +569,15 @@ pub async fn launch_game(
                         }
                         Err(e) => {
                             tracing::error!("Failed to download mod update: {}", e);
-                            // Fall back to bundled mod
+
+                            if update_info.mandatory {
+                                return Err(format!(
+                                    "Mandatory Miracle Client update to {} failed: {}",
+                                    update_info.latest_version, e
+                                ));
+                            }
+
+                            // Optional update failed - fall back to bundled mod
                             install_bundled_mod(&app, &version, &profile_dir_name)
                                 .await
                                 .map_err(|e| e.to_string())?;
@@ -347,8 +604,6 @@ pub async fn launch_game(
             .map_err(|e| e.to_string())?;
     }
 
-    // Resolve and install missing dependencies (including Fabric API)
-    tracing::info!("Resolving mod dependencies...");
     // Use profile-specific mods directory
     let mods_dir = dirs::data_dir()
         .unwrap_or_else(|| PathBuf::from("."))
@@ -357,7 +612,14 @@ pub async fn launch_game(
         .join(&version)
         .join(&profile_dir_name);
 
-    if let Ok(installed_deps) = resolve_and_install_dependencies(&mods_dir, &version).await {
+    check_launch_cancelled(&cancel_flag, &progress_tx, &mods_dir)?;
+
+    // Resolve and install missing dependencies (including Fabric API)
+    tracing::info!("Resolving mod dependencies...");
+    let _ = progress_tx.send(LaunchProgress::stage(LaunchStage::ResolvingDependencies));
+    if let Ok(installed_deps) =
+        resolve_and_install_dependencies(&mods_dir, &version, &loader, Some(&app)).await
+    {
         if !installed_deps.is_empty() {
             tracing::info!(
                 "Auto-installed {} dependencies: {:?}",
@@ -367,20 +629,73 @@ pub async fn launch_game(
         }
     }
 
+    check_launch_cancelled(&cancel_flag, &progress_tx, &mods_dir)?;
+
+    // Resolve a Java runtime: an explicit per-launch override wins, then the
+    // profile's saved preference, and only if neither is set do we fall back
+    // to auto-detecting (or downloading a matching Temurin build).
+    let java_path = match javaPath.or(profile_java_path) {
+        Some(path) => path,
+        None => {
+            let _ = progress_tx.send(LaunchProgress::stage(LaunchStage::ResolvingJava));
+            let tx = progress_tx.clone();
+            let progress_callback = move |current: u64, total: u64, file: &str| {
+                let _ = tx.send(LaunchProgress {
+                    stage: LaunchStage::ResolvingJava,
+                    current,
+                    total,
+                    file: Some(file.to_string()),
+                    detail: None,
+                });
+            };
+            let required_major = state
+                .minecraft_manager
+                .required_java_major(&version)
+                .await
+                .map_err(|e| e.to_string())?;
+            state
+                .java_manager
+                .resolve_for(&version, required_major, progress_callback)
+                .await
+                .map_err(|e| e.to_string())?
+                .display()
+                .to_string()
+        }
+    };
+
+    check_launch_cancelled(&cancel_flag, &progress_tx, &mods_dir)?;
+
+    // Run the profile's pre-launch hook, if any, aborting the launch if it fails.
+    if let Some(command) = &pre_launch_command {
+        tracing::info!("Running pre-launch hook: {}", command);
+        run_profile_hook(command, &mods_dir, &version, &profile_dir_name, &username, &uuid)?;
+    }
+
     // Launch the game with profile-specific mods folder
-    app.emit("launch_state", "launching").ok();
+    let _ = progress_tx.send(LaunchProgress::stage(LaunchStage::Launching));
+
+    let launch_timestamp = chrono::Utc::now().format("%Y%m%d-%H%M%S");
+    let log_path = get_logs_directory(Some(&version), Some(&profile_dir_name))
+        .join(format!("launch-{}.log", launch_timestamp));
+    let log_capture = crate::minecraft::LogCapture {
+        log_path,
+        line_tx: Some(log_tx),
+    };
 
     let child_process = state
         .minecraft_manager
         .launch(
             &version,
-            &fabric_version,
+            loader_kind,
+            &loader_version,
             &accessToken,
             &username,
             &uuid,
             ram,
             showGameLogs.unwrap_or(false),
             Some(&profile_dir_name),
+            Some(&java_path),
+            Some(log_capture),
         )
         .await
         .map_err(|e| e.to_string())?;
@@ -392,7 +707,7 @@ pub async fn launch_game(
     *state.game_process.lock().unwrap() = Some(child_process);
     *state.current_player.lock().unwrap() = Some((uuid.clone(), username.clone()));
 
-    app.emit("launch_state", "running").ok();
+    let _ = progress_tx.send(LaunchProgress::stage(LaunchStage::Running));
 
     // Update online status to "online"
     let _ = state.supabase.update_user_status(&uuid, true, None).await;
@@ -407,6 +722,9 @@ pub async fn launch_game(
     let app_clone = app.clone();
     let uuid_clone = uuid.clone();
     let username_clone = username.clone();
+    let mods_dir_clone = mods_dir.clone();
+    let version_clone = version.clone();
+    let profile_dir_clone = profile_dir_name.clone();
     tokio::spawn(async move {
         // Poll to check if process is still running and handle auth requests
         loop {
@@ -437,6 +755,23 @@ pub async fn launch_game(
 
             if !is_running {
                 tracing::info!("Game process {} has exited", pid);
+
+                // Run the profile's post-exit hook, if any, before flipping
+                // the user offline.
+                if let Some(command) = &post_exit_command {
+                    tracing::info!("Running post-exit hook: {}", command);
+                    if let Err(e) = run_profile_hook(
+                        command,
+                        &mods_dir_clone,
+                        &version_clone,
+                        &profile_dir_clone,
+                        &username_clone,
+                        &uuid_clone,
+                    ) {
+                        tracing::warn!("Post-exit hook failed: {}", e);
+                    }
+                }
+
                 // Get state to clear the process and update offline status
                 if let Some(state) = app_clone.try_state::<AppState>() {
                     *state.game_process.lock().unwrap() = None;
@@ -910,6 +1245,19 @@ pub async fn delete_all_mod_folders() -> Result<String, String> {
     Ok(format!("Deleted {} version mod folders", deleted_count))
 }
 
+/// Signal an in-flight `launch_game` to abort at its next stage boundary.
+/// `launch_game` checks this between stages (it can't interrupt an HTTP
+/// download already in flight) and, on cancelling, removes the profile's
+/// Miracle Client jar (see `check_launch_cancelled`).
+#[tauri::command]
+pub fn cancel_launch(app: AppHandle) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    state
+        .launch_cancel
+        .store(true, std::sync::atomic::Ordering::SeqCst);
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn stop_game(app: AppHandle) -> Result<(), String> {
     let state = app.state::<AppState>();
@@ -941,6 +1289,7 @@ pub async fn stop_game(app: AppHandle) -> Result<(), String> {
 /// Resolve and install missing dependencies for all mods
 #[tauri::command]
 pub async fn resolve_dependencies_for_version(
+    app: AppHandle,
     minecraft_version: String,
 ) -> Result<Vec<String>, String> {
     let game_dir = dirs::data_dir()
@@ -958,7 +1307,9 @@ pub async fn resolve_dependencies_for_version(
         minecraft_version
     );
 
-    let installed = resolve_and_install_dependencies(&mods_dir, &minecraft_version).await?;
+    let installed =
+        resolve_and_install_dependencies(&mods_dir, &minecraft_version, "fabric", Some(&app))
+            .await?;
 
     if installed.is_empty() {
         tracing::info!("All dependencies satisfied!");
@@ -995,6 +1346,10 @@ pub struct ModInfo {
     version: String,
     enabled: bool,
     filename: String,
+    /// Mod loader the jar's metadata identified it as: `fabric`, `quilt`,
+    /// `forge`, `neoforge`, or `unknown` when none of the recognized
+    /// metadata files could be parsed.
+    loader: String,
 }
 
 /// Helper function to get the mods directory for a version and optional profile.
@@ -1055,6 +1410,18 @@ fn get_datapacks_directory(minecraft_version: Option<&str>, profile_dir: Option<
     }
 }
 
+fn get_logs_directory(minecraft_version: Option<&str>, profile_dir: Option<&str>) -> PathBuf {
+    let game_dir = dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("MiracleClient");
+
+    match (minecraft_version, profile_dir) {
+        (Some(version), Some(profile)) => game_dir.join("logs").join(version).join(profile),
+        (Some(version), None) => game_dir.join("logs").join(version),
+        _ => game_dir.join("logs"),
+    }
+}
+
 /// Look up profile name from ID and return sanitized directory name
 pub(super) fn get_profile_dir_name(state: &AppState, profile_id: &str) -> String {
     let manager = state.profile_manager.lock().unwrap();
@@ -1069,6 +1436,7 @@ pub(super) fn get_profile_dir_name(state: &AppState, profile_id: &str) -> String
 pub async fn install_performance_mods(
     mods_dir: &PathBuf,
     minecraft_version: &str,
+    loader: &str,
 ) -> Result<Vec<String>, String> {
     let mut installed = Vec::new();
 
@@ -1091,6 +1459,11 @@ pub async fn install_performance_mods(
         Vec::new()
     };
 
+    // Prefer the Miracle CDN manifest when it has a hash-verified build for this
+    // Minecraft version; jars not published there fall back to Modrinth.
+    let update_manager = UpdateManager::new(env!("CARGO_PKG_VERSION"));
+    let cdn_manifest = update_manager.fetch_cdn_manifest().await.unwrap_or_default();
+
     for mod_slug in PERFORMANCE_MODS {
         // Check if this mod is already installed (by checking if filename contains the slug)
         let already_installed = existing_files
@@ -1102,9 +1475,35 @@ pub async fn install_performance_mods(
             continue;
         }
 
-        // Download from Modrinth directly to the mods directory
         tracing::info!("Installing performance mod: {}", mod_slug);
-        match modrinth::download_mod_to_dir(mod_slug, minecraft_version, mods_dir).await {
+
+        let cdn_entry = cdn_manifest
+            .iter()
+            .find(|m| m.mod_id == *mod_slug && m.minecraft_version == minecraft_version);
+
+        if let Some(cdn_mod) = cdn_entry {
+            let dest = mods_dir.join(format!("{}-{}.jar", mod_slug, cdn_mod.version));
+            match update_manager
+                .download_verified(&cdn_mod.download_url, &dest, &cdn_mod.sha256)
+                .await
+            {
+                Ok(()) => {
+                    tracing::info!("Installed performance mod from CDN: {}", mod_slug);
+                    installed.push(mod_slug.to_string());
+                    continue;
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "CDN download of {} failed verification, falling back to Modrinth: {}",
+                        mod_slug,
+                        e
+                    );
+                }
+            }
+        }
+
+        // Fall back to Modrinth directly into the mods directory
+        match modrinth::download_mod_to_dir(mod_slug, minecraft_version, loader, mods_dir).await {
             Ok(filename) => {
                 tracing::info!("Installed performance mod: {} -> {}", mod_slug, filename);
                 installed.push(mod_slug.to_string());
@@ -1129,7 +1528,105 @@ pub async fn ensure_performance_mods(
     let state = app.state::<AppState>();
     let profile_dir = get_profile_dir_name(&state, &profile_id);
     let mods_dir = get_mods_directory(Some(&minecraft_version), Some(&profile_dir));
-    install_performance_mods(&mods_dir, &minecraft_version).await
+    let loader = state
+        .profile_manager
+        .lock()
+        .unwrap()
+        .get_profile(&profile_id)
+        .map(|p| p.loader.clone())
+        .unwrap_or_else(|| "fabric".to_string());
+    install_performance_mods(&mods_dir, &minecraft_version, &loader).await
+}
+
+/// Metadata recovered from a mod jar: (id, name, version, loader).
+type JarModMetadata = (String, String, String, String);
+
+/// Read `fabric.mod.json` from an already-open jar archive.
+fn read_fabric_mod_json(archive: &mut zip::ZipArchive<std::fs::File>) -> Option<JarModMetadata> {
+    let mut file = archive.by_name("fabric.mod.json").ok()?;
+    let mut contents = String::new();
+    std::io::Read::read_to_string(&mut file, &mut contents).ok()?;
+    let json = serde_json::from_str::<serde_json::Value>(&contents).ok()?;
+
+    let id = json["id"].as_str()?.to_string();
+    let name = json["name"].as_str().unwrap_or(&id).to_string();
+    let version = json["version"].as_str().unwrap_or("Unknown").to_string();
+    Some((id, name, version, "fabric".to_string()))
+}
+
+/// Read `quilt.mod.json` from an already-open jar archive.
+fn read_quilt_mod_json(archive: &mut zip::ZipArchive<std::fs::File>) -> Option<JarModMetadata> {
+    let mut file = archive.by_name("quilt.mod.json").ok()?;
+    let mut contents = String::new();
+    std::io::Read::read_to_string(&mut file, &mut contents).ok()?;
+    let json = serde_json::from_str::<serde_json::Value>(&contents).ok()?;
+
+    let loader = &json["quilt_loader"];
+    let id = loader["id"].as_str()?.to_string();
+    let name = loader["metadata"]["name"]
+        .as_str()
+        .unwrap_or(&id)
+        .to_string();
+    let version = loader["version"].as_str().unwrap_or("Unknown").to_string();
+    Some((id, name, version, "quilt".to_string()))
+}
+
+/// Read `Implementation-Version` out of a jar's `META-INF/MANIFEST.MF`, used
+/// to resolve Forge/NeoForge's `${file.jarVersion}` placeholder.
+fn read_manifest_implementation_version(
+    archive: &mut zip::ZipArchive<std::fs::File>,
+) -> Option<String> {
+    let mut file = archive.by_name("META-INF/MANIFEST.MF").ok()?;
+    let mut contents = String::new();
+    std::io::Read::read_to_string(&mut file, &mut contents).ok()?;
+    contents.lines().find_map(|line| {
+        line.strip_prefix("Implementation-Version:")
+            .map(|v| v.trim().to_string())
+    })
+}
+
+/// Read a Forge-family `mods.toml`/`neoforge.mods.toml` from an already-open
+/// jar archive, resolving `${file.jarVersion}` against the jar's manifest.
+fn read_forge_mods_toml(
+    archive: &mut zip::ZipArchive<std::fs::File>,
+    entry_name: &str,
+    loader: &str,
+) -> Option<JarModMetadata> {
+    let mut file = archive.by_name(entry_name).ok()?;
+    let mut contents = String::new();
+    std::io::Read::read_to_string(&mut file, &mut contents).ok()?;
+    let parsed: toml::Value = toml::from_str(&contents).ok()?;
+
+    let entry = parsed.get("mods")?.as_array()?.first()?;
+    let id = entry.get("modId")?.as_str()?.to_string();
+    let name = entry
+        .get("displayName")
+        .and_then(|v| v.as_str())
+        .unwrap_or(&id)
+        .to_string();
+    let mut version = entry
+        .get("version")
+        .and_then(|v| v.as_str())
+        .unwrap_or("Unknown")
+        .to_string();
+
+    if version == "${file.jarVersion}" {
+        version = read_manifest_implementation_version(archive).unwrap_or(version);
+    }
+
+    Some((id, name, version, loader.to_string()))
+}
+
+/// Try every recognized mod metadata format against a jar, in order of how
+/// common the loader is in this launcher's ecosystem.
+fn read_jar_mod_metadata(jar_path: &std::path::Path) -> Option<JarModMetadata> {
+    let file = std::fs::File::open(jar_path).ok()?;
+    let mut archive = zip::ZipArchive::new(file).ok()?;
+
+    read_fabric_mod_json(&mut archive)
+        .or_else(|| read_quilt_mod_json(&mut archive))
+        .or_else(|| read_forge_mods_toml(&mut archive, "META-INF/mods.toml", "forge"))
+        .or_else(|| read_forge_mods_toml(&mut archive, "META-INF/neoforge.mods.toml", "neoforge"))
 }
 
 #[tauri::command]
@@ -1172,31 +1669,25 @@ pub async fn get_installed_mods(
                 continue;
             };
 
-            // Try to parse fabric.mod.json from the JAR
-            if let Ok(file) = std::fs::File::open(&jar_path) {
-                if let Ok(mut archive) = zip::ZipArchive::new(file) {
-                    if let Ok(mut mod_json_file) = archive.by_name("fabric.mod.json") {
-                        let mut contents = String::new();
-                        if std::io::Read::read_to_string(&mut mod_json_file, &mut contents).is_ok()
-                        {
-                            if let Ok(json) = serde_json::from_str::<serde_json::Value>(&contents) {
-                                let id = json["id"].as_str().unwrap_or(&filename).to_string();
-                                let name = json["name"].as_str().unwrap_or(&id).to_string();
-                                let version =
-                                    json["version"].as_str().unwrap_or("Unknown").to_string();
-
-                                mods.push(ModInfo {
-                                    id: id.clone(),
-                                    name,
-                                    version,
-                                    enabled: is_enabled,
-                                    filename: filename.clone(),
-                                });
-                            }
-                        }
-                    }
-                }
-            }
+            // Try each known loader's metadata format in turn; fall back to
+            // the filename itself so an unrecognized jar still shows up.
+            let (id, name, version, loader) = read_jar_mod_metadata(&jar_path).unwrap_or_else(|| {
+                (
+                    filename.clone(),
+                    filename.clone(),
+                    "Unknown".to_string(),
+                    "unknown".to_string(),
+                )
+            });
+
+            mods.push(ModInfo {
+                id,
+                name,
+                version,
+                enabled: is_enabled,
+                filename: filename.clone(),
+                loader,
+            });
         }
     }
 
@@ -1205,91 +1696,448 @@ pub async fn get_installed_mods(
     Ok(mods)
 }
 
+/// Report from `verify_profile`: which mod jars were already fine, which
+/// were corrupt but successfully re-downloaded, and which couldn't be
+/// repaired at all.
 #[derive(Debug, Serialize, Deserialize)]
-pub struct ContentInfo {
-    name: String,
-    filename: String,
-    enabled: bool,
+pub struct VerifyProfileReport {
+    pub healthy: Vec<String>,
+    pub repaired: Vec<String>,
+    pub unrecoverable: Vec<String>,
+}
+
+/// Hex-encoded SHA1 of a byte slice, matching the hash Modrinth's
+/// `version_file` endpoint expects.
+fn sha1_hex(bytes: &[u8]) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(bytes);
+    format!("{:x}", hasher.finalize())
+}
+
+/// Hex-encoded SHA-256 of a byte slice, used for content-addressed
+/// verification of shared-profile mods (see `hash_profile_mods`).
+fn sha256_hex(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    format!("{:x}", hasher.finalize())
+}
+
+/// Look up a file's expected Modrinth version by SHA1 hash, and confirm
+/// both the hash and size actually match before trusting the jar.
+async fn modrinth_hash_matches(client: &reqwest::Client, bytes: &[u8], sha1_hex: &str) -> bool {
+    let url = format!(
+        "{}/version_file/{}?algorithm=sha1",
+        modrinth::MODRINTH_API_BASE,
+        sha1_hex
+    );
+
+    let Ok(response) = client.get(&url).send().await else {
+        return false;
+    };
+    if !response.status().is_success() {
+        return false;
+    }
+
+    let Ok(version) = response.json::<modrinth::ModrinthVersion>().await else {
+        return false;
+    };
+
+    version
+        .files
+        .iter()
+        .any(|f| f.hashes.sha1 == sha1_hex && f.size as usize == bytes.len())
 }
 
+/// Verify every enabled mod jar in a profile against Modrinth's hash
+/// database, re-downloading anything that's missing, unrecognized, or fails
+/// to open as a valid zip archive. Disabled (`.jar.disabled`) mods are left
+/// alone, same as `get_installed_mods`.
 #[tauri::command]
-pub async fn get_installed_resourcepacks(
+pub async fn verify_profile(
     app: AppHandle,
-    minecraft_version: Option<String>,
-    profile_id: Option<String>,
-) -> Result<Vec<ContentInfo>, String> {
-    let profile_dir = profile_id.as_ref().map(|pid| {
-        let state = app.state::<AppState>();
-        get_profile_dir_name(&state, pid)
-    });
-    let dir = get_resourcepacks_directory(minecraft_version.as_deref(), profile_dir.as_deref());
-    tracing::info!(
-        "get_installed_resourcepacks: Looking in {:?} (version={:?}, profile={:?})",
-        dir,
-        minecraft_version,
-        profile_dir
-    );
-    tracing::info!("Directory exists: {}", dir.exists());
+    profile_id: String,
+    minecraft_version: String,
+) -> Result<VerifyProfileReport, String> {
+    let profile_dir = get_profile_dir_name(&app.state::<AppState>(), &profile_id);
+    let mods_dir = get_mods_directory(Some(&minecraft_version), Some(&profile_dir));
+    let loader = app
+        .state::<AppState>()
+        .profile_manager
+        .lock()
+        .unwrap()
+        .get_profile(&profile_id)
+        .map(|p| p.loader.clone())
+        .unwrap_or_else(|| "fabric".to_string());
+
+    let mut report = VerifyProfileReport {
+        healthy: Vec::new(),
+        repaired: Vec::new(),
+        unrecoverable: Vec::new(),
+    };
 
+    let mods = get_installed_mods(app, Some(minecraft_version.clone()), Some(profile_id)).await?;
+    let client = modrinth::create_client()?;
 
-    if !dir.exists() {
-        return Ok(Vec::new());
-    }
+    for mod_info in mods.into_iter().filter(|m| m.enabled) {
+        let jar_path = mods_dir.join(&mod_info.filename);
+        let bytes = match tokio::fs::read(&jar_path).await {
+            Ok(b) => b,
+            Err(_) => {
+                report.unrecoverable.push(mod_info.filename.clone());
+                continue;
+            }
+        };
 
-    let mut packs = Vec::new();
-    if let Ok(entries) = std::fs::read_dir(&dir) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            let filename = entry.file_name().to_string_lossy().to_string();
+        let opens_as_zip = zip::ZipArchive::new(std::io::Cursor::new(&bytes)).is_ok();
+        let hash = sha1_hex(&bytes);
+        let verified = opens_as_zip && modrinth_hash_matches(&client, &bytes, &hash).await;
 
-            let (is_enabled, name) = if filename.ends_with(".zip.disabled") {
-                (false, filename.trim_end_matches(".disabled").to_string())
-            } else if filename.ends_with(".zip") {
-                (true, filename.clone())
-            } else if path.is_dir() {
-                (true, filename.clone())
-            } else {
-                continue;
-            };
+        if verified {
+            report.healthy.push(mod_info.filename.clone());
+            continue;
+        }
 
-            packs.push(ContentInfo {
-                name: name.trim_end_matches(".zip").to_string(),
-                filename,
-                enabled: is_enabled,
-            });
+        tracing::warn!(
+            "verify_profile: {} failed verification, repairing",
+            mod_info.filename
+        );
+        let _ = tokio::fs::remove_file(&jar_path).await;
+
+        match modrinth::download_mod_to_dir(&mod_info.id, &minecraft_version, &loader, &mods_dir)
+            .await
+        {
+            Ok(_) => report.repaired.push(mod_info.filename.clone()),
+            Err(e) => {
+                tracing::warn!("verify_profile: failed to repair {}: {}", mod_info.id, e);
+                report.unrecoverable.push(mod_info.filename.clone());
+            }
         }
     }
 
-    tracing::info!("Found {} resource packs in {:?}", packs.len(), dir);
-    packs.sort_by(|a, b| a.name.cmp(&b.name));
-    Ok(packs)
+    Ok(report)
 }
 
+/// Audit `profile_id`'s resolved classpath (vanilla + loader libraries,
+/// see [`MinecraftManager::build_classpath`]) for missing or corrupted
+/// jars, independently of [`verify_profile`] (which only checks mod jars
+/// in the mods directory). When `repair` is true, anything unhealthy is
+/// re-downloaded through the same engine `launch_game` uses.
 #[tauri::command]
-pub async fn get_installed_shaders(
+pub async fn verify_library_installation(
     app: AppHandle,
-    minecraft_version: Option<String>,
-    profile_id: Option<String>,
-) -> Result<Vec<ContentInfo>, String> {
-    let profile_dir = profile_id.as_ref().map(|pid| {
-        let state = app.state::<AppState>();
-        get_profile_dir_name(&state, pid)
-    });
-    let dir = get_shaderpacks_directory(minecraft_version.as_deref(), profile_dir.as_deref());
-    tracing::info!(
-        "get_installed_shaders: Looking in {:?} (version={:?}, profile={:?})",
-        dir,
-        minecraft_version,
-        profile_dir
-    );
-    tracing::info!("Directory exists: {}", dir.exists());
+    profile_id: String,
+    minecraft_version: String,
+    repair: bool,
+) -> Result<IntegrityReport, String> {
+    let loader = app
+        .state::<AppState>()
+        .profile_manager
+        .lock()
+        .unwrap()
+        .get_profile(&profile_id)
+        .map(|p| p.loader.clone())
+        .unwrap_or_else(|| "fabric".to_string());
+    let loader_kind = LoaderKind::parse(&loader);
 
-    if !dir.exists() {
-        return Ok(Vec::new());
+    let state = app.state::<AppState>();
+    let loader_version = state
+        .minecraft_manager
+        .get_loader_version(loader_kind, &minecraft_version)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    state
+        .minecraft_manager
+        .verify_installation(&minecraft_version, loader_kind, &loader_version, repair)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// A problem found between the mods installed in a profile's mods
+/// directory, surfaced by `scan_profile_conflicts` so the UI can prompt the
+/// user to disable or resolve them before launching.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ModConflict {
+    /// More than one enabled jar declares the same Fabric mod id.
+    DuplicateId { mod_id: String, filenames: Vec<String> },
+    /// `filename`'s `breaks` entry for `breaks_mod_id` is satisfied by
+    /// `conflicting_filename`, which is also installed and enabled.
+    Breaks {
+        filename: String,
+        mod_id: String,
+        breaks_mod_id: String,
+        version_requirement: String,
+        conflicting_filename: String,
+    },
+    /// `filename`'s `depends` entry for `depends_mod_id` isn't satisfied by
+    /// any other enabled jar in the profile.
+    UnsatisfiedDependency {
+        filename: String,
+        mod_id: String,
+        depends_mod_id: String,
+        version_requirement: String,
+    },
+}
+
+/// Parse one Fabric version-range predicate, e.g. `>=1.0.0`, `~1.2`, a bare
+/// `1.0.0` for an exact match, or `*` for any version. A predicate without a
+/// recognized operator is compared as an exact version: the numeric
+/// dot-separated components must match, missing ones counting as 0 and
+/// non-numeric ones dropped.
+fn version_satisfies_predicate(predicate: &str, version: &str) -> bool {
+    let predicate = predicate.trim();
+    if predicate == "*" {
+        return true;
     }
 
-    let mut packs = Vec::new();
-    if let Ok(entries) = std::fs::read_dir(&dir) {
+    let (op, target) = if let Some(rest) = predicate.strip_prefix(">=") {
+        (">=", rest)
+    } else if let Some(rest) = predicate.strip_prefix("<=") {
+        ("<=", rest)
+    } else if let Some(rest) = predicate.strip_prefix('>') {
+        (">", rest)
+    } else if let Some(rest) = predicate.strip_prefix('<') {
+        ("<", rest)
+    } else if let Some(rest) = predicate.strip_prefix('=') {
+        ("=", rest)
+    } else if let Some(rest) = predicate.strip_prefix('~') {
+        ("~", rest)
+    } else if let Some(rest) = predicate.strip_prefix('^') {
+        ("^", rest)
+    } else {
+        ("=", predicate)
+    };
+
+    let parse_parts =
+        |v: &str| -> Vec<u32> { v.trim().split('.').filter_map(|p| p.parse().ok()).collect() };
+    let version_parts = parse_parts(version);
+    let target_parts = parse_parts(target);
+
+    if op == "~" {
+        return version_parts.first() == target_parts.first()
+            && version_parts.get(1) == target_parts.get(1);
+    }
+    if op == "^" {
+        return version_parts.first() == target_parts.first();
+    }
+
+    let cmp = (0..version_parts.len().max(target_parts.len()))
+        .map(|i| {
+            version_parts
+                .get(i)
+                .copied()
+                .unwrap_or(0)
+                .cmp(&target_parts.get(i).copied().unwrap_or(0))
+        })
+        .find(|o| *o != std::cmp::Ordering::Equal)
+        .unwrap_or(std::cmp::Ordering::Equal);
+
+    match op {
+        ">=" => cmp != std::cmp::Ordering::Less,
+        "<=" => cmp != std::cmp::Ordering::Greater,
+        ">" => cmp == std::cmp::Ordering::Greater,
+        "<" => cmp == std::cmp::Ordering::Less,
+        _ => cmp == std::cmp::Ordering::Equal,
+    }
+}
+
+/// Check a full Fabric version requirement, which may list several
+/// space-or-comma-separated predicates that must ALL hold. `breaks` entries
+/// use this exact same range syntax as `depends`.
+fn version_matches_requirement(requirement: &str, version: &str) -> bool {
+    requirement
+        .split([' ', ','])
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .all(|predicate| version_satisfies_predicate(predicate, version))
+}
+
+/// Scan every enabled jar in a profile's mods directory for `fabric.mod.json`
+/// conflicts: more than one jar claiming the same mod id, a `breaks` range
+/// satisfied by another installed mod, or a `depends` range nothing installed
+/// satisfies. Jars that aren't Fabric mods (or fail to parse) are silently
+/// skipped, same as `resolve_dependencies`.
+#[tauri::command]
+pub async fn scan_profile_conflicts(
+    app: AppHandle,
+    minecraft_version: String,
+    profile_id: String,
+) -> Result<Vec<ModConflict>, String> {
+    let profile_dir = get_profile_dir_name(&app.state::<AppState>(), &profile_id);
+    let mods_dir = get_mods_directory(Some(&minecraft_version), Some(&profile_dir));
+
+    if !mods_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let entries =
+        std::fs::read_dir(&mods_dir).map_err(|e| format!("Failed to read mods directory: {}", e))?;
+
+    let mut mods: Vec<(String, dependency_resolver::FabricModJson)> = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let filename = entry.file_name().to_string_lossy().to_string();
+
+        if !filename.ends_with(".jar") {
+            continue;
+        }
+
+        if let Ok(metadata) = dependency_resolver::parse_mod_metadata(&path) {
+            mods.push((filename, metadata));
+        }
+    }
+
+    let mut conflicts = Vec::new();
+
+    let mut filenames_by_id: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (filename, metadata) in &mods {
+        filenames_by_id
+            .entry(metadata.id.as_str())
+            .or_default()
+            .push(filename.as_str());
+    }
+    for (mod_id, filenames) in &filenames_by_id {
+        if filenames.len() > 1 {
+            conflicts.push(ModConflict::DuplicateId {
+                mod_id: mod_id.to_string(),
+                filenames: filenames.iter().map(|f| f.to_string()).collect(),
+            });
+        }
+    }
+
+    for (filename, metadata) in &mods {
+        if let Some(breaks) = &metadata.breaks {
+            for (breaks_mod_id, requirement) in breaks {
+                for (other_filename, other) in &mods {
+                    if &other.id == breaks_mod_id
+                        && version_matches_requirement(requirement, &other.version)
+                    {
+                        conflicts.push(ModConflict::Breaks {
+                            filename: filename.clone(),
+                            mod_id: metadata.id.clone(),
+                            breaks_mod_id: breaks_mod_id.clone(),
+                            version_requirement: requirement.clone(),
+                            conflicting_filename: other_filename.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        if let Some(depends) = &metadata.depends {
+            for (depends_mod_id, requirement) in depends {
+                if depends_mod_id == "minecraft"
+                    || depends_mod_id == "java"
+                    || depends_mod_id == "fabricloader"
+                {
+                    continue;
+                }
+
+                let satisfied = mods.iter().any(|(_, other)| {
+                    &other.id == depends_mod_id
+                        && version_matches_requirement(requirement, &other.version)
+                });
+
+                if !satisfied {
+                    conflicts.push(ModConflict::UnsatisfiedDependency {
+                        filename: filename.clone(),
+                        mod_id: metadata.id.clone(),
+                        depends_mod_id: depends_mod_id.clone(),
+                        version_requirement: requirement.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(conflicts)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ContentInfo {
+    name: String,
+    filename: String,
+    enabled: bool,
+}
+
+#[tauri::command]
+pub async fn get_installed_resourcepacks(
+    app: AppHandle,
+    minecraft_version: Option<String>,
+    profile_id: Option<String>,
+) -> Result<Vec<ContentInfo>, String> {
+    let profile_dir = profile_id.as_ref().map(|pid| {
+        let state = app.state::<AppState>();
+        get_profile_dir_name(&state, pid)
+    });
+    let dir = get_resourcepacks_directory(minecraft_version.as_deref(), profile_dir.as_deref());
+    tracing::info!(
+        "get_installed_resourcepacks: Looking in {:?} (version={:?}, profile={:?})",
+        dir,
+        minecraft_version,
+        profile_dir
+    );
+    tracing::info!("Directory exists: {}", dir.exists());
+
+
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut packs = Vec::new();
+    if let Ok(entries) = std::fs::read_dir(&dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let filename = entry.file_name().to_string_lossy().to_string();
+
+            let (is_enabled, name) = if filename.ends_with(".zip.disabled") {
+                (false, filename.trim_end_matches(".disabled").to_string())
+            } else if filename.ends_with(".zip") {
+                (true, filename.clone())
+            } else if path.is_dir() {
+                (true, filename.clone())
+            } else {
+                continue;
+            };
+
+            packs.push(ContentInfo {
+                name: name.trim_end_matches(".zip").to_string(),
+                filename,
+                enabled: is_enabled,
+            });
+        }
+    }
+
+    tracing::info!("Found {} resource packs in {:?}", packs.len(), dir);
+    packs.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(packs)
+}
+
+#[tauri::command]
+pub async fn get_installed_shaders(
+    app: AppHandle,
+    minecraft_version: Option<String>,
+    profile_id: Option<String>,
+) -> Result<Vec<ContentInfo>, String> {
+    let profile_dir = profile_id.as_ref().map(|pid| {
+        let state = app.state::<AppState>();
+        get_profile_dir_name(&state, pid)
+    });
+    let dir = get_shaderpacks_directory(minecraft_version.as_deref(), profile_dir.as_deref());
+    tracing::info!(
+        "get_installed_shaders: Looking in {:?} (version={:?}, profile={:?})",
+        dir,
+        minecraft_version,
+        profile_dir
+    );
+    tracing::info!("Directory exists: {}", dir.exists());
+
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut packs = Vec::new();
+    if let Ok(entries) = std::fs::read_dir(&dir) {
         for entry in entries.flatten() {
             let path = entry.path();
             let filename = entry.file_name().to_string_lossy().to_string();
@@ -1389,35 +2237,12 @@ pub async fn toggle_mod(
             let path = entry.path();
             let filename = entry.file_name().to_string_lossy().to_string();
 
-            // Check if this is the mod we're looking for
-            let should_toggle = {
-                if let Ok(file) = std::fs::File::open(&path) {
-                    if let Ok(mut archive) = zip::ZipArchive::new(file) {
-                        if let Ok(mut mod_json_file) = archive.by_name("fabric.mod.json") {
-                            let mut contents = String::new();
-                            if std::io::Read::read_to_string(&mut mod_json_file, &mut contents)
-                                .is_ok()
-                            {
-                                if let Ok(json) =
-                                    serde_json::from_str::<serde_json::Value>(&contents)
-                                {
-                                    json["id"].as_str() == Some(&mod_id)
-                                } else {
-                                    false
-                                }
-                            } else {
-                                false
-                            }
-                        } else {
-                            false
-                        }
-                    } else {
-                        false
-                    }
-                } else {
-                    false
-                }
-            };
+            // Check if this is the mod we're looking for. Recognizes
+            // Fabric/Quilt mod.json as well as Forge/NeoForge mods.toml, not
+            // just fabric.mod.json.
+            let should_toggle = read_jar_mod_metadata(&path)
+                .map(|(id, _, _, _)| id == mod_id)
+                .unwrap_or(false);
 
             if should_toggle {
                 // Toggle the mod
@@ -1446,6 +2271,17 @@ pub async fn uninstall_mod(
     minecraft_version: Option<String>,
     profile_id: Option<String>,
 ) -> Result<(), String> {
+    if let Some(pid) = &profile_id {
+        let state = app.state::<AppState>();
+        let manager = state.profile_manager.lock().unwrap();
+        if manager.is_profile_locked(pid) {
+            return Err(
+                "This profile is linked to a modpack and locked against manual changes"
+                    .to_string(),
+            );
+        }
+    }
+
     let profile_dir = profile_id.as_ref().map(|pid| {
         let state = app.state::<AppState>();
         get_profile_dir_name(&state, pid)
@@ -1457,35 +2293,12 @@ pub async fn uninstall_mod(
         for entry in entries.flatten() {
             let path = entry.path();
 
-            // Check if this is the mod we're looking for
-            let should_delete = {
-                if let Ok(file) = std::fs::File::open(&path) {
-                    if let Ok(mut archive) = zip::ZipArchive::new(file) {
-                        if let Ok(mut mod_json_file) = archive.by_name("fabric.mod.json") {
-                            let mut contents = String::new();
-                            if std::io::Read::read_to_string(&mut mod_json_file, &mut contents)
-                                .is_ok()
-                            {
-                                if let Ok(json) =
-                                    serde_json::from_str::<serde_json::Value>(&contents)
-                                {
-                                    json["id"].as_str() == Some(&mod_id)
-                                } else {
-                                    false
-                                }
-                            } else {
-                                false
-                            }
-                        } else {
-                            false
-                        }
-                    } else {
-                        false
-                    }
-                } else {
-                    false
-                }
-            };
+            // Check if this is the mod we're looking for. Recognizes
+            // Fabric/Quilt mod.json as well as Forge/NeoForge mods.toml, not
+            // just fabric.mod.json.
+            let should_delete = read_jar_mod_metadata(&path)
+                .map(|(id, _, _, _)| id == mod_id)
+                .unwrap_or(false);
 
             if should_delete {
                 // Delete the mod file
@@ -1498,6 +2311,9 @@ pub async fn uninstall_mod(
     Err(format!("Mod with id '{}' not found", mod_id))
 }
 
+/// Download a mod into a profile's mods directory. `mod_slug` is a Modrinth
+/// project slug by default; passing `curseforge_id` or `external_source`
+/// (GitHub Releases / Maven) instead dispatches to that source.
 #[tauri::command]
 pub async fn download_mod(
     app: AppHandle,
@@ -1505,7 +2321,28 @@ pub async fn download_mod(
     minecraft_version: String,
     curseforge_id: Option<i32>,
     profile_id: Option<String>,
+    external_source: Option<ExternalModSource>,
 ) -> Result<String, String> {
+    let loader = {
+        let state = app.state::<AppState>();
+        let manager = state.profile_manager.lock().unwrap();
+
+        if let Some(pid) = &profile_id {
+            if manager.is_profile_locked(pid) {
+                return Err(
+                    "This profile is linked to a modpack and locked against manual changes"
+                        .to_string(),
+                );
+            }
+        }
+
+        profile_id
+            .as_ref()
+            .and_then(|pid| manager.get_profile(pid))
+            .map(|p| p.loader.clone())
+            .unwrap_or_else(|| "fabric".to_string())
+    };
+
     let profile_dir = profile_id.as_ref().map(|pid| {
         let state = app.state::<AppState>();
         get_profile_dir_name(&state, pid)
@@ -1519,8 +2356,86 @@ pub async fn download_mod(
 
     // If curseforge_id is provided, use CurseForge API
     if let Some(project_id) = curseforge_id {
-        return curseforge::download_curseforge_mod(project_id, &minecraft_version, &mods_dir)
-            .await;
+        let result =
+            curseforge::download_curseforge_mod(project_id, &minecraft_version, &mods_dir)
+                .await?;
+
+        if let Some(pid) = &profile_id {
+            let metadata = mod_updates::ModMetadata {
+                source: "curseforge".to_string(),
+                project_slug: project_id.to_string(),
+                project_id: project_id.to_string(),
+                installed_version: result.file_name.clone(),
+                version_id: result.file_id.to_string(),
+                installed_at: chrono::Utc::now().to_rfc3339(),
+                repo_base: None,
+                asset_pattern: None,
+                loader_fallback: None,
+                loader: loader.clone(),
+            };
+            if let Err(e) = mod_updates::update_mod_metadata(
+                &minecraft_version,
+                pid,
+                &result.file_name,
+                metadata,
+            ) {
+                tracing::warn!("Failed to save CurseForge mod metadata: {}", e);
+            }
+        }
+
+        return Ok(if result.dependencies_installed.is_empty() {
+            result.message
+        } else {
+            format!(
+                "{} (also installed: {})",
+                result.message,
+                result.dependencies_installed.join(", ")
+            )
+        });
+    }
+
+    // If a GitHub or Maven source is provided, skip Modrinth entirely and
+    // pull the jar straight from there.
+    if let Some(source) = &external_source {
+        let (filename, resolved_repo_base) =
+            install_from_external_source(source, &mods_dir).await?;
+
+        if let Some(pid) = &profile_id {
+            let (source_tag, project_slug, asset_pattern) = match source {
+                ExternalModSource::Github {
+                    owner_repo,
+                    asset_pattern,
+                } => ("github", owner_repo.clone(), Some(asset_pattern.clone())),
+                ExternalModSource::Maven {
+                    group, artifact, ..
+                } => ("maven", format!("{}:{}", group, artifact), None),
+            };
+            let repo_base = resolved_repo_base;
+
+            let metadata = mod_updates::ModMetadata {
+                source: source_tag.to_string(),
+                project_id: project_slug.clone(),
+                project_slug,
+                installed_version: "unknown".to_string(),
+                version_id: "unknown".to_string(),
+                installed_at: chrono::Utc::now().to_rfc3339(),
+                repo_base,
+                asset_pattern,
+                loader_fallback: None,
+                loader: loader.clone(),
+            };
+            if let Err(e) =
+                mod_updates::update_mod_metadata(&minecraft_version, pid, &filename, metadata)
+            {
+                tracing::warn!("Failed to save mod metadata for {}: {}", filename, e);
+            }
+        }
+
+        resolve_and_install_dependencies(&mods_dir, &minecraft_version, &loader, Some(&app))
+            .await
+            .ok();
+
+        return Ok(format!("Successfully installed: {}", filename));
     }
 
     let client = reqwest::Client::builder()
@@ -1529,11 +2444,16 @@ pub async fn download_mod(
         .build()
         .map_err(|e| e.to_string())?;
 
-    // Get mod versions from Modrinth
-    tracing::info!("Fetching versions for mod: {}", mod_slug);
+    // Get mod versions from Modrinth, filtered to the profile's mod loader
+    // (defaults to "fabric" when there's no profile to read one from).
+    tracing::info!(
+        "Fetching versions for mod: {} (loader={})",
+        mod_slug,
+        loader
+    );
     let versions_url = format!(
-        "https://api.modrinth.com/v2/project/{}/version?game_versions=[\"{}\"]&loaders=[\"fabric\"]",
-        mod_slug, minecraft_version
+        "https://api.modrinth.com/v2/project/{}/version?game_versions=[\"{}\"]&loaders=[\"{}\"]",
+        mod_slug, minecraft_version, loader
     );
 
     let versions_response = client
@@ -1562,10 +2482,14 @@ pub async fn download_mod(
         if let Some(file) = files.first() {
             let download_url = file["url"].as_str().ok_or("No download URL found")?;
             let filename = file["filename"].as_str().ok_or("No filename found")?;
+            let expected_sha512 = file["hashes"]["sha512"].as_str().map(|s| s.to_lowercase());
             let mod_path = mods_dir.join(filename);
 
             // Check if already downloaded
             if mod_path.exists() {
+                resolve_and_install_dependencies(&mods_dir, &minecraft_version, &loader, Some(&app))
+                    .await
+                    .ok();
                 return Ok(format!("Mod already installed: {}", filename));
             }
 
@@ -1595,19 +2519,56 @@ pub async fn download_mod(
                 return Err("Downloaded mod file is too small".to_string());
             }
 
+            if let Some(expected) = &expected_sha512 {
+                let mut hasher = Sha512::new();
+                hasher.update(&bytes);
+                let actual = format!("{:x}", hasher.finalize());
+
+                if &actual != expected {
+                    return Err(format!(
+                        "Downloaded mod file failed checksum verification for {}: expected {}, got {}",
+                        filename, expected, actual
+                    ));
+                }
+            }
+
             tokio::fs::write(&mod_path, bytes)
                 .await
                 .map_err(|e| format!("Failed to write mod file: {}", e))?;
 
             tracing::info!("Mod downloaded successfully: {}", filename);
+
+            // Pull in any missing Fabric dependencies the new mod declares,
+            // same resolver `import_profile` and `resolve_dependencies_for_version`
+            // already use.
+            match resolve_and_install_dependencies(
+                &mods_dir,
+                &minecraft_version,
+                &loader,
+                Some(&app),
+            )
+            .await
+            {
+                Ok(deps) if !deps.is_empty() => {
+                    tracing::info!(
+                        "download_mod: installed {} dependencies for {}: {:?}",
+                        deps.len(),
+                        filename,
+                        deps
+                    );
+                }
+                Ok(_) => {}
+                Err(e) => tracing::warn!("download_mod: dependency resolution failed: {}", e),
+            }
+
             Ok(format!("Successfully installed: {}", filename))
         } else {
             Err("No download file found in version".to_string())
         }
     } else {
         Err(format!(
-            "No compatible version found for Minecraft {} (Fabric)",
-            minecraft_version
+            "No compatible version found for Minecraft {} ({})",
+            minecraft_version, loader
         ))
     }
 }
@@ -1688,6 +2649,63 @@ pub async fn check_miracle_update(
         .await
 }
 
+/// Check for a launcher (not mod) update on a given release track. Separate
+/// from `check_miracle_update`, which tracks the bundled Fabric mod - this
+/// tracks the launcher app itself, so opt-in beta/nightly testers can get
+/// launcher builds ahead of stable users.
+#[tauri::command]
+pub async fn check_for_launcher_update(
+    app: AppHandle,
+    track: crate::supabase::ReleaseTrack,
+) -> Result<Option<crate::supabase::ReleaseInfo>, String> {
+    let state = app.state::<AppState>();
+    state
+        .supabase
+        .check_for_update(env!("CARGO_PKG_VERSION"), track)
+        .await
+}
+
+/// List every Java runtime we can find on this machine (bundled downloads,
+/// `JAVA_HOME`, common install dirs, PATH), for the profile JRE picker.
+#[tauri::command]
+pub async fn get_all_jre(app: AppHandle) -> Result<Vec<crate::java::JreInfo>, String> {
+    let state = app.state::<AppState>();
+    Ok(state.java_manager.get_all_jre().await)
+}
+
+/// Suggest the best installed Java for `minecraft_version` without
+/// downloading anything, for the UI to pre-fill a profile's JRE setting.
+#[tauri::command]
+pub async fn autodetect_java(
+    app: AppHandle,
+    minecraft_version: String,
+) -> Result<Option<crate::java::JreInfo>, String> {
+    let state = app.state::<AppState>();
+    Ok(state.java_manager.autodetect_java(&minecraft_version).await)
+}
+
+/// Find an installed Java matching `minecraft_version`'s required major,
+/// optionally accepting a newer major when `allow_higher` is set.
+#[tauri::command]
+pub async fn find_filtered_jre(
+    app: AppHandle,
+    minecraft_version: String,
+    allow_higher: bool,
+) -> Result<Option<crate::java::JreInfo>, String> {
+    let state = app.state::<AppState>();
+    Ok(state
+        .java_manager
+        .find_filtered_jre(&minecraft_version, allow_higher)
+        .await)
+}
+
+/// Validate a user-picked Java path, returning its major version if it's a
+/// real `java` executable.
+#[tauri::command]
+pub async fn test_jre(path: String) -> Result<u32, String> {
+    crate::java::JavaManager::test_jre(&path).map_err(|e| e.to_string())
+}
+
 /// Download and install a Miracle Client mod update
 #[tauri::command]
 pub async fn download_miracle_update(
@@ -1752,7 +2770,7 @@ pub async fn download_miracle_update(
 
     let downloaded_path = state
         .supabase
-        .download_mod_update(&update_info, &mods_dir)
+        .download_and_verify(&update_info, &mods_dir)
         .await?;
 
     tracing::info!(
@@ -1774,6 +2792,21 @@ pub async fn is_update_service_configured(app: AppHandle) -> Result<bool, String
     Ok(state.supabase.is_configured())
 }
 
+/// Get the currently saved Supabase backend config (falls back to the
+/// baked-in defaults if nothing's been saved)
+#[tauri::command]
+pub async fn get_supabase_config() -> Result<SupabaseConfig, String> {
+    Ok(SupabaseConfig::load())
+}
+
+/// Point the launcher at a different (e.g. self-hosted) Supabase-compatible
+/// backend. Takes effect on next launch, since the shared client in
+/// `AppState` is built once at startup.
+#[tauri::command]
+pub async fn set_supabase_config(config: SupabaseConfig) -> Result<(), String> {
+    config.save()
+}
+
 // ==================== Friends Commands ====================
 
 /// Register/update user in the friends system
@@ -1846,6 +2879,53 @@ pub async fn friends_remove(
     state.supabase.remove_friendship(&friendship_id).await
 }
 
+/// Block another user, refusing any pending/future friend request between
+/// the two until unblocked
+#[tauri::command]
+pub async fn friends_block_user(
+    app: AppHandle,
+    from_uuid: String,
+    to_user_id: String,
+) -> Result<FriendRequestResult, String> {
+    let state = app.state::<AppState>();
+    state.supabase.block_user(&from_uuid, &to_user_id).await
+}
+
+/// Remove a previously created block
+#[tauri::command]
+pub async fn friends_unblock_user(
+    app: AppHandle,
+    from_uuid: String,
+    to_user_id: String,
+) -> Result<FriendRequestResult, String> {
+    let state = app.state::<AppState>();
+    state.supabase.unblock_user(&from_uuid, &to_user_id).await
+}
+
+/// Get the users this account has blocked
+#[tauri::command]
+pub async fn friends_get_blocked(
+    app: AppHandle,
+    minecraft_uuid: String,
+) -> Result<Vec<User>, String> {
+    let state = app.state::<AppState>();
+    state.supabase.get_blocked_users(&minecraft_uuid).await
+}
+
+/// Get friends in common with another user
+#[tauri::command]
+pub async fn friends_get_mutual(
+    app: AppHandle,
+    minecraft_uuid: String,
+    other_user_id: String,
+) -> Result<Vec<User>, String> {
+    let state = app.state::<AppState>();
+    state
+        .supabase
+        .get_mutual_friends(&minecraft_uuid, &other_user_id)
+        .await
+}
+
 /// Update user's online status
 #[tauri::command]
 pub async fn friends_update_status(
@@ -1855,12 +2935,63 @@ pub async fn friends_update_status(
     current_server: Option<String>,
 ) -> Result<(), String> {
     let state = app.state::<AppState>();
+    *state.current_server.lock().unwrap() = current_server.clone();
     state
         .supabase
         .update_user_status(&minecraft_uuid, is_online, current_server.as_deref())
         .await
 }
 
+/// Open the Supabase Realtime presence channel and forward every update to
+/// the frontend as a `friend_presence_update` event, so it can react to a
+/// friend coming online or switching servers without re-polling
+/// `friends_get_list`. The subscription (and its reconnect loop) lives for
+/// the rest of the app's lifetime.
+#[tauri::command]
+pub async fn start_friend_presence_stream(app: AppHandle) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let mut presence_rx = SupabaseRealtimeClient::new(&state.supabase).subscribe_presence();
+
+    tauri::async_runtime::spawn(async move {
+        while let Ok(update) = presence_rx.recv().await {
+            app.emit("friend_presence_update", &update).ok();
+        }
+    });
+
+    Ok(())
+}
+
+/// Start dispatching native OS notifications for incoming friend requests,
+/// friends coming online, and friends joining the server you're on, per the
+/// locally persisted `NotificationSettings`. `my_user_id` is this account's
+/// Supabase `users.id`, used to filter incoming friend requests server-side.
+#[tauri::command]
+pub async fn start_friend_notifications(app: AppHandle, my_user_id: String) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let settings = notifications::load_settings();
+    notifications::subscribe(
+        &state.supabase,
+        my_user_id,
+        settings,
+        state.current_server.clone(),
+    );
+    Ok(())
+}
+
+/// Get the locally persisted desktop-notification settings
+#[tauri::command]
+pub async fn get_notification_settings() -> Result<notifications::NotificationSettings, String> {
+    Ok(notifications::load_settings())
+}
+
+/// Persist desktop-notification settings for future sessions
+#[tauri::command]
+pub async fn set_notification_settings(
+    settings: notifications::NotificationSettings,
+) -> Result<(), String> {
+    notifications::save_settings(&settings)
+}
+
 // ==================== Profile Commands ====================
 
 /// Get all profiles for a Minecraft version
@@ -1925,6 +3056,64 @@ pub async fn set_active_profile(
     manager.set_active_profile(&minecraft_version, &profile_id)
 }
 
+/// Save a profile's Java override (explicit executable path and/or extra
+/// JVM args), or clear it by passing `None` so `launch_game` goes back to
+/// auto-detecting/downloading a matching runtime.
+#[tauri::command]
+pub async fn set_profile_java_config(
+    app: AppHandle,
+    profile_id: String,
+    java_path: Option<String>,
+    jvm_args: Option<String>,
+) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let mut manager = state.profile_manager.lock().unwrap();
+    manager.set_profile_java_config(&profile_id, java_path, jvm_args)
+}
+
+/// Get profiles for a version bucketed by group label, for a UI that wants
+/// to render folders instead of one flat list. A profile in zero groups (or
+/// the version's whole list if groups aren't being used) lands under the
+/// `"Ungrouped"` key; a profile in multiple groups appears under each.
+#[tauri::command]
+pub async fn get_profiles_by_group(
+    app: AppHandle,
+    minecraft_version: String,
+) -> Result<std::collections::HashMap<String, Vec<Profile>>, String> {
+    let state = app.state::<AppState>();
+    let manager = state.profile_manager.lock().unwrap();
+    let profiles = manager.get_profiles(&minecraft_version);
+
+    let mut buckets: std::collections::HashMap<String, Vec<Profile>> =
+        std::collections::HashMap::new();
+    for profile in profiles {
+        if profile.groups.is_empty() {
+            buckets
+                .entry("Ungrouped".to_string())
+                .or_default()
+                .push(profile);
+        } else {
+            for group in &profile.groups {
+                buckets.entry(group.clone()).or_default().push(profile.clone());
+            }
+        }
+    }
+
+    Ok(buckets)
+}
+
+/// Replace a profile's group labels
+#[tauri::command]
+pub async fn set_profile_groups(
+    app: AppHandle,
+    profile_id: String,
+    groups: Vec<String>,
+) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let mut manager = state.profile_manager.lock().unwrap();
+    manager.set_profile_groups(&profile_id, groups)
+}
+
 /// Create a new custom profile
 #[tauri::command]
 pub async fn create_profile(
@@ -1974,8 +3163,23 @@ pub async fn duplicate_profile(
 #[tauri::command]
 pub async fn export_profile(app: AppHandle, profile_id: String) -> Result<ProfileExport, String> {
     let state = app.state::<AppState>();
-    let manager = state.profile_manager.lock().unwrap();
-    manager.export_profile(&profile_id)
+    let mut export = {
+        let manager = state.profile_manager.lock().unwrap();
+        manager.export_profile(&profile_id)?
+    };
+
+    // Fill in which non-Modrinth source each mod came from, if any, so a
+    // re-import knows where to re-fetch it from.
+    let metadata = mod_updates::load_metadata(&export.version, &profile_id);
+    for meta in metadata.mods.values() {
+        if meta.source == "github" || meta.source == "maven" {
+            export
+                .mod_sources
+                .insert(meta.project_slug.clone(), meta.source.clone());
+        }
+    }
+
+    Ok(export)
 }
 
 /// Import a profile from exported format
@@ -1992,6 +3196,10 @@ pub async fn import_profile(
         name,
         version: minecraft_version.clone(),
         mods,
+        groups: Vec::new(),
+        linked_project_id: None,
+        linked_version_id: None,
+        mod_sources: HashMap::new(),
     };
     manager.import_profile(export, &minecraft_version)
 }
@@ -2015,6 +3223,28 @@ pub async fn get_performance_mods() -> Result<Vec<String>, String> {
     Ok(PERFORMANCE_MODS.iter().map(|s| s.to_string()).collect())
 }
 
+/// Export a profile as a standalone `.mrpack` file, unlike `export_profile`
+/// above which only round-trips a bare mod slug list with no versions or
+/// config. See `modpack::export_profile_as_mrpack` for the format.
+#[tauri::command]
+pub async fn export_profile_mrpack(
+    profile_id: String,
+    out_path: String,
+) -> Result<modpack::MrpackExportResult, String> {
+    modpack::export_profile_as_mrpack(profile_id, out_path).await
+}
+
+/// Import an `.mrpack` file's content into an existing profile. See
+/// `modpack::install_mrpack` for the format.
+#[tauri::command]
+pub async fn import_profile_mrpack(
+    app: AppHandle,
+    path: String,
+    profile_id: String,
+) -> Result<modpack::ModpackImportResult, String> {
+    modpack::install_mrpack(app, path, profile_id).await
+}
+
 // ==================== Profile Sharing Commands ====================
 
 use crate::supabase::{ShareProfileResult, SharedProfile};
@@ -2036,6 +3266,8 @@ pub async fn share_profile_online(
         (export.name, export.version, export.mods)
     };
 
+    let mod_hashes = hash_profile_mods(&app, &profile_id, &version, &mods).await;
+
     // Share to Supabase
     state
         .supabase
@@ -2043,12 +3275,51 @@ pub async fn share_profile_online(
             &name,
             &version,
             &mods,
+            &mod_hashes,
             creator_uuid.as_deref(),
             creator_username.as_deref(),
         )
         .await
 }
 
+/// Hash each of `mods`' installed jars in `profile_id`'s real mods
+/// directory, in the same order as `mods`, so the result can be stored as
+/// `SharedProfile::mod_hashes` and later re-checked by
+/// `verify_shared_profile_mods`. A mod that isn't downloaded locally (or
+/// whose jar can't be read) gets an empty string rather than shortening the
+/// array, so `mods[i]`/`mod_hashes[i]` always line up.
+async fn hash_profile_mods(
+    app: &AppHandle,
+    profile_id: &str,
+    minecraft_version: &str,
+    mods: &[String],
+) -> Vec<String> {
+    let profile_dir = get_profile_dir_name(&app.state::<AppState>(), profile_id);
+    let mods_dir = get_mods_directory(Some(minecraft_version), Some(&profile_dir));
+
+    let installed = get_installed_mods(
+        app.clone(),
+        Some(minecraft_version.to_string()),
+        Some(profile_id.to_string()),
+    )
+    .await
+    .unwrap_or_default();
+
+    let mut hashes = Vec::with_capacity(mods.len());
+    for mod_id in mods {
+        let filename = installed.iter().find(|m| &m.id == mod_id).map(|m| m.filename.clone());
+        let hash = match filename {
+            Some(filename) => tokio::fs::read(mods_dir.join(&filename))
+                .await
+                .map(|bytes| sha256_hex(&bytes))
+                .unwrap_or_default(),
+            None => String::new(),
+        };
+        hashes.push(hash);
+    }
+    hashes
+}
+
 /// Get a shared profile by short code
 #[tauri::command]
 pub async fn get_shared_profile(
@@ -2059,6 +3330,95 @@ pub async fn get_shared_profile(
     state.supabase.get_shared_profile(&short_code).await
 }
 
+/// Push a new version of a profile the caller previously shared, under the
+/// same short code, so followers who imported it can be told via
+/// `check_profile_update`. Fails (with `success: false`) if `creator_uuid`
+/// doesn't match the original sharer or if the local profile's version
+/// isn't newer than what's already shared.
+#[tauri::command]
+pub async fn update_shared_profile_online(
+    app: AppHandle,
+    profile_id: String,
+    short_code: String,
+    creator_uuid: String,
+) -> Result<ShareProfileResult, String> {
+    let state = app.state::<AppState>();
+
+    let (version, mods) = {
+        let manager = state.profile_manager.lock().unwrap();
+        let export = manager.export_profile(&profile_id)?;
+        (export.version, export.mods)
+    };
+
+    let mod_hashes = hash_profile_mods(&app, &profile_id, &version, &mods).await;
+
+    state
+        .supabase
+        .update_shared_profile(&short_code, &version, &mods, &mod_hashes, &creator_uuid)
+        .await
+}
+
+/// Check whether a previously-imported shared profile has a newer version
+/// available, without counting as a download.
+#[tauri::command]
+pub async fn check_profile_update(
+    app: AppHandle,
+    short_code: String,
+    installed_version: String,
+) -> Result<Option<SharedProfile>, String> {
+    let state = app.state::<AppState>();
+    state
+        .supabase
+        .check_profile_update(&short_code, &installed_version)
+        .await
+}
+
+/// Per-mod result of re-hashing a downloaded shared profile's installed
+/// jars against the creator's `mod_hashes`, so the UI can show exactly
+/// which mods (if any) failed verification instead of an all-or-nothing
+/// pass/fail.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ModVerificationResult {
+    pub mod_id: String,
+    /// `true` if the installed jar's SHA-256 matches the hash recorded at
+    /// share time, or if there's nothing to check against (an older share
+    /// predating `mod_hashes`, or the creator hadn't hashed that entry).
+    pub verified: bool,
+}
+
+/// Re-hash `profile_id`'s installed mods and compare each against the
+/// hashes recorded when `short_code` was shared, flagging any mismatch -
+/// a tampered download, a substituted file, or a failed re-download.
+#[tauri::command]
+pub async fn verify_shared_profile_mods(
+    app: AppHandle,
+    profile_id: String,
+    short_code: String,
+) -> Result<Vec<ModVerificationResult>, String> {
+    let state = app.state::<AppState>();
+    let shared = state
+        .supabase
+        .get_shared_profile(&short_code)
+        .await?
+        .ok_or_else(|| format!("Profile with code '{}' not found", short_code))?;
+
+    let hashes = hash_profile_mods(&app, &profile_id, &shared.version, &shared.mods).await;
+
+    Ok(shared
+        .mods
+        .iter()
+        .enumerate()
+        .map(|(i, mod_id)| {
+            let expected = shared.mod_hashes.get(i).map(String::as_str).unwrap_or("");
+            let actual = hashes.get(i).map(String::as_str).unwrap_or("");
+            ModVerificationResult {
+                mod_id: mod_id.clone(),
+                verified: expected.is_empty() || expected == actual,
+            }
+        })
+        .collect())
+}
+
 /// Import a shared profile from Supabase by short code
 #[tauri::command]
 pub async fn import_shared_profile(
@@ -2081,6 +3441,10 @@ pub async fn import_shared_profile(
         name: format!("{} (Shared)", shared.name),
         version: target_version.clone(),
         mods: shared.mods,
+        groups: Vec::new(),
+        linked_project_id: None,
+        linked_version_id: None,
+        mod_sources: HashMap::new(),
     };
 
     manager.import_profile(export, &target_version)
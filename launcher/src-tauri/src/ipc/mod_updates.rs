@@ -1,18 +1,47 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use sha1::{Digest as _, Sha1};
+use sha2::{Digest as _, Sha512};
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
+use tauri::{AppHandle, Emitter, Manager};
+
+use super::AppState;
 
 const MODRINTH_API_BASE: &str = "https://api.modrinth.com/v2";
 
 /// Metadata stored for each installed mod to track updates
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModMetadata {
-    pub source: String, // "modrinth" | "curseforge"
+    pub source: String, // "modrinth" | "curseforge" | "github" | "maven"
     pub project_slug: String,
     pub project_id: String,
     pub installed_version: String,
     pub version_id: String,
     pub installed_at: String,
+    /// For `source == "maven"`: the repo base URL (`project_slug` holds
+    /// `group:artifact`). Unused for other sources.
+    #[serde(default)]
+    pub repo_base: Option<String>,
+    /// For `source == "github"`: the `*`-wildcard asset name pattern used to
+    /// pick the matching release asset (`project_slug` holds `owner/repo`).
+    /// Unused for other sources.
+    #[serde(default)]
+    pub asset_pattern: Option<String>,
+    /// Set when the requested loader had no published version and this mod
+    /// was installed from a compatible fallback loader instead (currently
+    /// just Quilt falling back to Fabric). Holds the loader actually used.
+    #[serde(default)]
+    pub loader_fallback: Option<String>,
+    /// The loader this installed version targets, so `check_mod_updates`
+    /// queries Modrinth for the right `loaders=[...]` filter even if the
+    /// profile's own loader setting changes later. Defaults to `"fabric"` for entries
+    /// saved before this field existed.
+    #[serde(default = "default_loader")]
+    pub loader: String,
+}
+
+fn default_loader() -> String {
+    "fabric".to_string()
 }
 
 /// The metadata file that stores info about all installed mods
@@ -25,6 +54,7 @@ pub struct ModMetadataIndex {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModUpdateCheck {
     pub filename: String,
+    pub mod_id: String,
     pub mod_name: String,
     pub current_version: String,
     pub latest_version: String,
@@ -32,6 +62,49 @@ pub struct ModUpdateCheck {
     pub has_update: bool,
     pub source: String,
     pub project_slug: String,
+    pub download_url: String,
+    /// Expected hash of the file at `download_url`, when the source exposes
+    /// one, so [`update_mod`] can verify the download before swapping it into
+    /// place instead of trusting the network unconditionally.
+    #[serde(default)]
+    pub sha512: Option<String>,
+    #[serde(default)]
+    pub sha1: Option<String>,
+}
+
+/// A Modrinth version resolved either via the `version_files` hash lookup or
+/// a regular `project/{id}/version` listing — both return this same shape.
+#[derive(Debug, Clone, Deserialize)]
+struct ModrinthVersion {
+    id: String,
+    project_id: String,
+    version_number: String,
+    name: String,
+    date_published: String,
+    #[serde(default)]
+    game_versions: Vec<String>,
+    files: Vec<ModrinthVersionFileEntry>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ModrinthVersionFileEntry {
+    url: String,
+    #[serde(default)]
+    primary: bool,
+    #[serde(default)]
+    hashes: Option<super::modrinth::ModrinthHashes>,
+}
+
+impl ModrinthVersion {
+    fn primary_file(&self) -> Option<&ModrinthVersionFileEntry> {
+        self.files.iter().find(|f| f.primary).or_else(|| self.files.first())
+    }
+
+    fn primary_download_url(&self) -> String {
+        self.primary_file()
+            .map(|f| f.url.clone())
+            .unwrap_or_default()
+    }
 }
 
 fn create_client() -> Result<reqwest::Client, String> {
@@ -107,153 +180,726 @@ pub fn remove_mod_metadata(version: &str, profile_id: &str, filename: &str) -> R
     save_metadata(version, profile_id, &index)
 }
 
-/// Check all mods in a profile for updates
+/// Check every enabled jar in a profile's mods directory for a newer
+/// compatible version. Mods pinned in `ModMetadataIndex` with
+/// `source == "curseforge"` are checked via CurseForge's fingerprint API;
+/// everything else is resolved by SHA512 hash via Modrinth's
+/// `version_files` endpoint, so it also covers mods that were dropped in
+/// manually or imported from another launcher and never got a `ModMetadata`
+/// entry.
 #[tauri::command]
 pub async fn check_mod_updates(
+    app: AppHandle,
     version: String,
     profile_id: String,
 ) -> Result<Vec<ModUpdateCheck>, String> {
-    let index = load_metadata(&version, &profile_id);
+    let (profile_dir, loader) = {
+        let state = app.state::<AppState>();
+        let manager = state.profile_manager.lock().unwrap();
+        let profile = manager.get_profile(&profile_id);
+        let profile_dir = profile
+            .map(|p| crate::profiles::sanitize_profile_name(&p.name))
+            .unwrap_or_else(|| crate::profiles::sanitize_profile_name(&profile_id));
+        let loader = profile
+            .map(|p| p.loader.clone())
+            .unwrap_or_else(|| "fabric".to_string());
+        (profile_dir, loader)
+    };
 
-    if index.mods.is_empty() {
+    let mods_dir = super::get_mods_directory(Some(&version), Some(&profile_dir));
+    if !mods_dir.exists() {
         return Ok(Vec::new());
     }
 
-    let client = create_client()?;
-    let mut updates = Vec::new();
+    // Mods installed from CurseForge are checked via fingerprint matching
+    // instead of Modrinth's hash lookup, so split them out up front.
+    let index = load_metadata(&version, &profile_id);
+    let curseforge_filenames: HashSet<String> = index
+        .mods
+        .iter()
+        .filter(|(_, m)| m.source == "curseforge")
+        .map(|(filename, _)| filename.clone())
+        .collect();
 
-    for (filename, metadata) in &index.mods {
-        match metadata.source.as_str() {
-            "modrinth" => {
-                if let Some(update) =
-                    check_modrinth_update(&client, filename, metadata, &version).await?
-                {
-                    updates.push(update);
+    let mut hash_to_filename = HashMap::new();
+    let mut curseforge_bytes: HashMap<String, Vec<u8>> = HashMap::new();
+    if let Ok(entries) = std::fs::read_dir(&mods_dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let filename = entry.file_name().to_string_lossy().to_string();
+            if !filename.ends_with(".jar") {
+                continue; // skip .jar.disabled and anything else
+            }
+
+            let bytes = match std::fs::read(&path) {
+                Ok(b) => b,
+                Err(e) => {
+                    tracing::warn!("check_mod_updates: failed to read {}: {}", filename, e);
+                    continue;
                 }
+            };
+
+            if curseforge_filenames.contains(&filename) {
+                curseforge_bytes.insert(filename, bytes);
+                continue;
             }
-            "curseforge" => {
-                // CurseForge update checking would go here
-                // For now, skip - requires more complex API handling
+
+            let mut hasher = Sha512::new();
+            hasher.update(&bytes);
+            let hash = format!("{:x}", hasher.finalize());
+            hash_to_filename.insert(hash, filename);
+        }
+    }
+
+    let mut updates = Vec::new();
+
+    if !curseforge_bytes.is_empty() {
+        updates.extend(check_curseforge_updates(&curseforge_bytes, &version, &loader).await?);
+    }
+
+    if !hash_to_filename.is_empty() {
+        let client = create_client()?;
+        let hashes: Vec<String> = hash_to_filename.keys().cloned().collect();
+        let resolved = resolve_version_files(&client, &hashes, "sha512").await?;
+
+        for (hash, current) in &resolved {
+            let filename = match hash_to_filename.get(hash) {
+                Some(f) => f,
+                None => continue,
+            };
+
+            let mod_loader = index
+                .mods
+                .get(filename)
+                .map(|m| m.loader.as_str())
+                .unwrap_or(&loader);
+            if let Some(update) =
+                check_modrinth_update(&client, filename, current, &version, mod_loader).await?
+            {
+                updates.push(update);
             }
-            _ => {}
         }
     }
 
     Ok(updates)
 }
 
-/// Check a single Modrinth mod for updates
+/// Check CurseForge-sourced mods for updates via fingerprint matching: hash
+/// each jar with CurseForge's whitespace-stripped Murmur2, bulk-resolve the
+/// owning project via `/fingerprints`, then list that project's files for
+/// `game_version`/`loader` and compare `fileDate` to find the newest.
+async fn check_curseforge_updates(
+    jars: &HashMap<String, Vec<u8>>,
+    game_version: &str,
+    loader: &str,
+) -> Result<Vec<ModUpdateCheck>, String> {
+    let mut fingerprint_to_filename: HashMap<i64, String> = HashMap::new();
+    for (filename, bytes) in jars {
+        let fingerprint = super::curseforge::murmur2_fingerprint(bytes) as u32 as i64;
+        fingerprint_to_filename.insert(fingerprint, filename.clone());
+    }
+
+    let fingerprints: Vec<i64> = fingerprint_to_filename.keys().cloned().collect();
+    let matches = super::curseforge::get_files_by_fingerprints(&fingerprints).await?;
+
+    let mut updates = Vec::new();
+    for (mod_id, current_file) in matches {
+        let filename = match fingerprint_to_filename.get(&current_file.file_fingerprint) {
+            Some(f) => f.clone(),
+            None => continue,
+        };
+
+        let files = super::curseforge::get_mod_files_for_loader(mod_id, game_version, loader)
+            .await
+            .unwrap_or_default();
+
+        let best = files
+            .iter()
+            .fold(None::<&super::curseforge::CurseForgeFile>, |best, candidate| {
+                match best {
+                    Some(b) if candidate.file_date <= b.file_date => Some(b),
+                    _ => Some(candidate),
+                }
+            });
+
+        let best = match best {
+            Some(f) => f,
+            None => continue,
+        };
+
+        if best.file_date <= current_file.file_date || best.id == current_file.id {
+            continue; // already on the latest compatible file
+        }
+
+        let download_url = match &best.download_url {
+            Some(url) => url.clone(),
+            None => continue, // nothing we could download anyway
+        };
+
+        // algo == 1 is sha1 - see CurseForgeFileHash's doc comment.
+        let sha1 = best
+            .hashes
+            .iter()
+            .find(|h| h.algo == 1)
+            .map(|h| h.value.clone());
+
+        updates.push(ModUpdateCheck {
+            filename,
+            mod_id: mod_id.to_string(),
+            mod_name: best
+                .file_name
+                .strip_suffix(".jar")
+                .unwrap_or(&best.file_name)
+                .to_string(),
+            current_version: current_file.file_name,
+            latest_version: best.file_name.clone(),
+            latest_version_id: best.id.to_string(),
+            has_update: true,
+            source: "curseforge".to_string(),
+            project_slug: mod_id.to_string(),
+            download_url,
+            sha512: None,
+            sha1,
+        });
+    }
+
+    Ok(updates)
+}
+
+/// `true` when every dot-separated component of `version`'s numeric core
+/// (ignoring any `-pre-release`/`+build` suffix) parses as a plain integer -
+/// i.e. it's shaped enough like semver for [`compare_versions`] to order it
+/// meaningfully rather than silently treating garbage components as `0`.
+fn is_valid_semver_core(version: &str) -> bool {
+    let without_build = version.split('+').next().unwrap_or(version);
+    let core = without_build.split('-').next().unwrap_or(without_build);
+    !core.is_empty()
+        && core
+            .split('.')
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// `true` when `candidate` should be considered newer than `current`: real
+/// semver precedence when both version strings parse as semver, otherwise a
+/// plain comparison of `date_published` (an RFC3339 timestamp, so string
+/// order already matches chronological order).
+fn is_newer_candidate(candidate: &ModrinthVersion, current: &ModrinthVersion) -> bool {
+    if is_valid_semver_core(&candidate.version_number) && is_valid_semver_core(&current.version_number)
+    {
+        crate::supabase::compare_versions(&candidate.version_number, &current.version_number)
+            == std::cmp::Ordering::Greater
+    } else {
+        candidate.date_published > current.date_published
+    }
+}
+
+/// Check a single Modrinth mod (already resolved to a project via its jar
+/// hash) for a newer version compatible with `game_version`/`loader`.
 async fn check_modrinth_update(
     client: &reqwest::Client,
     filename: &str,
-    metadata: &ModMetadata,
+    current: &ModrinthVersion,
     game_version: &str,
+    loader: &str,
 ) -> Result<Option<ModUpdateCheck>, String> {
     let url = format!(
-        "{}/project/{}/version?game_versions=[\"{}\"]&loaders=[\"fabric\"]",
-        MODRINTH_API_BASE, metadata.project_slug, game_version
+        "{}/project/{}/version?game_versions=[\"{}\"]&loaders=[\"{}\"]",
+        MODRINTH_API_BASE, current.project_id, game_version, loader
     );
 
-    let response = client.get(&url).send().await.map_err(|e| {
-        format!(
-            "Failed to check updates for {}: {}",
-            metadata.project_slug, e
-        )
-    })?;
+    let response = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to check updates for {}: {}", current.project_id, e))?;
 
     if !response.status().is_success() {
         return Ok(None); // Skip if API error
     }
 
-    #[derive(Deserialize)]
-    struct VersionInfo {
-        id: String,
-        version_number: String,
-        name: String,
+    let versions: Vec<ModrinthVersion> = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse versions: {}", e))?;
+
+    // Defense in depth: the query already filters by game_versions, but
+    // don't trust that alone to pick a compatible "latest".
+    let compatible: Vec<&ModrinthVersion> = versions
+        .iter()
+        .filter(|v| v.game_versions.iter().any(|gv| gv == game_version))
+        .collect();
+
+    let best = compatible
+        .into_iter()
+        .fold(None::<&ModrinthVersion>, |best, candidate| match best {
+            Some(b) if !is_newer_candidate(candidate, b) => Some(b),
+            _ => Some(candidate),
+        });
+
+    let best = match best {
+        Some(v) => v,
+        None => return Ok(None),
+    };
+
+    if !is_newer_candidate(best, current) {
+        return Ok(None); // Already on the latest compatible version
+    }
+
+    let best_hashes = best.primary_file().and_then(|f| f.hashes.as_ref());
+
+    Ok(Some(ModUpdateCheck {
+        filename: filename.to_string(),
+        mod_id: current.project_id.clone(),
+        mod_name: best.name.clone(),
+        current_version: current.version_number.clone(),
+        latest_version: best.version_number.clone(),
+        latest_version_id: best.id.clone(),
+        has_update: true,
+        source: "modrinth".to_string(),
+        project_slug: current.project_id.clone(),
+        download_url: best.primary_download_url(),
+        sha512: best_hashes.map(|h| h.sha512.clone()),
+        sha1: best_hashes.map(|h| h.sha1.clone()),
+    }))
+}
+
+/// Resolve a batch of content hashes to their Modrinth version via
+/// `POST /version_files`, shared by every hash-based lookup in this module.
+async fn resolve_version_files(
+    client: &reqwest::Client,
+    hashes: &[String],
+    algorithm: &str,
+) -> Result<HashMap<String, ModrinthVersion>, String> {
+    if hashes.is_empty() {
+        return Ok(HashMap::new());
     }
 
-    let versions: Vec<VersionInfo> = response
+    let response = client
+        .post(format!("{}/version_files", MODRINTH_API_BASE))
+        .json(&serde_json::json!({ "hashes": hashes, "algorithm": algorithm }))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to resolve mods on Modrinth: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "Failed to resolve mods on Modrinth: HTTP {}",
+            response.status()
+        ));
+    }
+
+    response
         .json()
         .await
-        .map_err(|e| format!("Failed to parse versions: {}", e))?;
+        .map_err(|e| format!("Failed to parse Modrinth version_files response: {}", e))
+}
+
+/// A mod dropped into the mods directory outside the launcher (manually, or
+/// imported from another launcher) that got matched back to a Modrinth
+/// project/version by content hash.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdoptedMod {
+    pub filename: String,
+    pub project_id: String,
+    pub version_number: String,
+}
+
+/// Result of [`adopt_untracked_mods`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdoptUntrackedResult {
+    pub adopted: Vec<AdoptedMod>,
+    pub untracked: Vec<String>,
+}
+
+/// Scan a profile's mods directory for `.jar` files missing from
+/// `ModMetadataIndex`, resolve each back to a Modrinth project/version by
+/// content hash, and persist a reconstructed `ModMetadata` entry for every
+/// match. Modrinth primarily indexes SHA-1 of the exact file bytes, so that's
+/// tried first; whatever SHA-1 misses is retried against SHA-512 before being
+/// given up on as genuinely untracked.
+#[tauri::command]
+pub async fn adopt_untracked_mods(
+    app: AppHandle,
+    version: String,
+    profile_id: String,
+) -> Result<AdoptUntrackedResult, String> {
+    let (profile_dir, loader) = {
+        let state = app.state::<AppState>();
+        let manager = state.profile_manager.lock().unwrap();
+        let profile = manager.get_profile(&profile_id);
+        let profile_dir = profile
+            .map(|p| crate::profiles::sanitize_profile_name(&p.name))
+            .unwrap_or_else(|| crate::profiles::sanitize_profile_name(&profile_id));
+        let loader = profile
+            .map(|p| p.loader.clone())
+            .unwrap_or_else(|| "fabric".to_string());
+        (profile_dir, loader)
+    };
 
-    if let Some(latest) = versions.first() {
-        // Check if there's a newer version
-        if latest.id != metadata.version_id {
-            return Ok(Some(ModUpdateCheck {
-                filename: filename.to_string(),
-                mod_name: latest.name.clone(),
-                current_version: metadata.installed_version.clone(),
-                latest_version: latest.version_number.clone(),
-                latest_version_id: latest.id.clone(),
-                has_update: true,
-                source: "modrinth".to_string(),
-                project_slug: metadata.project_slug.clone(),
-            }));
+    let mods_dir = super::get_mods_directory(Some(&version), Some(&profile_dir));
+    if !mods_dir.exists() {
+        return Ok(AdoptUntrackedResult {
+            adopted: Vec::new(),
+            untracked: Vec::new(),
+        });
+    }
+
+    let index = load_metadata(&version, &profile_id);
+
+    let mut untracked_files: HashMap<String, Vec<u8>> = HashMap::new();
+    if let Ok(entries) = std::fs::read_dir(&mods_dir) {
+        for entry in entries.flatten() {
+            let filename = entry.file_name().to_string_lossy().to_string();
+            if !filename.ends_with(".jar") || index.mods.contains_key(&filename) {
+                continue;
+            }
+            match std::fs::read(entry.path()) {
+                Ok(bytes) => {
+                    untracked_files.insert(filename, bytes);
+                }
+                Err(e) => {
+                    tracing::warn!("adopt_untracked_mods: failed to read {}: {}", filename, e)
+                }
+            }
         }
     }
 
-    Ok(None)
+    if untracked_files.is_empty() {
+        return Ok(AdoptUntrackedResult {
+            adopted: Vec::new(),
+            untracked: Vec::new(),
+        });
+    }
+
+    let client = create_client()?;
+
+    let mut sha1_to_filename: HashMap<String, String> = HashMap::new();
+    for (filename, bytes) in &untracked_files {
+        let mut hasher = Sha1::new();
+        hasher.update(bytes);
+        sha1_to_filename.insert(format!("{:x}", hasher.finalize()), filename.clone());
+    }
+
+    let sha1_hashes: Vec<String> = sha1_to_filename.keys().cloned().collect();
+    let mut resolved = resolve_version_files(&client, &sha1_hashes, "sha1").await?;
+    let mut hash_to_filename = sha1_to_filename;
+
+    let matched_filenames: HashSet<&String> = resolved
+        .keys()
+        .filter_map(|h| hash_to_filename.get(h))
+        .collect();
+    let still_missing: Vec<(&String, &Vec<u8>)> = untracked_files
+        .iter()
+        .filter(|(filename, _)| !matched_filenames.contains(filename))
+        .collect();
+
+    if !still_missing.is_empty() {
+        let mut sha512_to_filename: HashMap<String, String> = HashMap::new();
+        for (filename, bytes) in &still_missing {
+            let mut hasher = Sha512::new();
+            hasher.update(bytes);
+            sha512_to_filename.insert(format!("{:x}", hasher.finalize()), (*filename).clone());
+        }
+
+        let sha512_hashes: Vec<String> = sha512_to_filename.keys().cloned().collect();
+        let sha512_resolved = resolve_version_files(&client, &sha512_hashes, "sha512").await?;
+        for (hash, resolved_version) in sha512_resolved {
+            if let Some(filename) = sha512_to_filename.remove(&hash) {
+                hash_to_filename.insert(hash.clone(), filename);
+                resolved.insert(hash, resolved_version);
+            }
+        }
+    }
+
+    let mut adopted = Vec::new();
+    let mut adopted_filenames = HashSet::new();
+    for (hash, mod_version) in &resolved {
+        let Some(filename) = hash_to_filename.get(hash) else {
+            continue;
+        };
+
+        let metadata = ModMetadata {
+            source: "modrinth".to_string(),
+            project_slug: mod_version.project_id.clone(),
+            project_id: mod_version.project_id.clone(),
+            installed_version: mod_version.version_number.clone(),
+            version_id: mod_version.id.clone(),
+            installed_at: chrono::Utc::now().to_rfc3339(),
+            repo_base: None,
+            asset_pattern: None,
+            loader_fallback: None,
+            loader: loader.clone(),
+        };
+
+        if let Err(e) = update_mod_metadata(&version, &profile_id, filename, metadata) {
+            tracing::warn!("Failed to save adopted metadata for {}: {}", filename, e);
+            continue;
+        }
+
+        adopted_filenames.insert(filename.clone());
+        adopted.push(AdoptedMod {
+            filename: filename.clone(),
+            project_id: mod_version.project_id.clone(),
+            version_number: mod_version.version_number.clone(),
+        });
+    }
+
+    let untracked = untracked_files
+        .keys()
+        .filter(|f| !adopted_filenames.contains(*f))
+        .cloned()
+        .collect();
+
+    Ok(AdoptUntrackedResult { adopted, untracked })
+}
+
+/// What happened when [`update_mod`] was asked to apply a single update.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ModUpdateOutcome {
+    /// The old jar was swapped out for `new_filename` and `ModMetadata` was
+    /// updated to match.
+    Updated { new_filename: String },
+    /// The installed jar's content already matched the expected hash, so
+    /// nothing was downloaded or touched on disk.
+    AlreadyCurrent,
+    /// Writing the new jar or its metadata failed after the old jar had
+    /// already been moved aside; it was restored from its backup and the
+    /// original `ModMetadata` entry was left untouched.
+    RolledBack { reason: String },
+}
+
+/// Verify `bytes` against whichever of `sha512`/`sha1` is known, preferring
+/// sha512. `Ok(())` when neither is known - some sources don't expose a hash
+/// for a given file, and an update shouldn't be blocked entirely for that.
+fn verify_update_hash(bytes: &[u8], sha512: Option<&str>, sha1: Option<&str>) -> Result<(), String> {
+    if let Some(expected) = sha512 {
+        let mut hasher = Sha512::new();
+        hasher.update(bytes);
+        let actual = format!("{:x}", hasher.finalize());
+        return if actual.eq_ignore_ascii_case(expected) {
+            Ok(())
+        } else {
+            Err(format!(
+                "sha512 mismatch: expected {}, got {}",
+                expected, actual
+            ))
+        };
+    }
+
+    if let Some(expected) = sha1 {
+        let mut hasher = Sha1::new();
+        hasher.update(bytes);
+        let actual = format!("{:x}", hasher.finalize());
+        return if actual.eq_ignore_ascii_case(expected) {
+            Ok(())
+        } else {
+            Err(format!(
+                "sha1 mismatch: expected {}, got {}",
+                expected, actual
+            ))
+        };
+    }
+
+    Ok(())
 }
 
-/// Update a single mod to the latest version
+/// Replace a single installed jar with the version described by `update`,
+/// downloading to a temp file first and only swapping it into place once the
+/// bytes are verified against `update.sha512`/`update.sha1` (when known). The
+/// old jar is moved aside to a `.bak` rather than deleted outright, so a
+/// failure after that point - a write error, a metadata-save error - can
+/// restore it instead of leaving the profile with a missing mod. A failed
+/// download or a bad hash returns `Err` before the old jar is touched.
 #[tauri::command]
 pub async fn update_mod(
-    filename: String,
+    app: AppHandle,
     version: String,
     profile_id: String,
-) -> Result<String, String> {
-    let index = load_metadata(&version, &profile_id);
+    update: ModUpdateCheck,
+) -> Result<ModUpdateOutcome, String> {
+    let (profile_dir, loader) = {
+        let state = app.state::<AppState>();
+        let manager = state.profile_manager.lock().unwrap();
+        let profile = manager.get_profile(&profile_id);
+        let profile_dir = profile
+            .map(|p| crate::profiles::sanitize_profile_name(&p.name))
+            .unwrap_or_else(|| crate::profiles::sanitize_profile_name(&profile_id));
+        let loader = profile
+            .map(|p| p.loader.clone())
+            .unwrap_or_else(|| "fabric".to_string());
+        (profile_dir, loader)
+    };
+    let mods_dir = super::get_mods_directory(Some(&version), Some(&profile_dir));
+    let old_path = mods_dir.join(&update.filename);
 
-    let metadata = index
-        .mods
-        .get(&filename)
-        .ok_or_else(|| format!("No metadata found for {}", filename))?;
+    // If the jar that's already on disk matches what we'd be downloading,
+    // there's nothing to do - skip the network round-trip entirely.
+    if let Ok(existing) = std::fs::read(&old_path) {
+        if verify_update_hash(&existing, update.sha512.as_deref(), update.sha1.as_deref()).is_ok()
+            && (update.sha512.is_some() || update.sha1.is_some())
+        {
+            return Ok(ModUpdateOutcome::AlreadyCurrent);
+        }
+    }
 
-    let mods_dir = dirs::data_dir()
-        .unwrap_or_else(|| PathBuf::from("."))
-        .join("MiracleClient")
-        .join("mods")
-        .join(&version)
-        .join(&profile_id);
-
-    match metadata.source.as_str() {
-        "modrinth" => {
-            // Delete the old file
-            let old_path = mods_dir.join(&filename);
-            if old_path.exists() {
-                std::fs::remove_file(&old_path)
-                    .map_err(|e| format!("Failed to remove old mod: {}", e))?;
+    let client = create_client()?;
+    let response = client
+        .get(&update.download_url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to download update: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "Failed to download update: HTTP {}",
+            response.status()
+        ));
+    }
+
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| format!("Failed to read update bytes: {}", e))?;
+
+    if let Err(e) = verify_update_hash(&bytes, update.sha512.as_deref(), update.sha1.as_deref()) {
+        return Err(format!("Downloaded update for {} failed verification: {}", update.filename, e));
+    }
+
+    let new_filename = update
+        .download_url
+        .rsplit('/')
+        .next()
+        .filter(|s| !s.is_empty())
+        .unwrap_or(&update.filename)
+        .to_string();
+    let new_path = mods_dir.join(&new_filename);
+    let backup_path = mods_dir.join(format!("{}.bak", update.filename));
+
+    // Back up whenever old_path exists, even if new_filename == update.filename:
+    // renaming the live jar out of the way first (rather than only when the
+    // filename changes) is what makes the write below safe to retry/restore,
+    // since old_path and new_path can be the same path.
+    let had_backup = old_path.exists();
+    if had_backup {
+        std::fs::rename(&old_path, &backup_path)
+            .map_err(|e| format!("Failed to back up old mod: {}", e))?;
+    }
+
+    let restore_backup = |reason: String| -> ModUpdateOutcome {
+        if had_backup {
+            if let Err(e) = std::fs::rename(&backup_path, &old_path) {
+                tracing::error!(
+                    "update_mod: failed to restore backup for {}: {}",
+                    update.filename,
+                    e
+                );
             }
+        }
+        ModUpdateOutcome::RolledBack { reason }
+    };
+
+    if let Err(e) = std::fs::write(&new_path, &bytes) {
+        return Ok(restore_backup(format!(
+            "Failed to write updated mod: {}",
+            e
+        )));
+    }
+
+    let metadata = ModMetadata {
+        source: update.source.clone(),
+        project_slug: update.project_slug.clone(),
+        project_id: update.mod_id.clone(),
+        installed_version: update.latest_version.clone(),
+        version_id: update.latest_version_id.clone(),
+        installed_at: chrono::Utc::now().to_rfc3339(),
+        repo_base: None,
+        asset_pattern: None,
+        loader_fallback: None,
+        loader,
+    };
 
-            // Download the new version
-            let new_filename =
-                super::modrinth::download_mod_to_dir(&metadata.project_slug, &version, &mods_dir)
-                    .await?;
+    if let Err(e) = update_mod_metadata(&version, &profile_id, &new_filename, metadata) {
+        let _ = std::fs::remove_file(&new_path);
+        return Ok(restore_backup(format!(
+            "Failed to save updated metadata: {}",
+            e
+        )));
+    }
 
-            Ok(format!("Updated {} to {}", filename, new_filename))
+    if new_filename != update.filename {
+        if let Err(e) = remove_mod_metadata(&version, &profile_id, &update.filename) {
+            tracing::warn!(
+                "update_mod: failed to remove stale metadata for {}: {}",
+                update.filename,
+                e
+            );
         }
-        _ => Err("Unsupported source for updates".to_string()),
     }
+
+    if had_backup {
+        if let Err(e) = std::fs::remove_file(&backup_path) {
+            tracing::warn!("update_mod: failed to remove backup {:?}: {}", backup_path, e);
+        }
+    }
+
+    Ok(ModUpdateOutcome::Updated { new_filename })
 }
 
-/// Update all mods that have available updates
+/// Check every installed mod for updates and apply them, emitting
+/// `mod_update_progress` after each one so the frontend can show a live list
+/// as the pack gets brought current.
 #[tauri::command]
-pub async fn update_all_mods(version: String, profile_id: String) -> Result<Vec<String>, String> {
-    let updates = check_mod_updates(version.clone(), profile_id.clone()).await?;
-
+pub async fn update_all_mods(
+    app: AppHandle,
+    version: String,
+    profile_id: String,
+) -> Result<Vec<String>, String> {
+    let updates = check_mod_updates(app.clone(), version.clone(), profile_id.clone()).await?;
     let mut updated = Vec::new();
 
     for update in updates {
-        if update.has_update {
-            match update_mod(update.filename.clone(), version.clone(), profile_id.clone()).await {
-                Ok(msg) => {
-                    tracing::info!("{}", msg);
-                    updated.push(update.mod_name);
-                }
-                Err(e) => {
-                    tracing::warn!("Failed to update {}: {}", update.filename, e);
-                }
+        app.emit(
+            "mod_update_progress",
+            serde_json::json!({ "modName": update.mod_name, "status": "updating" }),
+        )
+        .ok();
+
+        let mod_name = update.mod_name.clone();
+        let old_filename = update.filename.clone();
+
+        match update_mod(app.clone(), version.clone(), profile_id.clone(), update).await {
+            Ok(ModUpdateOutcome::Updated { new_filename }) => {
+                tracing::info!("Updated {} to {}", old_filename, new_filename);
+                app.emit(
+                    "mod_update_progress",
+                    serde_json::json!({ "modName": mod_name, "status": "updated" }),
+                )
+                .ok();
+                updated.push(mod_name);
+            }
+            Ok(ModUpdateOutcome::AlreadyCurrent) => {
+                app.emit(
+                    "mod_update_progress",
+                    serde_json::json!({ "modName": mod_name, "status": "updated" }),
+                )
+                .ok();
+                updated.push(mod_name);
+            }
+            Ok(ModUpdateOutcome::RolledBack { reason }) => {
+                tracing::warn!("Rolled back update for {}: {}", old_filename, reason);
+                app.emit(
+                    "mod_update_progress",
+                    serde_json::json!({ "modName": mod_name, "status": "failed", "error": reason }),
+                )
+                .ok();
+            }
+            Err(e) => {
+                tracing::warn!("Failed to update {}: {}", old_filename, e);
+                app.emit(
+                    "mod_update_progress",
+                    serde_json::json!({ "modName": mod_name, "status": "failed", "error": e }),
+                )
+                .ok();
             }
         }
     }
@@ -261,6 +907,123 @@ pub async fn update_all_mods(version: String, profile_id: String) -> Result<Vec<
     Ok(updated)
 }
 
+/// A pending update resolved via Modrinth's batch hash-update endpoint,
+/// distinct from [`ModUpdateCheck`] in that it trusts the `version_id`
+/// already recorded in `ModMetadata` rather than re-resolving the project
+/// from the jar hash on every check.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModUpdate {
+    pub mod_id: String,
+    pub current_version: String,
+    pub latest_version: String,
+    pub download_url: String,
+}
+
+/// Check installed Modrinth-sourced mods for updates using
+/// `POST /version_files/update`, which resolves the latest version
+/// compatible with `game_version`/the profile's loader for a whole batch of
+/// file hashes in a single request. Unlike [`check_mod_updates`], this relies
+/// on the `project_id`/`version_id` already recorded in `ModMetadata` (set by
+/// `download_modrinth_content`) instead of re-resolving each jar's project
+/// from scratch, so it only covers mods installed through Modrinth.
+#[tauri::command]
+pub async fn check_modrinth_mod_updates(
+    app: AppHandle,
+    game_version: String,
+    profile_id: String,
+) -> Result<Vec<ModUpdate>, String> {
+    let (profile_dir, loader) = {
+        let state = app.state::<AppState>();
+        let manager = state.profile_manager.lock().unwrap();
+        let profile = manager.get_profile(&profile_id);
+        let profile_dir = profile
+            .map(|p| crate::profiles::sanitize_profile_name(&p.name))
+            .unwrap_or_else(|| crate::profiles::sanitize_profile_name(&profile_id));
+        let loader = profile
+            .map(|p| p.loader.clone())
+            .unwrap_or_else(|| "fabric".to_string());
+        (profile_dir, loader)
+    };
+
+    let index = load_metadata(&game_version, &profile_id);
+    let mods_dir = super::get_mods_directory(Some(&game_version), Some(&profile_dir));
+    if !mods_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut hash_to_metadata = HashMap::new();
+    for (filename, metadata) in &index.mods {
+        if metadata.source != "modrinth" {
+            continue;
+        }
+        let bytes = match std::fs::read(mods_dir.join(filename)) {
+            Ok(b) => b,
+            Err(e) => {
+                tracing::warn!(
+                    "check_modrinth_mod_updates: failed to read {}: {}",
+                    filename,
+                    e
+                );
+                continue;
+            }
+        };
+        let mut hasher = Sha512::new();
+        hasher.update(&bytes);
+        let hash = format!("{:x}", hasher.finalize());
+        hash_to_metadata.insert(hash, metadata.clone());
+    }
+
+    if hash_to_metadata.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let client = create_client()?;
+    let hashes: Vec<&String> = hash_to_metadata.keys().collect();
+    let response = client
+        .post(format!("{}/version_files/update", MODRINTH_API_BASE))
+        .json(&serde_json::json!({
+            "hashes": hashes,
+            "algorithm": "sha512",
+            "loaders": [loader],
+            "game_versions": [game_version],
+        }))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to check Modrinth mod updates: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "Failed to check Modrinth mod updates: HTTP {}",
+            response.status()
+        ));
+    }
+
+    let resolved: HashMap<String, ModrinthVersion> = response.json().await.map_err(|e| {
+        format!(
+            "Failed to parse Modrinth version_files/update response: {}",
+            e
+        )
+    })?;
+
+    let mut updates = Vec::new();
+    for (hash, latest) in &resolved {
+        let Some(metadata) = hash_to_metadata.get(hash) else {
+            continue;
+        };
+        if latest.id == metadata.version_id {
+            continue; // already on the latest compatible version
+        }
+        updates.push(ModUpdate {
+            mod_id: metadata.project_id.clone(),
+            current_version: metadata.installed_version.clone(),
+            latest_version: latest.version_number.clone(),
+            download_url: latest.primary_download_url(),
+        });
+    }
+
+    Ok(updates)
+}
+
 /// Get metadata for a specific mod
 #[tauri::command]
 pub async fn get_mod_metadata(
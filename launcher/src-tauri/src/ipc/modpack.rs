@@ -1,11 +1,19 @@
+use futures::stream::{FuturesUnordered, StreamExt};
 use serde::{Deserialize, Serialize};
+use sha1::{Digest as _, Sha1};
+use sha2::Sha512;
 use std::collections::HashMap;
-use std::io::Read;
+use std::io::{Read, Write};
 use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::Emitter;
 use tokio::io::AsyncWriteExt;
+use tokio::sync::Semaphore;
 use zip::ZipArchive;
 
-use crate::profiles::ProfileManager;
+use crate::profiles::{sanitize_profile_name, Profile, ProfileManager};
+
+use super::retry::{send_with_retry, RetryConfig};
 
 const MODRINTH_API_BASE: &str = "https://api.modrinth.com/v2";
 
@@ -90,6 +98,31 @@ pub struct ModpackInstallProgress {
     pub message: String,
 }
 
+/// How many mod files to download at once. High enough to actually saturate
+/// the connection on large packs, low enough to stay polite to Modrinth's/
+/// CurseForge's APIs instead of hammering them with hundreds of concurrent
+/// requests.
+const MODPACK_DOWNLOAD_CONCURRENCY: usize = 6;
+
+fn emit_modpack_install_progress(
+    app: &tauri::AppHandle,
+    stage: &str,
+    current: u32,
+    total: u32,
+    message: &str,
+) {
+    app.emit(
+        "modpack_install_progress",
+        ModpackInstallProgress {
+            stage: stage.to_string(),
+            current,
+            total,
+            message: message.to_string(),
+        },
+    )
+    .ok();
+}
+
 fn create_client() -> Result<reqwest::Client, String> {
     reqwest::Client::builder()
         .timeout(std::time::Duration::from_secs(120))
@@ -105,8 +138,12 @@ async fn get_modrinth_modpack_version(
 ) -> Result<(String, String), String> {
     let client = create_client()?;
 
+    // No `loaders` filter here: the loader a modpack targets isn't known
+    // until its .mrpack index is downloaded and its `dependencies` are read,
+    // and pinning this to "fabric" would return zero versions for any
+    // Forge/Quilt/NeoForge pack.
     let url = format!(
-        "{}/project/{}/version?game_versions=[\"{}\"]&loaders=[\"fabric\"]",
+        "{}/project/{}/version?game_versions=[\"{}\"]",
         MODRINTH_API_BASE, project_slug, game_version
     );
 
@@ -200,21 +237,159 @@ async fn download_mrpack(url: &str) -> Result<(MrpackIndex, Vec<u8>), String> {
     Ok((index, bytes.to_vec()))
 }
 
+/// Reject a zip entry's relative path if it tries to escape the directory
+/// we're extracting it into (a `..` component, or an absolute path), the
+/// same "zip slip" guard every archive extractor needs.
+fn is_safe_relative_path(relative_path: &str) -> bool {
+    let path = std::path::Path::new(relative_path);
+    !path.is_absolute()
+        && !path
+            .components()
+            .any(|c| matches!(c, std::path::Component::ParentDir))
+}
+
+/// Verify downloaded bytes against a .mrpack file's recorded hashes, preferring
+/// SHA-512 and falling back to SHA-1 if the SHA-512 doesn't match.
+fn verify_mrpack_hashes(bytes: &[u8], hashes: &MrpackHashes) -> Result<(), String> {
+    let mut sha512 = Sha512::new();
+    sha512.update(bytes);
+    let sha512_hash = format!("{:x}", sha512.finalize());
+    if sha512_hash.eq_ignore_ascii_case(&hashes.sha512) {
+        return Ok(());
+    }
+
+    let mut sha1 = Sha1::new();
+    sha1.update(bytes);
+    let sha1_hash = format!("{:x}", sha1.finalize());
+    if sha1_hash.eq_ignore_ascii_case(&hashes.sha1) {
+        return Ok(());
+    }
+
+    Err(format!(
+        "Hash mismatch: expected sha512={} (or sha1={}), got sha512={} sha1={}",
+        hashes.sha512, hashes.sha1, sha512_hash, sha1_hash
+    ))
+}
+
+/// Whether a file already at `dest_path` needs to be (re)downloaded: true if
+/// it's missing, unreadable, or its contents no longer match the mrpack
+/// index's recorded hash (tampered with or corrupted since the last install).
+async fn mrpack_file_needs_redownload(dest_path: &std::path::Path, hashes: &MrpackHashes) -> bool {
+    let Ok(bytes) = tokio::fs::read(dest_path).await else {
+        return true;
+    };
+    verify_mrpack_hashes(&bytes, hashes).is_err()
+}
+
+/// Read every `overrides/`/`client-overrides/` entry out of an mrpack
+/// archive and resolve each one to the directory the launcher actually
+/// reads from. `resourcepacks/` and `shaderpacks/` paths are routed through
+/// their per-version, per-profile directories (the only ones the launcher
+/// looks at when it adds `-Dfabric.*` JVM args for a profile); everything
+/// else has no per-profile equivalent in this launcher and is written to
+/// the shared game directory. `server-overrides/` entries are ignored -
+/// they're never relevant on the client.
+///
+/// Returns the `(dest_path, contents, is_client_override)` entries ready to
+/// write, plus the mrpack-relative path of each one so the caller can record
+/// them as `pack_override_files` for later cleanup.
+fn extract_mrpack_overrides(
+    mrpack_bytes: &[u8],
+    minecraft_version: &str,
+    profile_dir_name: &str,
+) -> Result<(Vec<(PathBuf, Vec<u8>, bool)>, Vec<String>), String> {
+    let cursor = std::io::Cursor::new(mrpack_bytes);
+    let mut archive =
+        ZipArchive::new(cursor).map_err(|e| format!("Failed to reopen modpack: {}", e))?;
+
+    let game_dir = dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("MiracleClient");
+    let resourcepacks_dir =
+        super::get_resourcepacks_directory(Some(minecraft_version), Some(profile_dir_name));
+    let shaderpacks_dir =
+        super::get_shaderpacks_directory(Some(minecraft_version), Some(profile_dir_name));
+
+    let mut files_to_write = Vec::new();
+    let mut override_files = Vec::new();
+
+    for i in 0..archive.len() {
+        let mut file = archive
+            .by_index(i)
+            .map_err(|e| format!("Failed to read archive entry: {}", e))?;
+
+        let name = file.name().to_string();
+
+        // client-overrides/ takes precedence over overrides/ for the same
+        // path; server-overrides/ (and anything else) isn't ours to touch.
+        let prefix = if name.starts_with("client-overrides/") {
+            "client-overrides/"
+        } else if name.starts_with("overrides/") {
+            "overrides/"
+        } else {
+            continue;
+        };
+
+        let relative_path = name.strip_prefix(prefix).unwrap_or(&name);
+        if relative_path.is_empty() || name.ends_with('/') {
+            continue;
+        }
+        if !is_safe_relative_path(relative_path) {
+            tracing::warn!("Skipping unsafe override path in modpack archive: {}", name);
+            continue;
+        }
+
+        let dest_path = if let Some(rel) = relative_path.strip_prefix("resourcepacks/") {
+            resourcepacks_dir.join(rel)
+        } else if let Some(rel) = relative_path.strip_prefix("shaderpacks/") {
+            shaderpacks_dir.join(rel)
+        } else {
+            game_dir.join(relative_path)
+        };
+
+        let mut contents = Vec::new();
+        file.read_to_end(&mut contents).ok();
+
+        if !contents.is_empty() {
+            files_to_write.push((dest_path, contents, prefix == "client-overrides/"));
+            override_files.push(relative_path.to_string());
+        }
+    }
+
+    Ok((files_to_write, override_files))
+}
+
+/// Determine the mod loader an `.mrpack` targets from its `dependencies`
+/// map (`fabric-loader`, `forge`, `quilt-loader`, or `neoforge`), defaulting
+/// to Fabric when none of those keys are present.
+fn modrinth_loader_from_dependencies(dependencies: &HashMap<String, String>) -> &'static str {
+    if dependencies.contains_key("neoforge") {
+        "neoforge"
+    } else if dependencies.contains_key("forge") {
+        "forge"
+    } else if dependencies.contains_key("quilt-loader") {
+        "quilt"
+    } else {
+        "fabric"
+    }
+}
+
 /// Install a modpack from Modrinth or CurseForge
 #[tauri::command]
 pub async fn install_modpack(
+    app: tauri::AppHandle,
     project_slug: String,
     source: String,
     game_version: String,
 ) -> Result<String, String> {
     match source.as_str() {
-        "modrinth" => install_modrinth_modpack(&project_slug, &game_version).await,
+        "modrinth" => install_modrinth_modpack(&app, &project_slug, &game_version).await,
         "curseforge" => {
             // project_slug is actually the project ID for CurseForge
             let project_id: i64 = project_slug
                 .parse()
                 .map_err(|_| "Invalid CurseForge project ID".to_string())?;
-            install_curseforge_modpack_online(project_id, &game_version).await
+            install_curseforge_modpack_online(&app, project_id, &game_version).await
         }
         _ => Err(format!("Unknown source: {}", source)),
     }
@@ -222,6 +397,7 @@ pub async fn install_modpack(
 
 /// Install a CurseForge modpack from online (by project ID)
 async fn install_curseforge_modpack_online(
+    app: &tauri::AppHandle,
     project_id: i64,
     game_version: &str,
 ) -> Result<String, String> {
@@ -233,6 +409,8 @@ async fn install_curseforge_modpack_online(
         game_version
     );
 
+    emit_modpack_install_progress(app, "fetching", 0, 1, "Looking up modpack files");
+
     // Get the modpack files
     let files = curseforge::get_mod_files(project_id as i32, game_version).await?;
 
@@ -249,15 +427,169 @@ async fn install_curseforge_modpack_online(
         .as_ref()
         .ok_or("No download URL - this modpack may require manual download")?;
 
+    emit_modpack_install_progress(app, "downloading", 0, 1, "Downloading modpack archive");
+
     // Download the modpack zip
     let zip_bytes = curseforge::download_file_bytes(download_url).await?;
 
     // Install from the zip bytes
-    install_curseforge_modpack_from_bytes(&zip_bytes).await
+    let (profile_id, _manual_download) =
+        install_curseforge_modpack_from_bytes(app, &zip_bytes).await?;
+    Ok(profile_id)
+}
+
+/// Map a `CurseForgeModLoader.id` (e.g. "forge", "fabric", "quilt",
+/// "neoforge-20.4.x") to the numeric `mod_loader` CurseForge's files API
+/// reports per file, and to the loader name this launcher's profiles track.
+/// Checked in this order since "neoforge" also contains "forge".
+fn curseforge_loader_type(loader_id: &str) -> (i32, &'static str) {
+    let id = loader_id.to_lowercase();
+    if id.contains("neoforge") {
+        (6, "neoforge")
+    } else if id.contains("quilt") {
+        (5, "quilt")
+    } else if id.contains("fabric") {
+        (4, "fabric")
+    } else if id.contains("forge") {
+        (1, "forge")
+    } else {
+        (4, "fabric")
+    }
+}
+
+/// A CurseForge mod that must be downloaded manually because its author
+/// disallows third-party distribution via the API (and the deterministic
+/// CDN URL couldn't be resolved either).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManualDownloadEntry {
+    pub project_id: i64,
+    pub file_id: i64,
+    pub file_name: String,
+    pub browser_url: String,
+}
+
+/// Outcome of resolving + downloading a single CurseForge manifest entry,
+/// reported back from the concurrent download pool.
+enum CurseForgeFileOutcome {
+    Downloaded,
+    AlreadyInstalled,
+    SkippedWrongLoader,
+    ManualDownload(ManualDownloadEntry),
+    Failed(String),
+}
+
+/// CurseForge's own CDN lays files out at a deterministic path derived from
+/// the numeric file ID, which works even when `downloadUrl` comes back null
+/// (the API omits it for some mods despite the CDN copy still existing).
+fn forgecdn_fallback_url(file_id: i64, file_name: &str) -> String {
+    format!(
+        "https://edge.forgecdn.net/files/{}/{}/{}",
+        file_id / 1000,
+        file_id % 1000,
+        urlencoding::encode(file_name)
+    )
+}
+
+fn manual_download_entry(file_ref: &CurseForgeFileRef, file_name: &str) -> ManualDownloadEntry {
+    ManualDownloadEntry {
+        project_id: file_ref.project_id,
+        file_id: file_ref.file_id,
+        file_name: file_name.to_string(),
+        browser_url: format!(
+            "https://www.curseforge.com/minecraft/mc-mods/{}/files/{}",
+            file_ref.project_id, file_ref.file_id
+        ),
+    }
+}
+
+async fn install_curseforge_file(
+    file_ref: CurseForgeFileRef,
+    mods_dir: Arc<PathBuf>,
+    loader_type: i32,
+) -> CurseForgeFileOutcome {
+    use super::curseforge;
+
+    let file_info = match curseforge::get_file_by_id(file_ref.project_id, file_ref.file_id).await {
+        Ok(info) => info,
+        Err(e) => {
+            tracing::warn!(
+                "Failed to get file info for project={} file={}: {}",
+                file_ref.project_id,
+                file_ref.file_id,
+                e
+            );
+            return CurseForgeFileOutcome::Failed(format!(
+                "project:{}/file:{}",
+                file_ref.project_id, file_ref.file_id
+            ));
+        }
+    };
+
+    // Skip files built for a different loader than the pack targets.
+    if let Some(loader) = file_info.mod_loader {
+        if loader != loader_type {
+            tracing::info!("Skipping mod for a different loader: {}", file_info.file_name);
+            return CurseForgeFileOutcome::SkippedWrongLoader;
+        }
+    }
+
+    let dest_path = mods_dir.join(&file_info.file_name);
+    if dest_path.exists() {
+        tracing::info!("Mod already exists: {}", file_info.file_name);
+        return CurseForgeFileOutcome::AlreadyInstalled;
+    }
+
+    // CurseForge returns a null downloadUrl when the mod's author has
+    // disallowed third-party distribution through the API; try the CDN's
+    // deterministic file path before giving up on it entirely.
+    let url = match &file_info.download_url {
+        Some(url) => url.clone(),
+        None => forgecdn_fallback_url(file_ref.file_id, &file_info.file_name),
+    };
+
+    match curseforge::download_file_bytes(&url).await {
+        Ok(bytes) => {
+            let actual = curseforge::murmur2_fingerprint(&bytes) as u32;
+            if actual as i64 == file_info.file_fingerprint {
+                tokio::fs::write(&dest_path, &bytes).await.ok();
+                tracing::info!("Downloaded: {}", file_info.file_name);
+                CurseForgeFileOutcome::Downloaded
+            } else {
+                tracing::warn!(
+                    "Fingerprint mismatch for {}: expected {}, got {}",
+                    file_info.file_name,
+                    file_info.file_fingerprint,
+                    actual
+                );
+                CurseForgeFileOutcome::Failed(file_info.file_name.clone())
+            }
+        }
+        Err(e) => {
+            if file_info.download_url.is_none() {
+                tracing::warn!(
+                    "{} disallows third-party downloads and the CDN fallback also failed ({}); must be installed manually",
+                    file_info.file_name,
+                    e
+                );
+                CurseForgeFileOutcome::ManualDownload(manual_download_entry(
+                    &file_ref,
+                    &file_info.file_name,
+                ))
+            } else {
+                tracing::warn!("Failed to download {}: {}", file_info.file_name, e);
+                CurseForgeFileOutcome::Failed(file_info.file_name.clone())
+            }
+        }
+    }
 }
 
-/// Install a CurseForge modpack from raw zip bytes (manifest.json format)
-async fn install_curseforge_modpack_from_bytes(zip_bytes: &[u8]) -> Result<String, String> {
+/// Install a CurseForge modpack from raw zip bytes (manifest.json format).
+/// Returns the new profile ID along with the list of mods that must be
+/// downloaded manually (see `ManualDownloadEntry`).
+async fn install_curseforge_modpack_from_bytes(
+    app: &tauri::AppHandle,
+    zip_bytes: &[u8],
+) -> Result<(String, Vec<ManualDownloadEntry>), String> {
     use super::curseforge;
 
     // Parse manifest synchronously to avoid holding ZipArchive across await
@@ -285,16 +617,16 @@ async fn install_curseforge_modpack_from_bytes(zip_bytes: &[u8]) -> Result<Strin
         manifest.version
     );
 
-    // Check if this is a Fabric modpack
-    let is_fabric = manifest
+    // Determine the pack's loader from its primary `modLoaders` entry so
+    // the right numeric filter is used when deciding which files to install.
+    let (loader_type, loader_name) = manifest
         .minecraft
         .mod_loaders
         .iter()
-        .any(|loader| loader.id.to_lowercase().contains("fabric"));
-
-    if !is_fabric {
-        tracing::warn!("This modpack uses Forge/other loader. Only Fabric mods will be installed.");
-    }
+        .find(|l| l.primary)
+        .or_else(|| manifest.minecraft.mod_loaders.first())
+        .map(|l| curseforge_loader_type(&l.id))
+        .unwrap_or((4, "fabric"));
 
     let game_version = &manifest.minecraft.version;
 
@@ -304,88 +636,61 @@ async fn install_curseforge_modpack_from_bytes(zip_bytes: &[u8]) -> Result<Strin
         profile_manager.create_modpack_profile(&manifest.name, game_version, "curseforge")?;
 
     let profile_id = profile.id.clone();
+    profile_manager.set_profile_loader(&profile_id, loader_name.to_string())?;
     let mods_dir = profile_manager.get_mods_dir(game_version, &profile_id);
 
+    if let Some(icon_bytes) = extract_zip_icon(zip_bytes) {
+        if let Err(e) = cache_profile_icon(&mut profile_manager, &profile_id, icon_bytes).await {
+            tracing::warn!("Failed to cache modpack icon: {}", e);
+        }
+    }
+
     // Create mods directory
     tokio::fs::create_dir_all(&mods_dir)
         .await
         .map_err(|e| format!("Failed to create mods directory: {}", e))?;
 
-    // Download all mods
+    // Download all mods, up to MODPACK_DOWNLOAD_CONCURRENCY at a time, emitting
+    // modpack_install_progress as each one finishes rather than blocking the
+    // whole pack behind one mod at a time.
     let total_files = manifest.files.len();
     let mut downloaded = 0;
     let mut skipped = 0;
     let mut failed: Vec<String> = Vec::new();
+    let mut manual_download: Vec<ManualDownloadEntry> = Vec::new();
+
+    let mods_dir_shared = Arc::new(mods_dir.clone());
+    let semaphore = Arc::new(Semaphore::new(MODPACK_DOWNLOAD_CONCURRENCY));
+    let mut tasks = FuturesUnordered::new();
+    for file_ref in manifest.files.clone() {
+        let semaphore = semaphore.clone();
+        let mods_dir_shared = mods_dir_shared.clone();
+        tasks.push(tokio::spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            install_curseforge_file(file_ref, mods_dir_shared, loader_type).await
+        }));
+    }
 
-    for (i, file_ref) in manifest.files.iter().enumerate() {
-        tracing::info!(
-            "Processing mod {}/{}: project={} file={}",
-            i + 1,
-            total_files,
-            file_ref.project_id,
-            file_ref.file_id
-        );
-
-        // Get file info from CurseForge API
-        match curseforge::get_file_by_id(file_ref.project_id, file_ref.file_id).await {
-            Ok(file_info) => {
-                // Check if it's a Fabric mod (mod_loader type 4)
-                if let Some(loader) = file_info.mod_loader {
-                    if loader != 4 {
-                        tracing::info!("Skipping non-Fabric mod: {}", file_info.file_name);
-                        skipped += 1;
-                        continue;
-                    }
-                }
-
-                let dest_path = mods_dir.join(&file_info.file_name);
-                if dest_path.exists() {
-                    tracing::info!("Mod already exists: {}", file_info.file_name);
-                    downloaded += 1;
-                    continue;
-                }
-
-                // Download the mod
-                if let Some(url) = &file_info.download_url {
-                    match curseforge::download_file_bytes(url).await {
-                        Ok(bytes) => {
-                            if bytes.len() > 1000 {
-                                tokio::fs::write(&dest_path, &bytes).await.ok();
-                                downloaded += 1;
-                                tracing::info!("Downloaded: {}", file_info.file_name);
-                            } else {
-                                failed.push(file_info.file_name.clone());
-                            }
-                        }
-                        Err(e) => {
-                            tracing::warn!("Failed to download {}: {}", file_info.file_name, e);
-                            failed.push(file_info.file_name.clone());
-                        }
-                    }
-                } else {
-                    tracing::warn!(
-                        "No download URL for: {} (manual download required)",
-                        file_info.file_name
-                    );
-                    failed.push(format!("{} (no API download)", file_info.file_name));
-                }
-            }
-            Err(e) => {
-                tracing::warn!(
-                    "Failed to get file info for project={} file={}: {}",
-                    file_ref.project_id,
-                    file_ref.file_id,
-                    e
-                );
-                failed.push(format!(
-                    "project:{}/file:{}",
-                    file_ref.project_id, file_ref.file_id
-                ));
+    let mut completed = 0u32;
+    while let Some(result) = tasks.next().await {
+        completed += 1;
+        match result {
+            Ok(CurseForgeFileOutcome::Downloaded) | Ok(CurseForgeFileOutcome::AlreadyInstalled) => {
+                downloaded += 1;
             }
+            Ok(CurseForgeFileOutcome::SkippedWrongLoader) => skipped += 1,
+            Ok(CurseForgeFileOutcome::ManualDownload(entry)) => manual_download.push(entry),
+            Ok(CurseForgeFileOutcome::Failed(entry)) => failed.push(entry),
+            Err(e) => failed.push(format!("task panicked: {}", e)),
         }
 
-        // Small delay to avoid rate limiting
-        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        emit_modpack_install_progress(
+            app,
+            "downloading",
+            completed,
+            total_files as u32,
+            &format!("Processed {} of {} mods", completed, total_files),
+        );
     }
 
     // Extract overrides from the zip
@@ -415,6 +720,10 @@ async fn install_curseforge_modpack_from_bytes(zip_bytes: &[u8]) -> Result<Strin
                 if relative_path.is_empty() || name.ends_with('/') {
                     continue;
                 }
+                if !is_safe_relative_path(relative_path) {
+                    tracing::warn!("Skipping unsafe override path in modpack archive: {}", name);
+                    continue;
+                }
 
                 // Put mods in the profile-specific directory
                 let dest_path = if relative_path.starts_with("mods/") {
@@ -458,11 +767,89 @@ async fn install_curseforge_modpack_from_bytes(zip_bytes: &[u8]) -> Result<Strin
         tracing::warn!("Failed mods: {:?}", failed);
     }
 
+    if !manual_download.is_empty() {
+        tracing::error!(
+            "{} mod(s) must be downloaded manually and placed in {:?}: {:#?}",
+            manual_download.len(),
+            mods_dir,
+            manual_download
+        );
+    }
+
     tracing::info!("{}", result_msg);
-    Ok(profile_id)
+    Ok((profile_id, manual_download))
+}
+
+/// Outcome of resolving + downloading a single mrpack file entry, reported
+/// back from the concurrent download pool.
+enum MrpackFileOutcome {
+    Installed(String),
+    AlreadyInstalled(String),
+    /// The entry's path doesn't fall under `mods/`, `resourcepacks/`, or
+    /// `shaderpacks/`, so there's nowhere in the profile layout for it.
+    NotApplicable,
+    Failed(String),
+}
+
+/// Resolve + download a single mrpack file entry, routing it to the
+/// mods/resourcepacks/shaderpacks dir its path prefix indicates - same
+/// routing as [`install_from_mrpack_file`], which `install_mrpack` uses.
+async fn install_mrpack_file(
+    client: reqwest::Client,
+    file: MrpackFile,
+    mods_dir: Arc<PathBuf>,
+    resourcepacks_dir: Arc<PathBuf>,
+    shaderpacks_dir: Arc<PathBuf>,
+) -> MrpackFileOutcome {
+    let (dest_dir, relative) = if let Some(rel) = file.path.strip_prefix("mods/") {
+        (mods_dir.as_ref(), rel)
+    } else if let Some(rel) = file.path.strip_prefix("resourcepacks/") {
+        (resourcepacks_dir.as_ref(), rel)
+    } else if let Some(rel) = file.path.strip_prefix("shaderpacks/") {
+        (shaderpacks_dir.as_ref(), rel)
+    } else {
+        tracing::warn!("Skipping unsupported mrpack entry: {}", file.path);
+        return MrpackFileOutcome::NotApplicable;
+    };
+
+    if let Err(e) = tokio::fs::create_dir_all(dest_dir).await {
+        tracing::warn!("Failed to create {}: {}", dest_dir.display(), e);
+        return MrpackFileOutcome::Failed(file.path);
+    }
+
+    let filename = relative.to_string();
+    let dest_path = dest_dir.join(relative);
+
+    if dest_path.exists() && !mrpack_file_needs_redownload(&dest_path, &file.hashes).await {
+        return MrpackFileOutcome::AlreadyInstalled(filename);
+    }
+
+    // Try each download URL, retrying transient failures with backoff before
+    // falling through to the next mirror, and verifying the bytes against
+    // the index's hashes.
+    for url in &file.downloads {
+        match send_with_retry(|| client.get(url), &RetryConfig::default()).await {
+            Ok(response) => {
+                if let Ok(bytes) = response.bytes().await {
+                    if let Err(e) = verify_mrpack_hashes(&bytes, &file.hashes) {
+                        tracing::warn!("Integrity check failed for {}: {}", filename, e);
+                        continue;
+                    }
+                    if tokio::fs::write(&dest_path, &bytes).await.is_ok() {
+                        return MrpackFileOutcome::Installed(filename);
+                    }
+                }
+            }
+            Err(_) => continue,
+        }
+    }
+
+    tracing::warn!("Failed to download: {}", filename);
+    MrpackFileOutcome::Failed(filename)
 }
 
 async fn install_modrinth_modpack(
+    app: &tauri::AppHandle,
     project_slug: &str,
     game_version: &str,
 ) -> Result<String, String> {
@@ -473,131 +860,212 @@ async fn install_modrinth_modpack(
     );
 
     // Get the modpack version and download URL
-    let (_version_id, download_url) =
+    let (version_id, download_url) =
         get_modrinth_modpack_version(project_slug, game_version).await?;
 
     // Download and parse the mrpack
     let (index, mrpack_bytes) = download_mrpack(&download_url).await?;
 
+    // Derive the actual profile version from the pack itself rather than the
+    // (possibly best-guess) version the caller searched with.
+    let profile_version = index
+        .dependencies
+        .get("minecraft")
+        .cloned()
+        .unwrap_or_else(|| game_version.to_string());
+
     // Create a new profile for this modpack
     let mut profile_manager = ProfileManager::new();
-    let profile = profile_manager.create_modpack_profile(&index.name, game_version, "modrinth")?;
+    let profile =
+        profile_manager.create_modpack_profile(&index.name, &profile_version, "modrinth")?;
 
     let profile_id = profile.id.clone();
-    let mods_dir = profile_manager.get_mods_dir(game_version, &profile_id);
+    let loader = modrinth_loader_from_dependencies(&index.dependencies);
+    profile_manager.set_profile_loader(&profile_id, loader.to_string())?;
+    let mods_dir = profile_manager.get_mods_dir(&profile_version, &profile_id);
+    let profile_dir_name = sanitize_profile_name(&index.name);
+    let resourcepacks_dir =
+        super::get_resourcepacks_directory(Some(&profile_version), Some(&profile_dir_name));
+    let shaderpacks_dir =
+        super::get_shaderpacks_directory(Some(&profile_version), Some(&profile_dir_name));
+
+    if let Some(icon_bytes) = extract_zip_icon(&mrpack_bytes) {
+        if let Err(e) = cache_profile_icon(&mut profile_manager, &profile_id, icon_bytes).await {
+            tracing::warn!("Failed to cache modpack icon: {}", e);
+        }
+    }
 
     // Create mods directory
     tokio::fs::create_dir_all(&mods_dir)
         .await
         .map_err(|e| format!("Failed to create mods directory: {}", e))?;
 
-    let client = create_client()?;
-
-    // Download all mod files
-    let total_files = index.files.len();
-    for (i, file) in index.files.iter().enumerate() {
-        // Check if this is a client-side file
-        if let Some(env) = &file.env {
-            if env.client == "unsupported" {
-                continue;
+    // Pre-download the Minecraft client/libraries (and Fabric loader, if
+    // that's what the pack targets) so the profile is ready to launch
+    // immediately instead of paying that cost on first launch. Best-effort:
+    // a failure here just means it'll be retried at launch time as usual.
+    let minecraft_manager = crate::minecraft::MinecraftManager::new();
+    emit_modpack_install_progress(app, "downloading", 0, 1, "Downloading Minecraft client");
+    if let Err(e) = minecraft_manager
+        .download_minecraft(&profile_version, |current, total, message| {
+            emit_modpack_install_progress(app, "downloading", current as u32, total as u32, message);
+        })
+        .await
+    {
+        tracing::warn!("Failed to pre-download Minecraft {}: {}", profile_version, e);
+    }
+    if loader == "fabric" {
+        if let Some(fabric_loader_version) = index.dependencies.get("fabric-loader") {
+            emit_modpack_install_progress(app, "downloading", 0, 1, "Downloading Fabric loader");
+            if let Err(e) = minecraft_manager
+                .download_fabric(&profile_version, fabric_loader_version, |current, total, message| {
+                    emit_modpack_install_progress(
+                        app,
+                        "downloading",
+                        current as u32,
+                        total as u32,
+                        message,
+                    );
+                })
+                .await
+            {
+                tracing::warn!(
+                    "Failed to pre-download Fabric loader {}: {}",
+                    fabric_loader_version,
+                    e
+                );
             }
         }
+    }
 
-        // Only process mods folder files for now
-        if !file.path.starts_with("mods/") {
-            continue;
-        }
-
-        let filename = file.path.strip_prefix("mods/").unwrap_or(&file.path);
-
-        let dest_path = mods_dir.join(filename);
-
-        // Skip if already exists
-        if dest_path.exists() {
-            continue;
-        }
+    let client = create_client()?;
 
-        tracing::info!("Downloading mod {}/{}: {}", i + 1, total_files, filename);
+    // Download all mod/resourcepack/shaderpack files, up to
+    // MODPACK_DOWNLOAD_CONCURRENCY at a time, emitting modpack_install_progress
+    // as each one finishes.
+    let relevant_files: Vec<MrpackFile> = index
+        .files
+        .iter()
+        .filter(|f| {
+            f.env.as_ref().map(|e| e.client.as_str()) != Some("unsupported")
+                && (f.path.starts_with("mods/")
+                    || f.path.starts_with("resourcepacks/")
+                    || f.path.starts_with("shaderpacks/"))
+        })
+        .cloned()
+        .collect();
+    let total_files = relevant_files.len();
+    let mut failed: Vec<String> = Vec::new();
+    let mut installed_files: Vec<String> = Vec::new();
+
+    let mods_dir_shared = Arc::new(mods_dir.clone());
+    let resourcepacks_dir_shared = Arc::new(resourcepacks_dir.clone());
+    let shaderpacks_dir_shared = Arc::new(shaderpacks_dir.clone());
+    let semaphore = Arc::new(Semaphore::new(MODPACK_DOWNLOAD_CONCURRENCY));
+    let mut tasks = FuturesUnordered::new();
+    for file in relevant_files {
+        let semaphore = semaphore.clone();
+        let mods_dir_shared = mods_dir_shared.clone();
+        let resourcepacks_dir_shared = resourcepacks_dir_shared.clone();
+        let shaderpacks_dir_shared = shaderpacks_dir_shared.clone();
+        let client = client.clone();
+        tasks.push(tokio::spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            install_mrpack_file(
+                client,
+                file,
+                mods_dir_shared,
+                resourcepacks_dir_shared,
+                shaderpacks_dir_shared,
+            )
+            .await
+        }));
+    }
 
-        // Try each download URL
-        let mut downloaded = false;
-        for url in &file.downloads {
-            match client.get(url).send().await {
-                Ok(response) if response.status().is_success() => {
-                    if let Ok(bytes) = response.bytes().await {
-                        if tokio::fs::write(&dest_path, &bytes).await.is_ok() {
-                            downloaded = true;
-                            break;
-                        }
-                    }
-                }
-                _ => continue,
-            }
+    let mut completed = 0u32;
+    while let Some(result) = tasks.next().await {
+        completed += 1;
+        match result {
+            Ok(MrpackFileOutcome::Installed(filename))
+            | Ok(MrpackFileOutcome::AlreadyInstalled(filename)) => installed_files.push(filename),
+            Ok(MrpackFileOutcome::NotApplicable) => {}
+            Ok(MrpackFileOutcome::Failed(filename)) => failed.push(filename),
+            Err(e) => failed.push(format!("task panicked: {}", e)),
         }
 
-        if !downloaded {
-            tracing::warn!("Failed to download: {}", filename);
-        }
+        emit_modpack_install_progress(
+            app,
+            "downloading",
+            completed,
+            total_files as u32,
+            &format!("Processed {} of {} mods", completed, total_files),
+        );
     }
 
-    // Extract overrides from the mrpack (config files, etc.)
-    // Read all override files synchronously first (ZipFile is not Send)
-    let overrides: Vec<(PathBuf, Vec<u8>)> = {
-        let cursor = std::io::Cursor::new(&mrpack_bytes);
-        let mut archive =
-            ZipArchive::new(cursor).map_err(|e| format!("Failed to reopen modpack: {}", e))?;
-
-        let game_dir = dirs::data_dir()
-            .unwrap_or_else(|| PathBuf::from("."))
-            .join("MiracleClient");
-
-        let mut files_to_write = Vec::new();
-
-        for i in 0..archive.len() {
-            let mut file = archive
-                .by_index(i)
-                .map_err(|e| format!("Failed to read archive entry: {}", e))?;
-
-            let name = file.name().to_string();
-
-            // Handle overrides folder
-            if name.starts_with("overrides/") {
-                let relative_path = name.strip_prefix("overrides/").unwrap_or(&name);
-                if relative_path.is_empty() || name.ends_with('/') {
-                    continue;
-                }
-
-                let dest_path = game_dir.join(relative_path);
-
-                let mut contents = Vec::new();
-                file.read_to_end(&mut contents).ok();
-
-                if !contents.is_empty() {
-                    files_to_write.push((dest_path, contents));
-                }
-            }
-        }
-
-        files_to_write
-    };
+    if !failed.is_empty() {
+        tracing::warn!(
+            "{} mod(s) could not be downloaded or failed integrity verification: {:?}",
+            failed.len(),
+            failed
+        );
+    }
 
-    // Now write files asynchronously
-    for (dest_path, contents) in overrides {
+    // Extract overrides/client-overrides (config files, resourcepacks, etc),
+    // routing resourcepacks/ and shaderpacks/ through the same per-version,
+    // per-profile directories the launcher actually reads (see
+    // get_resourcepacks_directory/get_shaderpacks_directory) instead of the
+    // shared game root, where the launcher never looks for them. Anything
+    // else (config/, etc.) has no per-profile equivalent in this launcher
+    // and is written to the shared game directory, same as before.
+    // server-overrides/ is ignored - it's never relevant to the client.
+    let (overrides, override_files) = extract_mrpack_overrides(
+        &mrpack_bytes,
+        &profile_version,
+        &profile_dir_name,
+    )?;
+
+    // Write plain overrides first, then client-overrides so they win on conflicts.
+    for (dest_path, contents, _) in overrides.iter().filter(|(_, _, is_client)| !is_client) {
         if let Some(parent) = dest_path.parent() {
             tokio::fs::create_dir_all(parent).await.ok();
         }
 
-        let mut dest_file = tokio::fs::File::create(&dest_path)
+        let mut dest_file = tokio::fs::File::create(dest_path)
             .await
             .map_err(|e| format!("Failed to create override file: {}", e))?;
         dest_file
-            .write_all(&contents)
+            .write_all(contents)
             .await
             .map_err(|e| format!("Failed to write override file: {}", e))?;
     }
+    for (dest_path, contents, _) in overrides.iter().filter(|(_, _, is_client)| *is_client) {
+        if let Some(parent) = dest_path.parent() {
+            tokio::fs::create_dir_all(parent).await.ok();
+        }
+
+        let mut dest_file = tokio::fs::File::create(dest_path)
+            .await
+            .map_err(|e| format!("Failed to create client override file: {}", e))?;
+        dest_file
+            .write_all(contents)
+            .await
+            .map_err(|e| format!("Failed to write client override file: {}", e))?;
+    }
+
+    // Remember where this profile's mods and overrides came from so
+    // `update_linked_profile` can check for and apply newer pack versions in
+    // place, and `remove_all_related_files` can delete the override files
+    // outside the profile's own directories when the profile is deleted.
+    profile_manager.record_pack_link(
+        &profile_id,
+        project_slug,
+        &version_id,
+        installed_files,
+        override_files,
+    )?;
 
     // Set this profile as active
-    profile_manager.set_active_profile(game_version, &profile_id)?;
+    profile_manager.set_active_profile(&profile_version, &profile_id)?;
 
     tracing::info!(
         "Modpack '{}' installed successfully with profile ID: {}",
@@ -608,18 +1076,437 @@ async fn install_modrinth_modpack(
     Ok(profile_id)
 }
 
-/// Get information about a modpack before installing
+/// Result of checking/applying an update to a linked modpack profile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModpackUpdateResult {
+    pub profile_id: String,
+    pub updated: bool,
+    pub previous_version_id: String,
+    pub new_version_id: String,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub failed: Vec<String>,
+}
+
+/// Check a linked profile for a newer version of its source modpack and, if
+/// one exists, apply it in place: download the new file set (hash-verified,
+/// same as a fresh install), remove pack-managed files no longer present in
+/// the new index, and leave anything the user added themselves untouched.
 #[tauri::command]
-pub async fn get_modpack_info(
-    project_slug: String,
-    source: String,
-    game_version: String,
-) -> Result<ModpackInfo, String> {
-    match source.as_str() {
-        "modrinth" => {
-            let client = create_client()?;
+pub async fn update_linked_profile(profile_id: String) -> Result<ModpackUpdateResult, String> {
+    let mut profile_manager = ProfileManager::new();
+    let profile = profile_manager
+        .get_profile(&profile_id)
+        .cloned()
+        .ok_or_else(|| format!("Profile {} not found", profile_id))?;
+
+    let project_id = profile
+        .linked_project_id
+        .clone()
+        .ok_or("Profile is not linked to a modpack")?;
+    let previous_version_id = profile
+        .linked_version_id
+        .clone()
+        .unwrap_or_else(|| "unknown".to_string());
 
-            // Get project info
+    let (new_version_id, download_url) =
+        get_modrinth_modpack_version(&project_id, &profile.version).await?;
+
+    if new_version_id == previous_version_id {
+        return Ok(ModpackUpdateResult {
+            profile_id,
+            updated: false,
+            previous_version_id,
+            new_version_id,
+            added: Vec::new(),
+            removed: Vec::new(),
+            failed: Vec::new(),
+        });
+    }
+
+    let (index, _mrpack_bytes) = download_mrpack(&download_url).await?;
+    let mods_dir = profile_manager.get_mods_dir(&profile.version, &profile_id);
+    tokio::fs::create_dir_all(&mods_dir)
+        .await
+        .map_err(|e| format!("Failed to create mods directory: {}", e))?;
+
+    let client = create_client()?;
+
+    let mut new_pack_files: Vec<String> = Vec::new();
+    let mut added: Vec<String> = Vec::new();
+    let mut failed: Vec<String> = Vec::new();
+
+    for file in index.files.iter().filter(|f| f.path.starts_with("mods/")) {
+        if let Some(env) = &file.env {
+            if env.client == "unsupported" {
+                continue;
+            }
+        }
+
+        let filename = file.path.strip_prefix("mods/").unwrap_or(&file.path);
+        let dest_path = mods_dir.join(filename);
+        new_pack_files.push(filename.to_string());
+
+        if dest_path.exists() && !mrpack_file_needs_redownload(&dest_path, &file.hashes).await {
+            continue;
+        }
+
+        let mut downloaded = false;
+        for url in &file.downloads {
+            match send_with_retry(|| client.get(url), &RetryConfig::default()).await {
+                Ok(response) => {
+                    if let Ok(bytes) = response.bytes().await {
+                        if verify_mrpack_hashes(&bytes, &file.hashes).is_err() {
+                            continue;
+                        }
+                        if tokio::fs::write(&dest_path, &bytes).await.is_ok() {
+                            downloaded = true;
+                            break;
+                        }
+                    }
+                }
+                _ => continue,
+            }
+        }
+
+        if downloaded {
+            added.push(filename.to_string());
+        } else {
+            failed.push(filename.to_string());
+        }
+    }
+
+    // Remove pack-managed files the new version no longer lists. Anything
+    // not recorded in the old pack_files (i.e. user-added mods) is left alone.
+    let mut removed: Vec<String> = Vec::new();
+    for old_file in &profile.pack_files {
+        if !new_pack_files.contains(old_file) {
+            let path = mods_dir.join(old_file);
+            if path.exists() && tokio::fs::remove_file(&path).await.is_ok() {
+                removed.push(old_file.clone());
+            }
+        }
+    }
+
+    // Overrides aren't re-synced on an in-place update, so carry the
+    // previously-recorded set forward rather than dropping it.
+    profile_manager.record_pack_link(
+        &profile_id,
+        &project_id,
+        &new_version_id,
+        new_pack_files,
+        profile.pack_override_files.clone(),
+    )?;
+
+    Ok(ModpackUpdateResult {
+        profile_id,
+        updated: true,
+        previous_version_id,
+        new_version_id,
+        added,
+        removed,
+        failed,
+    })
+}
+
+/// Look up a jar's Modrinth download URL by its sha1 digest, so an exported
+/// mrpack can reference the canonical source instead of bundling the jar.
+async fn resolve_modrinth_download(client: &reqwest::Client, sha1_hex: &str) -> Option<String> {
+    let url = format!(
+        "{}/version_file/{}?algorithm=sha1",
+        MODRINTH_API_BASE, sha1_hex
+    );
+
+    let response = client.get(&url).send().await.ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+
+    let version: crate::ipc::modrinth::ModrinthVersion = response.json().await.ok()?;
+    version
+        .files
+        .into_iter()
+        .find(|f| f.hashes.sha1 == sha1_hex)
+        .map(|f| f.url)
+}
+
+/// Result of exporting a profile as a standard .mrpack file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MrpackExportResult {
+    pub mods_linked: u32,
+    pub mods_bundled: u32,
+}
+
+/// Caller-chosen knobs for `export_profile_as_mrpack`. Defaults match the
+/// previous unconditional behavior: link every resolvable mod, bundle
+/// resourcepacks/shaderpacks content that isn't resolvable, and leave
+/// `config/` out (it's shared across profiles, not profile-exclusive).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MrpackExportOptions {
+    #[serde(default = "default_true")]
+    pub include_resourcepacks: bool,
+    #[serde(default = "default_true")]
+    pub include_shaderpacks: bool,
+    #[serde(default)]
+    pub include_config: bool,
+    /// Bundle every mod under `overrides/mods` instead of linking resolvable
+    /// ones, for a fully self-contained pack that doesn't depend on Modrinth
+    /// at install time.
+    #[serde(default)]
+    pub bundle_all_mods: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for MrpackExportOptions {
+    fn default() -> Self {
+        Self {
+            include_resourcepacks: true,
+            include_shaderpacks: true,
+            include_config: false,
+            bundle_all_mods: false,
+        }
+    }
+}
+
+/// Hash and classify every file in one of a profile's content directories
+/// (mods/resourcepacks/shaderpacks) for export: files matching `extension`
+/// that we can resolve back to a Modrinth download become index entries
+/// under `mrpack_prefix`, everything else is returned to be bundled under
+/// `overrides/{mrpack_prefix}`. `force_bundle` skips the resolve step
+/// entirely, so every file is bundled even if a matching download exists -
+/// for callers who want a fully self-contained pack.
+async fn collect_export_entries(
+    client: &reqwest::Client,
+    dir: &std::path::Path,
+    mrpack_prefix: &str,
+    extension: &str,
+    force_bundle: bool,
+) -> Result<(Vec<MrpackFile>, Vec<(String, Vec<u8>)>), String> {
+    let mut index_files = Vec::new();
+    let mut overrides = Vec::new();
+
+    if !dir.exists() {
+        return Ok((index_files, overrides));
+    }
+
+    let mut entries = tokio::fs::read_dir(dir)
+        .await
+        .map_err(|e| format!("Failed to read {} directory: {}", mrpack_prefix, e))?;
+
+    while let Some(entry) = entries
+        .next_entry()
+        .await
+        .map_err(|e| format!("Failed to read {} directory: {}", mrpack_prefix, e))?
+    {
+        let path = entry.path();
+        let filename = entry.file_name().to_string_lossy().to_string();
+        let bytes = tokio::fs::read(&path)
+            .await
+            .map_err(|e| format!("Failed to read {}: {}", filename, e))?;
+
+        // Anything that doesn't match the expected extension (disabled
+        // mods, per-mod config dropped next to it, etc.) can't be
+        // referenced by a download entry, so it always gets bundled
+        // alongside the files we couldn't resolve.
+        if force_bundle || path.extension().and_then(|e| e.to_str()) != Some(extension) {
+            overrides.push((filename, bytes));
+            continue;
+        }
+
+        let sha1_hex = {
+            let mut hasher = Sha1::new();
+            hasher.update(&bytes);
+            format!("{:x}", hasher.finalize())
+        };
+        let sha512_hex = {
+            let mut hasher = Sha512::new();
+            hasher.update(&bytes);
+            format!("{:x}", hasher.finalize())
+        };
+
+        match resolve_modrinth_download(client, &sha1_hex).await {
+            Some(download_url) => {
+                index_files.push(MrpackFile {
+                    path: format!("{}/{}", mrpack_prefix, filename),
+                    hashes: MrpackHashes {
+                        sha1: sha1_hex,
+                        sha512: sha512_hex,
+                    },
+                    downloads: vec![download_url],
+                    file_size: bytes.len() as i64,
+                    env: Some(MrpackEnv {
+                        client: "required".to_string(),
+                        server: "unsupported".to_string(),
+                    }),
+                });
+            }
+            None => {
+                overrides.push((filename, bytes));
+            }
+        }
+    }
+
+    Ok((index_files, overrides))
+}
+
+/// Export a profile as a spec-compliant Modrinth modpack (.mrpack), so it's
+/// shareable with any mrpack-compatible launcher, not just MiracleClient.
+/// Walks the profile's mods, resourcepacks and shaderpacks directories;
+/// files we can resolve back to a Modrinth download are referenced in
+/// `modrinth.index.json`, anything we can't identify (e.g. a CurseForge-only
+/// jar) is bundled directly under the matching `overrides/` subfolder.
+#[tauri::command]
+pub async fn export_profile_as_mrpack(
+    profile_id: String,
+    out_path: String,
+    options: Option<MrpackExportOptions>,
+) -> Result<MrpackExportResult, String> {
+    let options = options.unwrap_or_default();
+    let profile_manager = ProfileManager::new();
+    let profile = profile_manager
+        .get_profile(&profile_id)
+        .cloned()
+        .ok_or_else(|| format!("Profile {} not found", profile_id))?;
+
+    let mods_dir = profile_manager.get_mods_dir(&profile.version, &profile_id);
+    let profile_dir = sanitize_profile_name(&profile.name);
+    let resourcepacks_dir =
+        super::get_resourcepacks_directory(Some(&profile.version), Some(&profile_dir));
+    let shaderpacks_dir =
+        super::get_shaderpacks_directory(Some(&profile.version), Some(&profile_dir));
+
+    let loader_version = crate::minecraft::MinecraftManager::new()
+        .get_fabric_loader(&profile.version)
+        .await
+        .map_err(|e| format!("Failed to resolve Fabric loader version: {}", e))?;
+
+    let client = create_client()?;
+
+    let (mods_index, mods_overrides) =
+        collect_export_entries(&client, &mods_dir, "mods", "jar", options.bundle_all_mods).await?;
+
+    let (resourcepacks_index, resourcepacks_overrides) = if options.include_resourcepacks {
+        collect_export_entries(&client, &resourcepacks_dir, "resourcepacks", "zip", false).await?
+    } else {
+        (Vec::new(), Vec::new())
+    };
+
+    let (shaderpacks_index, shaderpacks_overrides) = if options.include_shaderpacks {
+        collect_export_entries(&client, &shaderpacks_dir, "shaderpacks", "zip", false).await?
+    } else {
+        (Vec::new(), Vec::new())
+    };
+
+    let mut index_files = mods_index;
+    index_files.extend(resourcepacks_index);
+    index_files.extend(shaderpacks_index);
+
+    let mut overrides_files: Vec<(String, String, Vec<u8>)> = mods_overrides
+        .into_iter()
+        .map(|(name, bytes)| ("mods".to_string(), name, bytes))
+        .chain(
+            resourcepacks_overrides
+                .into_iter()
+                .map(|(name, bytes)| ("resourcepacks".to_string(), name, bytes)),
+        )
+        .chain(
+            shaderpacks_overrides
+                .into_iter()
+                .map(|(name, bytes)| ("shaderpacks".to_string(), name, bytes)),
+        )
+        .collect();
+
+    if options.include_config {
+        let config_dir = dirs::data_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("MiracleClient")
+            .join("config");
+        let (_, config_overrides) =
+            collect_export_entries(&client, &config_dir, "config", "", true).await?;
+        overrides_files.extend(
+            config_overrides
+                .into_iter()
+                .map(|(name, bytes)| ("config".to_string(), name, bytes)),
+        );
+    }
+
+    let mut dependencies = HashMap::new();
+    dependencies.insert("minecraft".to_string(), profile.version.clone());
+    dependencies.insert("fabric-loader".to_string(), loader_version);
+
+    let mods_linked = index_files.len() as u32;
+    let mods_bundled = overrides_files.len() as u32;
+
+    let index = MrpackIndex {
+        format_version: 1,
+        game: "minecraft".to_string(),
+        version_id: format!(
+            "{}-{}",
+            sanitize_profile_name(&profile.name),
+            chrono::Utc::now().format("%Y%m%d%H%M%S")
+        ),
+        name: profile.name.clone(),
+        summary: None,
+        files: index_files,
+        dependencies,
+    };
+
+    let index_json = serde_json::to_vec_pretty(&index)
+        .map_err(|e| format!("Failed to serialize modpack index: {}", e))?;
+
+    let out = PathBuf::from(&out_path);
+    if let Some(parent) = out.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create output directory: {}", e))?;
+    }
+
+    tokio::task::spawn_blocking(move || -> Result<(), String> {
+        let file = std::fs::File::create(&out)
+            .map_err(|e| format!("Failed to create {}: {}", out.display(), e))?;
+        let mut zip = zip::ZipWriter::new(file);
+        let options = zip::write::FileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+
+        zip.start_file("modrinth.index.json", options)
+            .map_err(|e| format!("Failed to write modpack index: {}", e))?;
+        zip.write_all(&index_json)
+            .map_err(|e| format!("Failed to write modpack index: {}", e))?;
+
+        for (category, filename, bytes) in &overrides_files {
+            zip.start_file(format!("overrides/{}/{}", category, filename), options)
+                .map_err(|e| format!("Failed to write override {}: {}", filename, e))?;
+            zip.write_all(bytes)
+                .map_err(|e| format!("Failed to write override {}: {}", filename, e))?;
+        }
+
+        zip.finish()
+            .map_err(|e| format!("Failed to finalize modpack archive: {}", e))?;
+        Ok(())
+    })
+    .await
+    .map_err(|e| format!("Export task panicked: {}", e))??;
+
+    Ok(MrpackExportResult {
+        mods_linked,
+        mods_bundled,
+    })
+}
+
+/// Get information about a modpack before installing
+#[tauri::command]
+pub async fn get_modpack_info(
+    project_slug: String,
+    source: String,
+    game_version: String,
+) -> Result<ModpackInfo, String> {
+    match source.as_str() {
+        "modrinth" => {
+            let client = create_client()?;
+
+            // Get project info
             let url = format!("{}/project/{}", MODRINTH_API_BASE, project_slug);
             let response = client
                 .get(&url)
@@ -715,6 +1602,135 @@ fn parse_instance_cfg(content: &str) -> HashMap<String, String> {
     map
 }
 
+/// A mod jar identified by content hash rather than by its filename.
+struct IdentifiedJar {
+    filename: String,
+    /// The mod's real display name, resolved from the matched Modrinth
+    /// version or CurseForge file rather than guessed from `filename`.
+    name: String,
+    sha512: String,
+    modrinth_project_id: Option<String>,
+    modrinth_version_id: Option<String>,
+    /// Human-readable version string (e.g. "1.2.3"), only known for
+    /// Modrinth matches since the CurseForge fingerprint endpoint doesn't
+    /// return one.
+    modrinth_version_number: Option<String>,
+    game_versions: Vec<String>,
+}
+
+/// Bulk-resolve jars to their Modrinth project/version by SHA-512, the same
+/// lookup the official Modrinth App uses to identify jars it didn't
+/// download itself.
+async fn resolve_modrinth_versions_by_hashes(
+    client: &reqwest::Client,
+    hashes: &[String],
+) -> HashMap<String, crate::ipc::modrinth::ModrinthVersion> {
+    if hashes.is_empty() {
+        return HashMap::new();
+    }
+
+    let url = format!("{}/version_files", MODRINTH_API_BASE);
+    let body = serde_json::json!({ "hashes": hashes, "algorithm": "sha512" });
+
+    let response = match client.post(&url).json(&body).send().await {
+        Ok(r) if r.status().is_success() => r,
+        _ => return HashMap::new(),
+    };
+
+    response.json().await.unwrap_or_default()
+}
+
+/// Identify every jar in `mods_dir` by content hash: SHA-512 against
+/// Modrinth's bulk `version_files` lookup first, then CurseForge's
+/// fingerprint-match endpoint for anything Modrinth doesn't recognize.
+/// Filenames that match neither are returned separately so the caller can
+/// fall back to the old filename-regex heuristic just for those.
+async fn identify_mods_by_hash(mods_dir: &std::path::Path) -> (Vec<IdentifiedJar>, Vec<String>) {
+    use super::curseforge;
+
+    let mut jar_names: Vec<String> = Vec::new();
+    let mut sha512_by_name: HashMap<String, String> = HashMap::new();
+    let mut fingerprint_by_name: HashMap<String, i64> = HashMap::new();
+
+    if let Ok(entries) = std::fs::read_dir(mods_dir) {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if !name.to_lowercase().ends_with(".jar") {
+                continue;
+            }
+            let Ok(bytes) = std::fs::read(entry.path()) else {
+                continue;
+            };
+
+            let mut sha512 = Sha512::new();
+            sha512.update(&bytes);
+            sha512_by_name.insert(name.clone(), format!("{:x}", sha512.finalize()));
+            fingerprint_by_name.insert(name.clone(), curseforge::murmur2_fingerprint(&bytes) as u32 as i64);
+            jar_names.push(name);
+        }
+    }
+
+    let modrinth_matches = match create_client() {
+        Ok(client) => {
+            let hashes: Vec<String> = sha512_by_name.values().cloned().collect();
+            resolve_modrinth_versions_by_hashes(&client, &hashes).await
+        }
+        Err(_) => HashMap::new(),
+    };
+
+    let mut identified = Vec::new();
+    let mut unmatched_fingerprints = Vec::new();
+    let mut unmatched_names = Vec::new();
+
+    for name in &jar_names {
+        if let Some(version) = sha512_by_name.get(name).and_then(|h| modrinth_matches.get(h)) {
+            identified.push(IdentifiedJar {
+                filename: name.clone(),
+                name: version.name.clone(),
+                sha512: sha512_by_name.get(name).cloned().unwrap_or_default(),
+                modrinth_project_id: Some(version.project_id.clone()),
+                modrinth_version_id: Some(version.id.clone()),
+                modrinth_version_number: Some(version.version_number.clone()),
+                game_versions: version.game_versions.clone(),
+            });
+            continue;
+        }
+
+        if let Some(fingerprint) = fingerprint_by_name.get(name) {
+            unmatched_fingerprints.push(*fingerprint);
+        }
+        unmatched_names.push(name.clone());
+    }
+
+    if !unmatched_fingerprints.is_empty() {
+        if let Ok(cf_matches) = curseforge::get_files_by_fingerprints(&unmatched_fingerprints).await {
+            let by_fingerprint: HashMap<i64, curseforge::CurseForgeFile> = cf_matches.into_iter().map(|(_, file)| (file.file_fingerprint, file)).collect();
+
+            unmatched_names.retain(|name| {
+                let Some(file) = fingerprint_by_name.get(name).and_then(|f| by_fingerprint.get(f)) else {
+                    return true;
+                };
+                identified.push(IdentifiedJar {
+                    filename: name.clone(),
+                    name: file
+                        .file_name
+                        .strip_suffix(".jar")
+                        .unwrap_or(&file.file_name)
+                        .to_string(),
+                    sha512: sha512_by_name.get(name).cloned().unwrap_or_default(),
+                    modrinth_project_id: None,
+                    modrinth_version_id: None,
+                    modrinth_version_number: None,
+                    game_versions: file.game_versions.clone(),
+                });
+                false
+            });
+        }
+    }
+
+    (identified, unmatched_names)
+}
+
 /// Install a Modrinth App profile folder (copies mods directly)
 async fn install_modrinth_profile_folder(profile_path: &std::path::Path) -> Result<String, String> {
     tracing::info!("Importing Modrinth profile from: {:?}", profile_path);
@@ -730,19 +1746,31 @@ async fn install_modrinth_profile_folder(profile_path: &std::path::Path) -> Resu
         .map(|n| n.to_string_lossy().to_string())
         .unwrap_or_else(|| "Imported Modrinth Profile".to_string());
 
-    // Try to detect Minecraft version from mod filenames
+    // Identify jars by content hash (Modrinth hash lookup, then CurseForge
+    // fingerprint matching) so version detection isn't at the mercy of
+    // however a mod happened to name its file.
+    let (identified, unmatched_names) = identify_mods_by_hash(&mods_dir).await;
+    for name in &unmatched_names {
+        tracing::warn!("Could not identify mod by hash, unable to pin a version for it: {}", name);
+    }
+
+    // Detect Minecraft version from the identified jars' reported game
+    // versions; only fall back to the filename regex for jars neither
+    // lookup could identify.
     let mut game_version = "1.21.4".to_string(); // Default
     let mut version_counts: std::collections::HashMap<String, u32> =
         std::collections::HashMap::new();
 
-    if let Ok(entries) = std::fs::read_dir(&mods_dir) {
-        for entry in entries.flatten() {
-            let name = entry.file_name().to_string_lossy().to_string();
-            if name.ends_with(".jar") {
-                // Look for patterns like "+1.21.4", "-1.21.1", "mc1.21", "MC1.21.4", "for-MC1.21"
-                if let Some(version) = extract_mc_version_from_mod(&name) {
-                    *version_counts.entry(version).or_insert(0) += 1;
-                }
+    for jar in &identified {
+        for version in &jar.game_versions {
+            *version_counts.entry(version.clone()).or_insert(0) += 1;
+        }
+    }
+    if version_counts.is_empty() {
+        for name in &unmatched_names {
+            // Look for patterns like "+1.21.4", "-1.21.1", "mc1.21", "MC1.21.4", "for-MC1.21"
+            if let Some(version) = extract_mc_version_from_mod(name) {
+                *version_counts.entry(version).or_insert(0) += 1;
             }
         }
     }
@@ -813,27 +1841,108 @@ async fn install_modrinth_profile_folder(profile_path: &std::path::Path) -> Resu
         copied,
         skipped
     );
+
+    // Pin the mods we identified via Modrinth so the profile can be updated
+    // later without re-hashing everything: a declarative entry in
+    // miracle.toml plus its resolved version/hash in miracle.lock.toml.
+    // CurseForge-identified jars aren't recorded here since miracle.toml
+    // only tracks Modrinth slugs, but they still counted toward version
+    // detection above.
+    let mut manifest_mods = Vec::new();
+    let mut locked_mods = Vec::new();
+    for jar in identified {
+        let (Some(slug), Some(version_id)) = (jar.modrinth_project_id, jar.modrinth_version_id)
+        else {
+            continue;
+        };
+        manifest_mods.push(super::manifest::ManifestMod {
+            slug: slug.clone(),
+            version: None,
+        });
+        locked_mods.push(super::manifest::LockedMod {
+            slug,
+            version_id,
+            filename: jar.filename,
+            sha512: jar.sha512,
+        });
+    }
+
+    if !manifest_mods.is_empty() {
+        let manifest = super::manifest::ProfileManifest {
+            minecraft_version: game_version.clone(),
+            loader: "fabric".to_string(),
+            mods: manifest_mods,
+        };
+        if let Err(e) = super::manifest::save_manifest(&dest_mods_dir, &manifest) {
+            tracing::warn!("Failed to write miracle.toml for imported profile: {}", e);
+        }
+        let lockfile = super::manifest::ProfileLockfile { mods: locked_mods };
+        if let Err(e) = super::manifest::save_lockfile(&dest_mods_dir, &lockfile) {
+            tracing::warn!("Failed to write miracle.lock.toml for imported profile: {}", e);
+        }
+    }
+
     Ok(profile_id)
 }
 
 /// Install a MultiMC/Prism Launcher instance from a folder path
-async fn install_multimc_instance(instance_path: &std::path::Path) -> Result<String, String> {
+async fn install_multimc_instance(
+    instance_path: &std::path::Path,
+) -> Result<(String, Vec<String>), String> {
     tracing::info!("Importing MultiMC/Prism instance from: {:?}", instance_path);
 
-    // Read instance.cfg for the name
+    // Read instance.cfg for the name, icon, and the per-instance Java/memory
+    // overrides and managed-pack link that Prism/MultiMC store alongside it,
+    // so those settings survive the migration instead of resetting to
+    // client defaults.
     let instance_cfg_path = instance_path.join("instance.cfg");
-    let instance_name = if instance_cfg_path.exists() {
-        let content = tokio::fs::read_to_string(&instance_cfg_path)
-            .await
-            .map_err(|e| format!("Failed to read instance.cfg: {}", e))?;
-        let config = parse_instance_cfg(&content);
-        config
-            .get("name")
-            .cloned()
-            .unwrap_or_else(|| "Imported Instance".to_string())
-    } else {
-        "Imported Instance".to_string()
-    };
+    let (instance_name, icon_key, java_path, jvm_args, min_memory_mb, max_memory_mb, managed_pack) =
+        if instance_cfg_path.exists() {
+            let content = tokio::fs::read_to_string(&instance_cfg_path)
+                .await
+                .map_err(|e| format!("Failed to read instance.cfg: {}", e))?;
+            let config = parse_instance_cfg(&content);
+
+            let managed_pack = if config.get("ManagedPack").map(String::as_str) == Some("true")
+                && config.get("ManagedPackType").map(String::as_str) == Some("modrinth")
+            {
+                match (
+                    config.get("ManagedPackID").cloned(),
+                    config.get("ManagedPackVersionID").cloned(),
+                ) {
+                    (Some(project_id), Some(version_id)) => Some(ManagedPackRef {
+                        project_id,
+                        version_id,
+                    }),
+                    _ => None,
+                }
+            } else {
+                None
+            };
+
+            (
+                config
+                    .get("name")
+                    .cloned()
+                    .unwrap_or_else(|| "Imported Instance".to_string()),
+                config.get("IconKey").cloned(),
+                config.get("JavaPath").cloned(),
+                config.get("JvmArgs").cloned(),
+                config.get("MinMemAlloc").and_then(|v| v.parse().ok()),
+                config.get("MaxMemAlloc").and_then(|v| v.parse().ok()),
+                managed_pack,
+            )
+        } else {
+            (
+                "Imported Instance".to_string(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+        };
 
     // Read mmc-pack.json for version info
     let mmc_pack_path = instance_path.join("mmc-pack.json");
@@ -884,6 +1993,22 @@ async fn install_multimc_instance(instance_path: &std::path::Path) -> Result<Str
     let profile_id = profile.id.clone();
     let dest_mods_dir = profile_manager.get_mods_dir(&minecraft_version, &profile_id);
 
+    if java_path.is_some() || jvm_args.is_some() {
+        profile_manager.set_profile_java_config(&profile_id, java_path, jvm_args)?;
+    }
+
+    if min_memory_mb.is_some() || max_memory_mb.is_some() {
+        profile_manager.set_profile_memory_config(&profile_id, min_memory_mb, max_memory_mb)?;
+    }
+
+    if let Some(managed_pack) = &managed_pack {
+        profile_manager.set_profile_link(
+            &profile_id,
+            managed_pack.project_id.clone(),
+            managed_pack.version_id.clone(),
+        )?;
+    }
+
     tokio::fs::create_dir_all(&dest_mods_dir)
         .await
         .map_err(|e| format!("Failed to create mods directory: {}", e))?;
@@ -953,6 +2078,57 @@ async fn install_multimc_instance(instance_path: &std::path::Path) -> Result<Str
     // Set as active profile
     profile_manager.set_active_profile(&minecraft_version, &profile_id)?;
 
+    if let Some(icon_key) = &icon_key {
+        if let Some(icon_bytes) = find_multimc_icon(instance_path, icon_key).await {
+            if let Err(e) = cache_profile_icon(&mut profile_manager, &profile_id, icon_bytes).await
+            {
+                tracing::warn!("Failed to cache imported instance icon: {}", e);
+            }
+        }
+    }
+
+    // Scan the copied jars by content hash (Modrinth first, then CurseForge
+    // fingerprint matching) so the import isn't just opaque jars: anything
+    // resolved becomes a tracked, updatable mod via the profile's manifest.
+    let (identified, unmatched_names) = identify_mods_by_hash(&dest_mods_dir).await;
+    for name in &unmatched_names {
+        tracing::warn!("Could not identify mod by hash during MultiMC import: {}", name);
+    }
+
+    let mut manifest_mods = Vec::new();
+    let mut locked_mods = Vec::new();
+    for jar in identified {
+        let (Some(slug), Some(version_id)) = (jar.modrinth_project_id, jar.modrinth_version_id)
+        else {
+            continue;
+        };
+        manifest_mods.push(super::manifest::ManifestMod {
+            slug: slug.clone(),
+            version: None,
+        });
+        locked_mods.push(super::manifest::LockedMod {
+            slug,
+            version_id,
+            filename: jar.filename,
+            sha512: jar.sha512,
+        });
+    }
+
+    if !manifest_mods.is_empty() {
+        let manifest = super::manifest::ProfileManifest {
+            minecraft_version: minecraft_version.clone(),
+            loader: "fabric".to_string(),
+            mods: manifest_mods,
+        };
+        if let Err(e) = super::manifest::save_manifest(&dest_mods_dir, &manifest) {
+            tracing::warn!("Failed to write miracle.toml for imported profile: {}", e);
+        }
+        let lockfile = super::manifest::ProfileLockfile { mods: locked_mods };
+        if let Err(e) = super::manifest::save_lockfile(&dest_mods_dir, &lockfile) {
+            tracing::warn!("Failed to write miracle.lock.toml for imported profile: {}", e);
+        }
+    }
+
     tracing::info!(
         "MultiMC instance '{}' imported: {} mods copied, {} skipped",
         instance_name,
@@ -960,7 +2136,7 @@ async fn install_multimc_instance(instance_path: &std::path::Path) -> Result<Str
         skipped
     );
 
-    Ok(profile_id)
+    Ok((profile_id, unmatched_names))
 }
 
 /// Recursively copy a directory
@@ -983,7 +2159,668 @@ async fn copy_dir_recursive(src: &std::path::Path, dst: &std::path::Path) -> Res
         }
     }
 
-    Ok(())
+    Ok(())
+}
+
+/// Look for a pack-level icon (`icon.png`/`pack.png` at the archive root) in
+/// a `.mrpack`/CurseForge zip and return its bytes, if present.
+fn extract_zip_icon(bytes: &[u8]) -> Option<Vec<u8>> {
+    let cursor = std::io::Cursor::new(bytes);
+    let mut archive = ZipArchive::new(cursor).ok()?;
+    for candidate in ["icon.png", "pack.png"] {
+        if let Ok(mut file) = archive.by_name(candidate) {
+            let mut data = Vec::new();
+            if file.read_to_end(&mut data).is_ok() {
+                return Some(data);
+            }
+        }
+    }
+    None
+}
+
+/// Resolve a MultiMC/Prism `IconKey` to image bytes, checking the instance's
+/// own `icons/` folder first, then the shared icons folder next to the
+/// instances directory (`<InstancesDir>/icons/`, a sibling of the instance).
+async fn find_multimc_icon(instance_path: &std::path::Path, icon_key: &str) -> Option<Vec<u8>> {
+    let filename = format!("{}.png", icon_key);
+
+    let own_icon = instance_path.join("icons").join(&filename);
+    if let Ok(data) = tokio::fs::read(&own_icon).await {
+        return Some(data);
+    }
+
+    let sibling_icon = instance_path.parent()?.join("icons").join(&filename);
+    tokio::fs::read(&sibling_icon).await.ok()
+}
+
+/// Cache an imported pack's icon under the shared MiracleClient data dir
+/// (mirroring the config-copy convention in `install_multimc_instance`) and
+/// record its path on the profile so the UI can display it.
+async fn cache_profile_icon(
+    profile_manager: &mut ProfileManager,
+    profile_id: &str,
+    bytes: Vec<u8>,
+) -> Result<(), String> {
+    let icons_dir = dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("MiracleClient")
+        .join("icons");
+    tokio::fs::create_dir_all(&icons_dir)
+        .await
+        .map_err(|e| format!("Failed to create icons directory: {}", e))?;
+
+    let icon_path = icons_dir.join(format!("{}.png", profile_id));
+    tokio::fs::write(&icon_path, bytes)
+        .await
+        .map_err(|e| format!("Failed to write profile icon: {}", e))?;
+
+    profile_manager.set_profile_icon(profile_id, Some(icon_path.to_string_lossy().to_string()))
+}
+
+// ============================================================================
+// Live Instance Import (MultiMC/Prism, ATLauncher, GDLauncher, CurseForge)
+// ============================================================================
+
+/// Common shape every launcher-specific parser below reduces its on-disk
+/// format to, so `import_profile` only has to know how to install one shape
+/// instead of one per launcher.
+struct ImportedInstance {
+    name: String,
+    mc_version: String,
+    #[allow(dead_code)]
+    loader: Option<String>,
+    #[allow(dead_code)]
+    loader_version: Option<String>,
+    /// Custom Java executable path, if the source launcher recorded a
+    /// per-instance override (currently only MultiMC/Prism's `JavaPath`).
+    java_path: Option<String>,
+    /// Extra JVM arguments, if the source launcher recorded a per-instance
+    /// override (currently only MultiMC/Prism's `JvmArgs`).
+    jvm_args: Option<String>,
+    /// Minimum JVM heap size in MB (currently only MultiMC/Prism's
+    /// `MinMemAlloc`).
+    min_memory_mb: Option<u32>,
+    /// Maximum JVM heap size in MB (currently only MultiMC/Prism's
+    /// `MaxMemAlloc`).
+    max_memory_mb: Option<u32>,
+    /// The upstream pack this instance tracks updates against, if the source
+    /// launcher recorded one (currently only MultiMC/Prism's `ManagedPack*`
+    /// keys, and only when the pack provider is Modrinth - `update_linked_profile`
+    /// only knows how to check Modrinth for new versions).
+    managed_pack: Option<ManagedPackRef>,
+    /// Icon identifier for this instance, if the source launcher recorded
+    /// one (currently only MultiMC/Prism's `IconKey`), resolved to actual
+    /// image bytes separately since that requires the instance's path.
+    icon_key: Option<String>,
+    mods: Vec<ImportedMod>,
+}
+
+/// The upstream pack/version a launcher-managed instance is tracking, as
+/// recorded in e.g. MultiMC/Prism's `instance.cfg` (`ManagedPackID`/
+/// `ManagedPackVersionID`).
+struct ManagedPackRef {
+    project_id: String,
+    version_id: String,
+}
+
+/// A single mod from an imported instance. Either a jar already sitting on
+/// disk (`path`), or a CurseForge project/file ID pair to re-resolve when
+/// the launcher only recorded the IDs (e.g. a mod the user never actually
+/// downloaded through the CurseForge app).
+struct ImportedMod {
+    source: String,
+    project_id: Option<String>,
+    file_id: Option<String>,
+    path: Option<PathBuf>,
+}
+
+/// Resolve an instance root to the directory that actually holds `mods`/
+/// `config` - some launchers nest a `.minecraft` (or `minecraft`) folder,
+/// others use the instance root directly.
+fn find_minecraft_dir(instance_path: &std::path::Path) -> PathBuf {
+    if instance_path.join(".minecraft").exists() {
+        instance_path.join(".minecraft")
+    } else if instance_path.join("minecraft").exists() {
+        instance_path.join("minecraft")
+    } else {
+        instance_path.to_path_buf()
+    }
+}
+
+/// Scan a mods folder for `.jar`/`.jar.disabled` files and record them as
+/// already-on-disk `ImportedMod`s.
+async fn collect_local_jar_mods(mods_dir: &std::path::Path) -> Result<Vec<ImportedMod>, String> {
+    if !mods_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut mods = Vec::new();
+    let mut entries = tokio::fs::read_dir(mods_dir)
+        .await
+        .map_err(|e| format!("Failed to read mods directory: {}", e))?;
+
+    while let Some(entry) = entries.next_entry().await.map_err(|e| e.to_string())? {
+        let path = entry.path();
+        let filename = path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_lowercase();
+
+        if filename.ends_with(".jar") || filename.ends_with(".jar.disabled") {
+            mods.push(ImportedMod {
+                source: "file".to_string(),
+                project_id: None,
+                file_id: None,
+                path: Some(path),
+            });
+        }
+    }
+
+    Ok(mods)
+}
+
+/// Parse a MultiMC/Prism instance (`instance.cfg` + `mmc-pack.json`).
+async fn parse_multimc_instance(path: &std::path::Path) -> Result<ImportedInstance, String> {
+    let instance_cfg_path = path.join("instance.cfg");
+    let (name, java_path, jvm_args, min_memory_mb, max_memory_mb, managed_pack, icon_key) =
+        if instance_cfg_path.exists() {
+            let content = tokio::fs::read_to_string(&instance_cfg_path)
+                .await
+                .map_err(|e| format!("Failed to read instance.cfg: {}", e))?;
+            let config = parse_instance_cfg(&content);
+            let name = config
+                .get("name")
+                .cloned()
+                .unwrap_or_else(|| "Imported Instance".to_string());
+            // Only `OverrideJavaLocation`/`OverrideJavaArgs`/`OverrideMemory`
+            // actually apply the recorded JavaPath/JvmArgs/MinMemAlloc/
+            // MaxMemAlloc on the MultiMC/Prism side, but we still recover the
+            // values themselves here since a user enabling them in Miracle
+            // later is harmless either way.
+            let java_path = config.get("JavaPath").cloned();
+            let jvm_args = config.get("JvmArgs").cloned();
+            let min_memory_mb = config.get("MinMemAlloc").and_then(|v| v.parse().ok());
+            let max_memory_mb = config.get("MaxMemAlloc").and_then(|v| v.parse().ok());
+
+            // `ManagedPack` instances track an upstream pack version; only
+            // Modrinth-provided packs are wired up here since
+            // `update_linked_profile` only knows how to check Modrinth for
+            // updates (CurseForge/ATLauncher/Flame packs still import fine,
+            // they just won't offer in-place updates).
+            let managed_pack = if config.get("ManagedPack").map(String::as_str) == Some("true")
+                && config.get("ManagedPackType").map(String::as_str) == Some("modrinth")
+            {
+                match (
+                    config.get("ManagedPackID").cloned(),
+                    config.get("ManagedPackVersionID").cloned(),
+                ) {
+                    (Some(project_id), Some(version_id)) => {
+                        Some(ManagedPackRef { project_id, version_id })
+                    }
+                    _ => None,
+                }
+            } else {
+                None
+            };
+
+            let icon_key = config.get("IconKey").cloned();
+
+            (
+                name,
+                java_path,
+                jvm_args,
+                min_memory_mb,
+                max_memory_mb,
+                managed_pack,
+                icon_key,
+            )
+        } else {
+            (
+                "Imported Instance".to_string(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+        };
+
+    let mmc_pack_path = path.join("mmc-pack.json");
+    let content = tokio::fs::read_to_string(&mmc_pack_path)
+        .await
+        .map_err(|_| "Not a valid MultiMC/Prism instance (missing mmc-pack.json)".to_string())?;
+    let pack: MmcPack = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse mmc-pack.json: {}", e))?;
+
+    let mc_version = pack
+        .components
+        .iter()
+        .find(|c| c.uid == "net.minecraft")
+        .map(|c| c.version.clone())
+        .unwrap_or_else(|| "1.21.4".to_string());
+
+    let loader_version = pack
+        .components
+        .iter()
+        .find(|c| c.uid == "net.fabricmc.fabric-loader")
+        .map(|c| c.version.clone());
+    let loader = loader_version.as_ref().map(|_| "fabric".to_string());
+
+    let mods_dir = find_minecraft_dir(path).join("mods");
+    let mods = collect_local_jar_mods(&mods_dir).await?;
+
+    Ok(ImportedInstance {
+        name,
+        mc_version,
+        loader,
+        loader_version,
+        java_path,
+        jvm_args,
+        min_memory_mb,
+        max_memory_mb,
+        managed_pack,
+        icon_key,
+        mods,
+    })
+}
+
+/// Parse an ATLauncher instance (`instance.json`).
+async fn parse_atlauncher_instance(path: &std::path::Path) -> Result<ImportedInstance, String> {
+    #[derive(Deserialize)]
+    struct AtlInstanceFile {
+        launcher: AtlLauncherInfo,
+    }
+    #[derive(Deserialize)]
+    struct AtlLauncherInfo {
+        name: Option<String>,
+        #[serde(rename = "minecraftVersion")]
+        minecraft_version: Option<String>,
+        #[serde(rename = "loaderVersion")]
+        loader_version: Option<AtlLoaderInfo>,
+    }
+    #[derive(Deserialize)]
+    struct AtlLoaderInfo {
+        #[serde(rename = "type")]
+        loader_type: Option<String>,
+        version: Option<String>,
+    }
+
+    let content = tokio::fs::read_to_string(path.join("instance.json"))
+        .await
+        .map_err(|_| "Not a valid ATLauncher instance (missing instance.json)".to_string())?;
+    let instance: AtlInstanceFile = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse instance.json: {}", e))?;
+
+    let name = instance
+        .launcher
+        .name
+        .unwrap_or_else(|| "Imported Instance".to_string());
+    let mc_version = instance
+        .launcher
+        .minecraft_version
+        .unwrap_or_else(|| "1.21.4".to_string());
+    let loader = instance
+        .launcher
+        .loader_version
+        .as_ref()
+        .and_then(|l| l.loader_type.clone());
+    let loader_version = instance.launcher.loader_version.and_then(|l| l.version);
+
+    let mods = collect_local_jar_mods(&path.join("mods")).await?;
+
+    Ok(ImportedInstance {
+        name,
+        mc_version,
+        loader,
+        loader_version,
+        java_path: None,
+        jvm_args: None,
+        min_memory_mb: None,
+        max_memory_mb: None,
+        managed_pack: None,
+        icon_key: None,
+        mods,
+    })
+}
+
+/// Parse a GDLauncher instance (`config.json`).
+async fn parse_gdlauncher_instance(path: &std::path::Path) -> Result<ImportedInstance, String> {
+    #[derive(Deserialize)]
+    struct GdlConfig {
+        name: Option<String>,
+        loader: Option<GdlLoader>,
+    }
+    #[derive(Deserialize)]
+    struct GdlLoader {
+        #[serde(rename = "mcVersion")]
+        mc_version: Option<String>,
+        #[serde(rename = "loaderType")]
+        loader_type: Option<String>,
+        #[serde(rename = "loaderVersion")]
+        loader_version: Option<String>,
+    }
+
+    let content = tokio::fs::read_to_string(path.join("config.json"))
+        .await
+        .map_err(|_| "Not a valid GDLauncher instance (missing config.json)".to_string())?;
+    let config: GdlConfig = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse config.json: {}", e))?;
+
+    let name = config.name.unwrap_or_else(|| {
+        path.file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "Imported Instance".to_string())
+    });
+
+    let (mc_version, loader, loader_version) = match config.loader {
+        Some(l) => (
+            l.mc_version.unwrap_or_else(|| "1.21.4".to_string()),
+            l.loader_type,
+            l.loader_version,
+        ),
+        None => ("1.21.4".to_string(), None, None),
+    };
+
+    let mods = collect_local_jar_mods(&path.join("mods")).await?;
+
+    Ok(ImportedInstance {
+        name,
+        mc_version,
+        loader,
+        loader_version,
+        java_path: None,
+        jvm_args: None,
+        min_memory_mb: None,
+        max_memory_mb: None,
+        managed_pack: None,
+        icon_key: None,
+        mods,
+    })
+}
+
+/// Parse a CurseForge App instance (`minecraftinstance.json`). Addons whose
+/// jar isn't actually on disk (`installedFile` with no matching file) are
+/// recorded by project/file ID instead, to be re-resolved through the
+/// `curseforge` module at install time.
+async fn parse_curseforge_instance(path: &std::path::Path) -> Result<ImportedInstance, String> {
+    #[derive(Deserialize)]
+    struct CfInstanceFile {
+        name: String,
+        #[serde(rename = "gameVersion")]
+        game_version: Option<String>,
+        #[serde(rename = "baseModLoader")]
+        base_mod_loader: Option<CfLoaderInfo>,
+        #[serde(rename = "installedAddons", default)]
+        installed_addons: Vec<CfInstalledAddon>,
+    }
+    #[derive(Deserialize)]
+    struct CfLoaderInfo {
+        name: Option<String>,
+    }
+    #[derive(Deserialize)]
+    struct CfInstalledAddon {
+        #[serde(rename = "addonID")]
+        addon_id: i64,
+        #[serde(rename = "installedFile")]
+        installed_file: Option<CfInstalledFile>,
+    }
+    #[derive(Deserialize)]
+    struct CfInstalledFile {
+        id: i64,
+        #[serde(rename = "FileNameOnDisk", alias = "fileName")]
+        file_name: Option<String>,
+    }
+
+    let content = tokio::fs::read_to_string(path.join("minecraftinstance.json"))
+        .await
+        .map_err(|_| {
+            "Not a valid CurseForge instance (missing minecraftinstance.json)".to_string()
+        })?;
+    let instance: CfInstanceFile = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse minecraftinstance.json: {}", e))?;
+
+    let mc_version = instance.game_version.unwrap_or_else(|| "1.21.4".to_string());
+    let loader = instance.base_mod_loader.and_then(|l| l.name).map(|n| {
+        if n.to_lowercase().contains("fabric") {
+            "fabric".to_string()
+        } else {
+            n
+        }
+    });
+
+    let mods_dir = path.join("mods");
+    let mut mods = Vec::new();
+    for addon in instance.installed_addons {
+        let local_path = addon
+            .installed_file
+            .as_ref()
+            .and_then(|f| f.file_name.clone())
+            .map(|name| mods_dir.join(name))
+            .filter(|p| p.exists());
+
+        if let Some(local_path) = local_path {
+            mods.push(ImportedMod {
+                source: "file".to_string(),
+                project_id: None,
+                file_id: None,
+                path: Some(local_path),
+            });
+        } else if let Some(file) = addon.installed_file {
+            mods.push(ImportedMod {
+                source: "curseforge".to_string(),
+                project_id: Some(addon.addon_id.to_string()),
+                file_id: Some(file.id.to_string()),
+                path: None,
+            });
+        }
+    }
+
+    Ok(ImportedInstance {
+        name: instance.name,
+        mc_version,
+        loader,
+        loader_version: None,
+        java_path: None,
+        jvm_args: None,
+        min_memory_mb: None,
+        max_memory_mb: None,
+        managed_pack: None,
+        icon_key: None,
+        mods,
+    })
+}
+
+/// Progress reported by `import_profile` via the `instance_import_progress`
+/// event, so the UI can show a loading bar across the copy/resolve stages
+/// instead of a single opaque spinner.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct InstanceImportProgress {
+    stage: String,
+    current: u32,
+    total: u32,
+}
+
+fn emit_instance_import_progress(app: &tauri::AppHandle, stage: &str, current: u32, total: u32) {
+    app.emit(
+        "instance_import_progress",
+        InstanceImportProgress {
+            stage: stage.to_string(),
+            current,
+            total,
+        },
+    )
+    .ok();
+}
+
+/// Import an existing instance from another launcher (as surfaced by
+/// `detect_installed_instances`) into a new profile: copy mods, `config/`
+/// and `resourcepacks/` already on disk, re-resolve any CurseForge addon
+/// that's only recorded as a project/file ID pair, then run the same
+/// dependency resolver used elsewhere to pull in missing Fabric
+/// dependencies. Reports each stage through the `instance_import_progress`
+/// event so the UI can drive a loading bar instead of a single spinner.
+#[tauri::command]
+pub async fn import_profile(
+    app: tauri::AppHandle,
+    instance_path: String,
+    source: String,
+) -> Result<Profile, String> {
+    let path = PathBuf::from(&instance_path);
+
+    emit_instance_import_progress(&app, "parsing", 0, 1);
+    let imported = match source.as_str() {
+        "multimc" | "prism" => parse_multimc_instance(&path).await?,
+        "atlauncher" => parse_atlauncher_instance(&path).await?,
+        "gdlauncher" => parse_gdlauncher_instance(&path).await?,
+        "curseforge" => parse_curseforge_instance(&path).await?,
+        other => return Err(format!("Unsupported launcher source for import: {}", other)),
+    };
+
+    let mut profile_manager = ProfileManager::new();
+    let profile =
+        profile_manager.create_modpack_profile(&imported.name, &imported.mc_version, &source)?;
+    let dest_mods_dir = profile_manager.get_mods_dir(&imported.mc_version, &profile.id);
+
+    if imported.java_path.is_some() || imported.jvm_args.is_some() {
+        profile_manager.set_profile_java_config(
+            &profile.id,
+            imported.java_path.clone(),
+            imported.jvm_args.clone(),
+        )?;
+    }
+
+    if imported.min_memory_mb.is_some() || imported.max_memory_mb.is_some() {
+        profile_manager.set_profile_memory_config(
+            &profile.id,
+            imported.min_memory_mb,
+            imported.max_memory_mb,
+        )?;
+    }
+
+    if let Some(managed_pack) = &imported.managed_pack {
+        profile_manager.set_profile_link(
+            &profile.id,
+            managed_pack.project_id.clone(),
+            managed_pack.version_id.clone(),
+        )?;
+    }
+
+    if let Some(icon_key) = &imported.icon_key {
+        if let Some(icon_bytes) = find_multimc_icon(&path, icon_key).await {
+            if let Err(e) = cache_profile_icon(&mut profile_manager, &profile.id, icon_bytes).await
+            {
+                tracing::warn!("Failed to cache imported instance icon: {}", e);
+            }
+        }
+    }
+
+    tokio::fs::create_dir_all(&dest_mods_dir)
+        .await
+        .map_err(|e| format!("Failed to create mods directory: {}", e))?;
+
+    let mut copied = 0;
+    let mut resolved = 0;
+    let total_mods = imported.mods.len() as u32;
+
+    for (index, imported_mod) in imported.mods.iter().enumerate() {
+        emit_instance_import_progress(&app, "copying_mods", index as u32, total_mods);
+        if let Some(local_path) = &imported_mod.path {
+            let dest_filename = local_path
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .replace(".disabled", "");
+
+            match tokio::fs::copy(local_path, dest_mods_dir.join(&dest_filename)).await {
+                Ok(_) => copied += 1,
+                Err(e) => tracing::warn!("Failed to copy {}: {}", dest_filename, e),
+            }
+            continue;
+        }
+
+        if imported_mod.source != "curseforge" {
+            continue;
+        }
+        let (Some(project_id), Some(file_id)) = (&imported_mod.project_id, &imported_mod.file_id)
+        else {
+            continue;
+        };
+        let (Ok(project_id), Ok(file_id)) = (project_id.parse::<i64>(), file_id.parse::<i64>())
+        else {
+            tracing::warn!("Invalid CurseForge project/file ID pair, skipping");
+            continue;
+        };
+
+        match super::curseforge::get_file_by_id(project_id, file_id).await {
+            Ok(file) => match &file.download_url {
+                Some(url) => match super::curseforge::download_file_bytes(url).await {
+                    Ok(bytes) => {
+                        match tokio::fs::write(dest_mods_dir.join(&file.file_name), bytes).await {
+                            Ok(_) => resolved += 1,
+                            Err(e) => tracing::warn!("Failed to write {}: {}", file.file_name, e),
+                        }
+                    }
+                    Err(e) => tracing::warn!("Failed to download CurseForge file {}: {}", file_id, e),
+                },
+                None => tracing::warn!(
+                    "CurseForge file {} has no direct download URL, skipping",
+                    file_id
+                ),
+            },
+            Err(e) => tracing::warn!("Failed to resolve CurseForge mod {}: {}", project_id, e),
+        }
+    }
+
+    emit_instance_import_progress(&app, "copying_mods", total_mods, total_mods);
+    tracing::info!(
+        "Imported '{}' from {}: {} mods copied, {} resolved via CurseForge",
+        imported.name,
+        source,
+        copied,
+        resolved
+    );
+
+    emit_instance_import_progress(&app, "resolving_dependencies", 0, 1);
+    if let Err(e) = super::dependency_resolver::resolve_and_install_dependencies(
+        &dest_mods_dir,
+        &imported.mc_version,
+        imported.loader.as_deref().unwrap_or("fabric"),
+        Some(&app),
+    )
+    .await
+    {
+        tracing::warn!("Dependency resolution failed for imported profile: {}", e);
+    }
+    emit_instance_import_progress(&app, "resolving_dependencies", 1, 1);
+
+    emit_instance_import_progress(&app, "copying_config", 0, 1);
+    let source_minecraft_dir = find_minecraft_dir(&path);
+    let game_dir = dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("MiracleClient");
+
+    let source_config = source_minecraft_dir.join("config");
+    if source_config.exists() {
+        copy_dir_recursive(&source_config, &game_dir.join("config")).await.ok();
+    }
+    emit_instance_import_progress(&app, "copying_config", 1, 1);
+
+    emit_instance_import_progress(&app, "copying_resourcepacks", 0, 1);
+    let source_resourcepacks = source_minecraft_dir.join("resourcepacks");
+    if source_resourcepacks.exists() {
+        let profile_dir = sanitize_profile_name(&profile.name);
+        let dest_resourcepacks =
+            super::get_resourcepacks_directory(Some(&imported.mc_version), Some(&profile_dir));
+        copy_dir_recursive(&source_resourcepacks, &dest_resourcepacks).await.ok();
+    }
+    emit_instance_import_progress(&app, "copying_resourcepacks", 1, 1);
+
+    profile_manager.set_active_profile(&imported.mc_version, &profile.id)?;
+    emit_instance_import_progress(&app, "done", 1, 1);
+
+    Ok(profile)
 }
 
 // ============================================================================
@@ -1009,6 +2846,265 @@ pub struct ModpackImportResult {
     pub mods_installed: u32,
     pub mods_failed: u32,
     pub warnings: Vec<String>,
+    /// CurseForge mods that couldn't be fetched via the API or its CDN, so
+    /// the UI can prompt the user to download them manually instead of this
+    /// only showing up in a log line.
+    #[serde(default)]
+    pub manual_downloads: Vec<ManualDownloadEntry>,
+    /// The Minecraft version declared by a `.mrpack`'s
+    /// `dependencies.minecraft`; `None` for the other import formats.
+    #[serde(default)]
+    pub minecraft_version: Option<String>,
+    /// The mod loader a `.mrpack`'s `dependencies` declare it targets (e.g.
+    /// "fabric", "quilt", "forge", "neoforge"); `None` for the other import
+    /// formats.
+    #[serde(default)]
+    pub loader: Option<String>,
+}
+
+/// Install a `.mrpack` file's content into an existing profile, unlike
+/// `import_modpack_file` below which always creates a new one. Every entry
+/// in the index is routed to its matching per-version/per-profile directory
+/// by its `path` prefix (`mods/`, `resourcepacks/`, `shaderpacks/`), verified
+/// against the manifest's SHA-512 (or its SHA-1), and a mismatch only fails that one file
+/// so the rest of the pack still installs. `path_or_url` may be a local file
+/// path or an `http(s)://` URL to download the `.mrpack` from first.
+#[tauri::command]
+pub async fn install_mrpack(
+    app: tauri::AppHandle,
+    path_or_url: String,
+    profile_id: String,
+) -> Result<ModpackImportResult, String> {
+    let profile_manager = ProfileManager::new();
+    let profile = profile_manager
+        .get_profile(&profile_id)
+        .cloned()
+        .ok_or_else(|| format!("Profile {} not found", profile_id))?;
+
+    emit_instance_import_progress(&app, "downloading", 0, 1);
+    let bytes = if path_or_url.starts_with("http://") || path_or_url.starts_with("https://") {
+        let client = create_client()?;
+        let response = send_with_retry(|| client.get(&path_or_url), &RetryConfig::default())
+            .await
+            .map_err(|e| format!("Failed to download {}: {}", path_or_url, e))?;
+        response
+            .bytes()
+            .await
+            .map_err(|e| format!("Failed to read {}: {}", path_or_url, e))?
+            .to_vec()
+    } else {
+        tokio::fs::read(&path_or_url)
+            .await
+            .map_err(|e| format!("Failed to read {}: {}", path_or_url, e))?
+    };
+    emit_instance_import_progress(&app, "downloading", 1, 1);
+
+    let index: MrpackIndex = {
+        let cursor = std::io::Cursor::new(&bytes);
+        let mut archive = ZipArchive::new(cursor)
+            .map_err(|e| format!("Failed to open modpack archive: {}", e))?;
+        let mut index_file = archive
+            .by_name("modrinth.index.json")
+            .map_err(|e| format!("Failed to find modrinth.index.json: {}", e))?;
+        let mut json = String::new();
+        index_file
+            .read_to_string(&mut json)
+            .map_err(|e| format!("Failed to read index: {}", e))?;
+        serde_json::from_str(&json).map_err(|e| format!("Failed to parse index: {}", e))?
+    };
+
+    if index.format_version != 1 {
+        return Err(format!(
+            "Unsupported mrpack formatVersion: {}",
+            index.format_version
+        ));
+    }
+
+    if let Some(expected_mc) = index.dependencies.get("minecraft") {
+        if expected_mc != &profile.version {
+            tracing::warn!(
+                "mrpack targets Minecraft {} but profile {} is {}",
+                expected_mc,
+                profile_id,
+                profile.version
+            );
+        }
+    }
+
+    let profile_dir = sanitize_profile_name(&profile.name);
+    let mods_dir = profile_manager.get_mods_dir(&profile.version, &profile_id);
+    let resourcepacks_dir =
+        super::get_resourcepacks_directory(Some(&profile.version), Some(&profile_dir));
+    let shaderpacks_dir =
+        super::get_shaderpacks_directory(Some(&profile.version), Some(&profile_dir));
+
+    let client = create_client()?;
+
+    // Download up to MODPACK_DOWNLOAD_CONCURRENCY files at a time, same
+    // bounded-concurrency pool as install_mrpack_from_bytes, so a 200-mod
+    // pack doesn't serialize hundreds of round trips.
+    let mods_dir_shared = Arc::new(mods_dir.clone());
+    let resourcepacks_dir_shared = Arc::new(resourcepacks_dir.clone());
+    let shaderpacks_dir_shared = Arc::new(shaderpacks_dir.clone());
+    let semaphore = Arc::new(Semaphore::new(MODPACK_DOWNLOAD_CONCURRENCY));
+    let mut tasks = FuturesUnordered::new();
+    for file in index.files.clone() {
+        let semaphore = semaphore.clone();
+        let mods_dir_shared = mods_dir_shared.clone();
+        let resourcepacks_dir_shared = resourcepacks_dir_shared.clone();
+        let shaderpacks_dir_shared = shaderpacks_dir_shared.clone();
+        let client = client.clone();
+        tasks.push(tokio::spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            install_from_mrpack_file(
+                file,
+                mods_dir_shared,
+                resourcepacks_dir_shared,
+                shaderpacks_dir_shared,
+                client,
+            )
+            .await
+        }));
+    }
+
+    let mut installed = 0u32;
+    let mut failed_files: Vec<String> = Vec::new();
+    while let Some(result) = tasks.next().await {
+        match result {
+            Ok(MrpackIndexFileOutcome::Installed) => installed += 1,
+            Ok(MrpackIndexFileOutcome::NotApplicable) => {}
+            Ok(MrpackIndexFileOutcome::SkippedServerOnly(_)) => {}
+            Ok(MrpackIndexFileOutcome::Failed(path)) => {
+                tracing::warn!("Failed to install {} into profile {}", path, profile_id);
+                failed_files.push(path);
+            }
+            Err(e) => {
+                tracing::warn!("mrpack download task panicked: {}", e);
+            }
+        }
+    }
+
+    // Extract overrides/client-overrides into the profile's shared game
+    // directory (or its per-profile resourcepacks/shaderpacks dir), same
+    // precedence and routing as the fresh-install path above.
+    let (overrides, _override_files) =
+        extract_mrpack_overrides(&bytes, &profile.version, &profile_dir)?;
+
+    for (dest_path, contents, _) in overrides.iter().filter(|(_, _, is_client)| !is_client) {
+        if let Some(parent) = dest_path.parent() {
+            tokio::fs::create_dir_all(parent).await.ok();
+        }
+        tokio::fs::write(dest_path, contents).await.ok();
+    }
+    for (dest_path, contents, _) in overrides.iter().filter(|(_, _, is_client)| *is_client) {
+        if let Some(parent) = dest_path.parent() {
+            tokio::fs::create_dir_all(parent).await.ok();
+        }
+        tokio::fs::write(dest_path, contents).await.ok();
+    }
+
+    tracing::info!(
+        "install_from_mrpack: {} file(s) installed into profile {}, {} failed",
+        installed,
+        profile_id,
+        failed_files.len()
+    );
+
+    // Pin whatever we can identify by hash so the profile can be checked for
+    // updates later, same manifest/lockfile pair `install_multimc_instance` writes.
+    let (identified, unmatched_names) = identify_mods_by_hash(&mods_dir).await;
+    for name in &unmatched_names {
+        tracing::warn!(
+            "install_from_mrpack: could not identify mod by hash, unable to pin a version for it: {}",
+            name
+        );
+    }
+
+    let loader = modrinth_loader_from_dependencies(&index.dependencies).to_string();
+    let minecraft_version = index.dependencies.get("minecraft").cloned();
+
+    let mut manifest_mods = Vec::new();
+    let mut locked_mods = Vec::new();
+    for jar in identified {
+        let (Some(slug), Some(version_id)) = (
+            jar.modrinth_project_id.clone(),
+            jar.modrinth_version_id.clone(),
+        ) else {
+            continue;
+        };
+
+        // Also pin it in the ModMetadataIndex the content-browser update
+        // checker reads, so an mrpack-imported mod is checked for updates
+        // the same as one installed through the in-app browser.
+        let metadata = super::mod_updates::ModMetadata {
+            source: "modrinth".to_string(),
+            project_slug: slug.clone(),
+            project_id: slug.clone(),
+            installed_version: jar.modrinth_version_number.clone().unwrap_or_default(),
+            version_id: version_id.clone(),
+            installed_at: chrono::Utc::now().to_rfc3339(),
+            repo_base: None,
+            asset_pattern: None,
+            loader_fallback: None,
+            loader: loader.clone(),
+        };
+        if let Err(e) = super::mod_updates::update_mod_metadata(
+            &profile.version,
+            &profile_id,
+            &jar.filename,
+            metadata,
+        ) {
+            tracing::warn!(
+                "Failed to save mod metadata for {} in profile {}: {}",
+                jar.filename,
+                profile_id,
+                e
+            );
+        }
+
+        manifest_mods.push(super::manifest::ManifestMod {
+            slug: slug.clone(),
+            version: None,
+        });
+        locked_mods.push(super::manifest::LockedMod {
+            slug,
+            version_id,
+            filename: jar.filename,
+            sha512: jar.sha512,
+        });
+    }
+
+    if !manifest_mods.is_empty() {
+        let manifest = super::manifest::ProfileManifest {
+            minecraft_version: profile.version.clone(),
+            loader: loader.clone(),
+            mods: manifest_mods,
+        };
+        if let Err(e) = super::manifest::save_manifest(&mods_dir, &manifest) {
+            tracing::warn!("Failed to write miracle.toml for {}: {}", profile_id, e);
+        }
+        let lockfile = super::manifest::ProfileLockfile { mods: locked_mods };
+        if let Err(e) = super::manifest::save_lockfile(&mods_dir, &lockfile) {
+            tracing::warn!(
+                "Failed to write miracle.lock.toml for {}: {}",
+                profile_id,
+                e
+            );
+        }
+    }
+
+    emit_instance_import_progress(&app, "complete", 1, 1);
+
+    Ok(ModpackImportResult {
+        profile_id,
+        name: index.name,
+        mods_installed: installed,
+        mods_failed: failed_files.len() as u32,
+        warnings: failed_files,
+        manual_downloads: vec![],
+        minecraft_version,
+        loader: Some(loader),
+    })
 }
 
 /// Preview a modpack file before importing
@@ -1020,8 +3116,14 @@ pub async fn preview_modpack_file(file_path: String) -> Result<ModpackPreview, S
         return Err("File or folder not found".to_string());
     }
 
-    // Check if it's a directory (MultiMC instance)
+    // Check if it's a directory (MultiMC/Prism, ATLauncher, or GDLauncher instance)
     if path.is_dir() {
+        if path.join("instance.json").exists() {
+            return preview_atlauncher_instance(path).await;
+        }
+        if path.join("config.json").exists() {
+            return preview_gdlauncher_instance(path).await;
+        }
         return preview_multimc_instance(path).await;
     }
 
@@ -1073,15 +3175,7 @@ async fn preview_mrpack(bytes: &[u8]) -> Result<ModpackPreview, String> {
         .cloned()
         .unwrap_or_else(|| "unknown".to_string());
 
-    let loader = if index.dependencies.contains_key("fabric-loader") {
-        Some("fabric".to_string())
-    } else if index.dependencies.contains_key("forge") {
-        Some("forge".to_string())
-    } else if index.dependencies.contains_key("quilt-loader") {
-        Some("quilt".to_string())
-    } else {
-        None
-    };
+    let loader = Some(modrinth_loader_from_dependencies(&index.dependencies).to_string());
 
     let mod_count = index
         .files
@@ -1089,21 +3183,13 @@ async fn preview_mrpack(bytes: &[u8]) -> Result<ModpackPreview, String> {
         .filter(|f| f.path.starts_with("mods/"))
         .count() as u32;
 
-    let mut warnings = Vec::new();
-    if loader.as_deref() != Some("fabric") {
-        warnings.push(format!(
-            "This modpack uses {:?} loader. Only Fabric mods are supported.",
-            loader
-        ));
-    }
-
     Ok(ModpackPreview {
         name: index.name,
         minecraft_version,
         mod_count,
         format: "modrinth".to_string(),
         loader,
-        warnings,
+        warnings: Vec::new(),
     })
 }
 
@@ -1121,20 +3207,13 @@ async fn preview_curseforge_zip(bytes: &[u8]) -> Result<ModpackPreview, String>
 
     let manifest: CurseForgeManifest = serde_json::from_str(&json).map_err(|e| e.to_string())?;
 
-    let loader = manifest.minecraft.mod_loaders.first().map(|l| l.id.clone());
-
-    let is_fabric = loader
-        .as_ref()
-        .map(|l| l.to_lowercase().contains("fabric"))
-        .unwrap_or(false);
-
-    let mut warnings = Vec::new();
-    if !is_fabric {
-        warnings.push(format!(
-            "This modpack uses {:?} loader. Only Fabric mods will be installed.",
-            loader
-        ));
-    }
+    let loader = manifest
+        .minecraft
+        .mod_loaders
+        .iter()
+        .find(|l| l.primary)
+        .or_else(|| manifest.minecraft.mod_loaders.first())
+        .map(|l| curseforge_loader_type(&l.id).1.to_string());
 
     Ok(ModpackPreview {
         name: manifest.name,
@@ -1142,7 +3221,7 @@ async fn preview_curseforge_zip(bytes: &[u8]) -> Result<ModpackPreview, String>
         mod_count: manifest.files.len() as u32,
         format: "curseforge".to_string(),
         loader,
-        warnings,
+        warnings: Vec::new(),
     })
 }
 
@@ -1298,9 +3377,48 @@ async fn preview_multimc_instance(path: &std::path::Path) -> Result<ModpackPrevi
     })
 }
 
+async fn preview_atlauncher_instance(path: &std::path::Path) -> Result<ModpackPreview, String> {
+    let imported = parse_atlauncher_instance(path).await?;
+
+    let mut warnings = Vec::new();
+    if imported.loader.as_deref() != Some("fabric") {
+        warnings.push("This instance may not use Fabric. Some mods may not work.".to_string());
+    }
+
+    Ok(ModpackPreview {
+        name: imported.name,
+        minecraft_version: imported.mc_version,
+        mod_count: imported.mods.len() as u32,
+        format: "atlauncher".to_string(),
+        loader: imported.loader,
+        warnings,
+    })
+}
+
+async fn preview_gdlauncher_instance(path: &std::path::Path) -> Result<ModpackPreview, String> {
+    let imported = parse_gdlauncher_instance(path).await?;
+
+    let mut warnings = Vec::new();
+    if imported.loader.as_deref() != Some("fabric") {
+        warnings.push("This instance may not use Fabric. Some mods may not work.".to_string());
+    }
+
+    Ok(ModpackPreview {
+        name: imported.name,
+        minecraft_version: imported.mc_version,
+        mod_count: imported.mods.len() as u32,
+        format: "gdlauncher".to_string(),
+        loader: imported.loader,
+        warnings,
+    })
+}
+
 /// Import a modpack from a file path
 #[tauri::command]
-pub async fn import_modpack_file(file_path: String) -> Result<ModpackImportResult, String> {
+pub async fn import_modpack_file(
+    app: tauri::AppHandle,
+    file_path: String,
+) -> Result<ModpackImportResult, String> {
     let path = std::path::Path::new(&file_path);
 
     if !path.exists() {
@@ -1312,18 +3430,49 @@ pub async fn import_modpack_file(file_path: String) -> Result<ModpackImportResul
         // Check what type of instance folder this is
         let mmc_pack = path.join("mmc-pack.json");
         let instance_cfg = path.join("instance.cfg");
+        let atlauncher_json = path.join("instance.json");
+        let gdlauncher_json = path.join("config.json");
         let mods_dir = path.join("mods");
         let minecraft_dir = path.join(".minecraft").join("mods");
 
         if mmc_pack.exists() || instance_cfg.exists() {
             // MultiMC/Prism style instance
-            let profile_id = install_multimc_instance(path).await?;
+            let (profile_id, warnings) = install_multimc_instance(path).await?;
             return Ok(ModpackImportResult {
                 profile_id,
                 name: "Imported Instance".to_string(),
                 mods_installed: 0,
+                mods_failed: warnings.len() as u32,
+                warnings,
+                manual_downloads: vec![],
+                minecraft_version: None,
+                loader: None,
+            });
+        } else if atlauncher_json.exists() {
+            let profile =
+                import_profile(app.clone(), file_path.clone(), "atlauncher".to_string()).await?;
+            return Ok(ModpackImportResult {
+                profile_id: profile.id,
+                name: profile.name,
+                mods_installed: profile.mods.len() as u32,
+                mods_failed: 0,
+                warnings: vec![],
+                manual_downloads: vec![],
+                minecraft_version: None,
+                loader: None,
+            });
+        } else if gdlauncher_json.exists() {
+            let profile =
+                import_profile(app.clone(), file_path.clone(), "gdlauncher".to_string()).await?;
+            return Ok(ModpackImportResult {
+                profile_id: profile.id,
+                name: profile.name,
+                mods_installed: profile.mods.len() as u32,
                 mods_failed: 0,
                 warnings: vec![],
+                manual_downloads: vec![],
+                minecraft_version: None,
+                loader: None,
             });
         } else if mods_dir.exists() {
             // Modrinth App style - mods folder directly in profile
@@ -1338,6 +3487,9 @@ pub async fn import_modpack_file(file_path: String) -> Result<ModpackImportResul
                 mods_installed: 0,
                 mods_failed: 0,
                 warnings: vec![],
+                manual_downloads: vec![],
+                minecraft_version: None,
+                loader: None,
             });
         } else if minecraft_dir.exists() {
             // Some other launcher with .minecraft subfolder
@@ -1352,6 +3504,9 @@ pub async fn import_modpack_file(file_path: String) -> Result<ModpackImportResul
                 mods_installed: 0,
                 mods_failed: 0,
                 warnings: vec![],
+                manual_downloads: vec![],
+                minecraft_version: None,
+                loader: None,
             });
         } else {
             return Err("Not a valid instance folder (no mods directory found)".to_string());
@@ -1400,48 +3555,141 @@ pub async fn import_modpack_file(file_path: String) -> Result<ModpackImportResul
             .unwrap_or_else(|| "1.21.4".to_string());
 
         // Use existing mrpack install logic but with the bytes we already have
-        let profile_id = install_mrpack_from_bytes(&bytes, &minecraft_version).await?;
+        let (profile_id, mods_installed, warnings) =
+            install_mrpack_from_bytes(&bytes, &minecraft_version).await?;
 
         Ok(ModpackImportResult {
             profile_id,
             name: index.name,
-            mods_installed: index
-                .files
-                .iter()
-                .filter(|f| f.path.starts_with("mods/"))
-                .count() as u32,
-            mods_failed: 0,
-            warnings: vec![],
+            mods_installed,
+            mods_failed: warnings.len() as u32,
+            warnings,
+            manual_downloads: vec![],
+            minecraft_version: index.dependencies.get("minecraft").cloned(),
+            loader: Some(modrinth_loader_from_dependencies(&index.dependencies).to_string()),
         })
     } else if file_names.iter().any(|n| n == "manifest.json") {
         // CurseForge format
-        let profile_id = install_curseforge_modpack_from_bytes(&bytes).await?;
+        let (profile_id, manual_downloads) =
+            install_curseforge_modpack_from_bytes(&app, &bytes).await?;
+        let mods_failed = manual_downloads.len() as u32;
+        let warnings = if manual_downloads.is_empty() {
+            vec![]
+        } else {
+            vec![format!(
+                "{} mod(s) disallow third-party distribution and must be downloaded manually: {}",
+                manual_downloads.len(),
+                manual_downloads
+                    .iter()
+                    .map(|m| m.file_name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )]
+        };
 
         Ok(ModpackImportResult {
             profile_id,
             name: "CurseForge Modpack".to_string(),
             mods_installed: 0,
-            mods_failed: 0,
-            warnings: vec![],
+            mods_failed,
+            warnings,
+            manual_downloads,
+            minecraft_version: None,
+            loader: None,
         })
     } else if file_names.iter().any(|n| n == "mmc-pack.json") {
         // MultiMC exported zip - extract and install
-        let profile_id = install_multimc_zip(&bytes).await?;
+        let (profile_id, warnings) = install_multimc_zip(&bytes).await?;
 
         Ok(ModpackImportResult {
             profile_id,
             name: "MultiMC Instance".to_string(),
             mods_installed: 0,
-            mods_failed: 0,
-            warnings: vec![],
+            mods_failed: warnings.len() as u32,
+            warnings,
+            manual_downloads: vec![],
+            minecraft_version: None,
+            loader: None,
         })
     } else {
         Err("Unrecognized modpack format".to_string())
     }
 }
 
+/// Outcome of resolving + downloading a single `.mrpack` index entry,
+/// reported back from the concurrent download pool.
+enum MrpackIndexFileOutcome {
+    Installed,
+    NotApplicable,
+    SkippedServerOnly(String),
+    Failed(String),
+}
+
+/// Resolve + download a single `.mrpack` index entry, routing it to the
+/// mods/resourcepacks/shaderpacks dir its path prefix indicates, reported
+/// back from the concurrent download pool. Shared by `install_mrpack` and
+/// `install_mrpack_from_bytes` so the dedicated-command and drag-and-drop
+/// import paths install the same set of files.
+async fn install_from_mrpack_file(
+    file: MrpackFile,
+    mods_dir: Arc<PathBuf>,
+    resourcepacks_dir: Arc<PathBuf>,
+    shaderpacks_dir: Arc<PathBuf>,
+    client: reqwest::Client,
+) -> MrpackIndexFileOutcome {
+    if let Some(env) = &file.env {
+        if env.client == "unsupported" {
+            return MrpackIndexFileOutcome::SkippedServerOnly(file.path);
+        }
+    }
+
+    let (dest_dir, relative) = if let Some(rel) = file.path.strip_prefix("mods/") {
+        (mods_dir.as_ref(), rel)
+    } else if let Some(rel) = file.path.strip_prefix("resourcepacks/") {
+        (resourcepacks_dir.as_ref(), rel)
+    } else if let Some(rel) = file.path.strip_prefix("shaderpacks/") {
+        (shaderpacks_dir.as_ref(), rel)
+    } else {
+        tracing::warn!("Skipping unsupported mrpack entry: {}", file.path);
+        return MrpackIndexFileOutcome::NotApplicable;
+    };
+
+    if let Err(e) = tokio::fs::create_dir_all(dest_dir).await {
+        tracing::warn!("Failed to create {}: {}", dest_dir.display(), e);
+        return MrpackIndexFileOutcome::Failed(file.path);
+    }
+
+    let dest_path = dest_dir.join(relative);
+    if dest_path.exists() && !mrpack_file_needs_redownload(&dest_path, &file.hashes).await {
+        return MrpackIndexFileOutcome::Installed;
+    }
+
+    for url in &file.downloads {
+        let Ok(response) = send_with_retry(|| client.get(url), &RetryConfig::default()).await
+        else {
+            continue;
+        };
+        let Ok(data) = response.bytes().await else {
+            continue;
+        };
+        if let Err(e) = verify_mrpack_hashes(&data, &file.hashes) {
+            tracing::warn!("Integrity check failed for {}: {}", file.path, e);
+            continue;
+        }
+        if tokio::fs::write(&dest_path, &data).await.is_ok() {
+            return MrpackIndexFileOutcome::Installed;
+        }
+    }
+
+    tracing::warn!("Failed to install {}: no URL produced a matching hash", file.path);
+    MrpackIndexFileOutcome::Failed(file.path)
+}
+
 /// Install a Modrinth modpack from raw bytes
-async fn install_mrpack_from_bytes(bytes: &[u8], game_version: &str) -> Result<String, String> {
+async fn install_mrpack_from_bytes(
+    bytes: &[u8],
+    game_version: &str,
+) -> Result<(String, u32, Vec<String>), String> {
     // Parse index synchronously to avoid holding ZipArchive across await
     let index: MrpackIndex = {
         let cursor = std::io::Cursor::new(bytes);
@@ -1463,6 +3711,17 @@ async fn install_mrpack_from_bytes(bytes: &[u8], game_version: &str) -> Result<S
     let profile = profile_manager.create_modpack_profile(&index.name, game_version, "modrinth")?;
     let profile_id = profile.id.clone();
     let mods_dir = profile_manager.get_mods_dir(game_version, &profile_id);
+    let profile_dir_name = sanitize_profile_name(&index.name);
+    let resourcepacks_dir =
+        super::get_resourcepacks_directory(Some(game_version), Some(&profile_dir_name));
+    let shaderpacks_dir =
+        super::get_shaderpacks_directory(Some(game_version), Some(&profile_dir_name));
+
+    if let Some(icon_bytes) = extract_zip_icon(bytes) {
+        if let Err(e) = cache_profile_icon(&mut profile_manager, &profile_id, icon_bytes).await {
+            tracing::warn!("Failed to cache modpack icon: {}", e);
+        }
+    }
 
     tokio::fs::create_dir_all(&mods_dir)
         .await
@@ -1470,38 +3729,56 @@ async fn install_mrpack_from_bytes(bytes: &[u8], game_version: &str) -> Result<S
 
     let client = create_client()?;
 
-    // Download mods
-    for file in &index.files {
-        if let Some(env) = &file.env {
-            if env.client == "unsupported" {
-                continue;
-            }
-        }
-
-        if !file.path.starts_with("mods/") {
-            continue;
-        }
-
-        let filename = file.path.strip_prefix("mods/").unwrap_or(&file.path);
-        let dest_path = mods_dir.join(filename);
-
-        if dest_path.exists() {
-            continue;
-        }
+    // Download mods/resourcepacks/shaderpacks up to MODPACK_DOWNLOAD_CONCURRENCY
+    // at a time, skipping anything the index marks as server-only and
+    // rejecting any download whose hash doesn't match what the index
+    // declared, both reported back as warnings instead of a shrinking mod
+    // count with no explanation.
+    let mods_dir_shared = Arc::new(mods_dir.clone());
+    let resourcepacks_dir_shared = Arc::new(resourcepacks_dir.clone());
+    let shaderpacks_dir_shared = Arc::new(shaderpacks_dir.clone());
+    let semaphore = Arc::new(Semaphore::new(MODPACK_DOWNLOAD_CONCURRENCY));
+    let mut tasks = FuturesUnordered::new();
+    for file in index.files.clone() {
+        let semaphore = semaphore.clone();
+        let mods_dir_shared = mods_dir_shared.clone();
+        let resourcepacks_dir_shared = resourcepacks_dir_shared.clone();
+        let shaderpacks_dir_shared = shaderpacks_dir_shared.clone();
+        let client = client.clone();
+        tasks.push(tokio::spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            install_from_mrpack_file(
+                file,
+                mods_dir_shared,
+                resourcepacks_dir_shared,
+                shaderpacks_dir_shared,
+                client,
+            )
+            .await
+        }));
+    }
 
-        for url in &file.downloads {
-            if let Ok(response) = client.get(url).send().await {
-                if response.status().is_success() {
-                    if let Ok(data) = response.bytes().await {
-                        tokio::fs::write(&dest_path, &data).await.ok();
-                        break;
-                    }
-                }
+    let mut warnings = Vec::new();
+    let mut installed_count = 0u32;
+    while let Some(result) = tasks.next().await {
+        match result {
+            Ok(MrpackIndexFileOutcome::Installed) => installed_count += 1,
+            Ok(MrpackIndexFileOutcome::NotApplicable) => {}
+            Ok(MrpackIndexFileOutcome::SkippedServerOnly(path)) => {
+                warnings.push(format!("Skipped {} (server-only mod)", path));
+            }
+            Ok(MrpackIndexFileOutcome::Failed(path)) => {
+                warnings.push(format!(
+                    "Failed to install {} (hash mismatch or download failure)",
+                    path
+                ));
             }
+            Err(e) => warnings.push(format!("task panicked: {}", e)),
         }
     }
 
-    // Extract overrides
+    // Extract overrides. `client-overrides/` takes the same destination as
+    // `overrides/`; `server-overrides/` is server-only and must be skipped.
     let overrides: Vec<(PathBuf, Vec<u8>)> = {
         let cursor = std::io::Cursor::new(bytes);
         let mut archive = ZipArchive::new(cursor).map_err(|e| e.to_string())?;
@@ -1515,9 +3792,23 @@ async fn install_mrpack_from_bytes(bytes: &[u8], game_version: &str) -> Result<S
         for i in 0..archive.len() {
             if let Ok(mut file) = archive.by_index(i) {
                 let name = file.name().to_string();
-                if name.starts_with("overrides/") {
-                    let relative = name.strip_prefix("overrides/").unwrap_or(&name);
+                let relative = if let Some(rel) = name.strip_prefix("overrides/") {
+                    Some(rel)
+                } else if let Some(rel) = name.strip_prefix("client-overrides/") {
+                    Some(rel)
+                } else {
+                    None
+                };
+
+                if let Some(relative) = relative {
                     if !relative.is_empty() && !name.ends_with('/') {
+                        if !is_safe_relative_path(relative) {
+                            tracing::warn!(
+                                "Skipping unsafe override path in modpack archive: {}",
+                                name
+                            );
+                            continue;
+                        }
                         let dest = game_dir.join(relative);
                         let mut contents = Vec::new();
                         file.read_to_end(&mut contents).ok();
@@ -1541,11 +3832,11 @@ async fn install_mrpack_from_bytes(bytes: &[u8], game_version: &str) -> Result<S
 
     profile_manager.set_active_profile(game_version, &profile_id)?;
 
-    Ok(profile_id)
+    Ok((profile_id, installed_count, warnings))
 }
 
 /// Install a MultiMC instance from a zip file
-async fn install_multimc_zip(bytes: &[u8]) -> Result<String, String> {
+async fn install_multimc_zip(bytes: &[u8]) -> Result<(String, Vec<String>), String> {
     // Extract to a temp directory and then install
     let temp_dir = std::env::temp_dir().join(format!("miracle_import_{}", uuid::Uuid::new_v4()));
 
@@ -1560,6 +3851,10 @@ async fn install_multimc_zip(bytes: &[u8]) -> Result<String, String> {
             let name = file.name().to_string();
 
             if !name.ends_with('/') {
+                if !is_safe_relative_path(&name) {
+                    tracing::warn!("Skipping unsafe path in MultiMC import zip: {}", name);
+                    continue;
+                }
                 let dest = temp_dir.join(&name);
                 let mut contents = Vec::new();
                 file.read_to_end(&mut contents).ok();
@@ -1596,6 +3891,16 @@ async fn install_multimc_zip(bytes: &[u8]) -> Result<String, String> {
 // Auto-Detection of Installed Instances
 // ============================================================================
 
+/// A mod in a detected instance, resolved by content hash where possible so
+/// the UI can distinguish recognized projects from unknown/local jars.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdentifiedMod {
+    pub name: String,
+    pub project_id: Option<String>,
+    pub version_id: Option<String>,
+    pub matched: bool,
+}
+
 /// Detected instance from another launcher
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DetectedInstance {
@@ -1605,6 +3910,8 @@ pub struct DetectedInstance {
     pub minecraft_version: Option<String>,
     pub loader: Option<String>,
     pub mod_count: u32,
+    #[serde(default)]
+    pub identified_mods: Vec<IdentifiedMod>,
 }
 
 /// Detect all installed instances from other launchers
@@ -1626,7 +3933,7 @@ pub async fn detect_installed_instances() -> Result<Vec<DetectedInstance>, Strin
                 if let Ok(entries) = std::fs::read_dir(&modrinth_path) {
                     for entry in entries.flatten() {
                         if entry.path().is_dir() {
-                            if let Some(instance) = detect_modrinth_instance(&entry.path()) {
+                            if let Some(instance) = detect_modrinth_instance(&entry.path()).await {
                                 // Avoid duplicates (in case both paths exist during migration)
                                 if !instances
                                     .iter()
@@ -1740,6 +4047,28 @@ pub async fn detect_installed_instances() -> Result<Vec<DetectedInstance>, Strin
         }
     }
 
+    // Scan GDLauncher (old gdlauncher_next path and the newer Carbon rewrite)
+    if let Some(ref appdata) = appdata {
+        for gdlauncher_dir in &["gdlauncher_next", "gdlauncher_carbon"] {
+            let gdlauncher_path = PathBuf::from(appdata)
+                .join(gdlauncher_dir)
+                .join("instances");
+            if gdlauncher_path.exists() {
+                if let Ok(entries) = std::fs::read_dir(&gdlauncher_path) {
+                    for entry in entries.flatten() {
+                        if entry.path().is_dir() {
+                            if let Some(instance) = detect_gdlauncher_instance(&entry.path()) {
+                                if !instances.iter().any(|i| i.path == instance.path) {
+                                    instances.push(instance);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     tracing::info!(
         "Detected {} instances from other launchers",
         instances.len()
@@ -1747,7 +4076,7 @@ pub async fn detect_installed_instances() -> Result<Vec<DetectedInstance>, Strin
     Ok(instances)
 }
 
-fn detect_modrinth_instance(path: &std::path::Path) -> Option<DetectedInstance> {
+async fn detect_modrinth_instance(path: &std::path::Path) -> Option<DetectedInstance> {
     // Modrinth stores profile metadata in app.db (SQLite), not individual JSON files
     // We detect by checking for a mods folder and use the directory name as instance name
     let mods_dir = path.join("mods");
@@ -1763,25 +4092,29 @@ fn detect_modrinth_instance(path: &std::path::Path) -> Option<DetectedInstance>
         return None;
     }
 
-    // Count mods and detect version from filenames
-    let mut mod_count = 0;
+    // Identify mods by content hash (SHA-512 against Modrinth, then
+    // CurseForge's fingerprint match) instead of guessing from filenames,
+    // since Modrinth App doesn't expose readable per-instance metadata.
+    let (identified, unmatched_names) = identify_mods_by_hash(&mods_dir).await;
+    let mod_count = (identified.len() + unmatched_names.len()) as u32;
+
+    // Majority-vote the Minecraft version across hash-identified jars'
+    // authoritative game_versions; only fall back to the filename heuristic
+    // if the pack has no hash-identified jars at all.
     let mut version_counts: std::collections::HashMap<String, u32> =
         std::collections::HashMap::new();
-
-    if let Ok(entries) = std::fs::read_dir(&mods_dir) {
-        for entry in entries.flatten() {
-            let entry_path = entry.path();
-            if entry_path.extension().map(|e| e == "jar").unwrap_or(false) {
-                mod_count += 1;
-                let name = entry.file_name().to_string_lossy().to_string();
-                if let Some(version) = extract_mc_version_from_mod(&name) {
-                    *version_counts.entry(version).or_insert(0) += 1;
-                }
+    for jar in &identified {
+        for game_version in &jar.game_versions {
+            *version_counts.entry(game_version.clone()).or_insert(0) += 1;
+        }
+    }
+    if version_counts.is_empty() {
+        for filename in &unmatched_names {
+            if let Some(version) = extract_mc_version_from_mod(filename) {
+                *version_counts.entry(version).or_insert(0) += 1;
             }
         }
     }
-
-    // Use the most common version found
     let minecraft_version = version_counts
         .iter()
         .max_by_key(|(_, count)| *count)
@@ -1794,6 +4127,22 @@ fn detect_modrinth_instance(path: &std::path::Path) -> Option<DetectedInstance>
         None
     };
 
+    let mut identified_mods: Vec<IdentifiedMod> = identified
+        .into_iter()
+        .map(|jar| IdentifiedMod {
+            name: jar.name,
+            project_id: jar.modrinth_project_id,
+            version_id: jar.modrinth_version_id,
+            matched: true,
+        })
+        .collect();
+    identified_mods.extend(unmatched_names.into_iter().map(|filename| IdentifiedMod {
+        name: filename,
+        project_id: None,
+        version_id: None,
+        matched: false,
+    }));
+
     Some(DetectedInstance {
         name,
         path: path.to_string_lossy().to_string(),
@@ -1801,6 +4150,7 @@ fn detect_modrinth_instance(path: &std::path::Path) -> Option<DetectedInstance>
         minecraft_version,
         loader,
         mod_count,
+        identified_mods,
     })
 }
 
@@ -1911,6 +4261,7 @@ fn detect_curseforge_instance(path: &std::path::Path) -> Option<DetectedInstance
         minecraft_version: instance.game_version,
         loader,
         mod_count,
+        identified_mods: Vec::new(),
     })
 }
 
@@ -1974,6 +4325,7 @@ fn detect_multimc_style_instance(path: &std::path::Path, source: &str) -> Option
         minecraft_version,
         loader,
         mod_count,
+        identified_mods: Vec::new(),
     })
 }
 
@@ -2025,6 +4377,53 @@ fn detect_atlauncher_instance(path: &std::path::Path) -> Option<DetectedInstance
         minecraft_version: launcher.minecraft_version,
         loader,
         mod_count,
+        identified_mods: Vec::new(),
+    })
+}
+
+fn detect_gdlauncher_instance(path: &std::path::Path) -> Option<DetectedInstance> {
+    // GDLauncher stores instance.cfg-equivalent metadata in config.json
+    let config_json = path.join("config.json");
+    if !config_json.exists() {
+        return None;
+    }
+
+    #[derive(Deserialize)]
+    struct GdlConfig {
+        name: Option<String>,
+        loader: Option<GdlLoader>,
+    }
+    #[derive(Deserialize)]
+    struct GdlLoader {
+        #[serde(rename = "mcVersion")]
+        mc_version: Option<String>,
+        #[serde(rename = "loaderType")]
+        loader_type: Option<String>,
+    }
+
+    let content = std::fs::read_to_string(&config_json).ok()?;
+    let config: GdlConfig = serde_json::from_str(&content).ok()?;
+
+    let name = config
+        .name
+        .unwrap_or_else(|| path.file_name().unwrap().to_string_lossy().to_string());
+
+    let (minecraft_version, loader) = match config.loader {
+        Some(l) => (l.mc_version, l.loader_type),
+        None => (None, None),
+    };
+
+    let mods_dir = path.join("mods");
+    let mod_count = count_jar_files(&mods_dir);
+
+    Some(DetectedInstance {
+        name,
+        path: path.to_string_lossy().to_string(),
+        source: "gdlauncher".to_string(),
+        minecraft_version,
+        loader,
+        mod_count,
+        identified_mods: Vec::new(),
     })
 }
 
@@ -1,11 +1,32 @@
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use sha1::{Digest as _, Sha1};
+use sha2::Sha512;
 use tauri::Manager;
 
-const MODRINTH_API_BASE: &str = "https://api.modrinth.com/v2";
+use super::retry::{send_with_retry, RetryConfig};
+use super::AppState;
+
+pub(crate) const MODRINTH_API_BASE: &str = "https://api.modrinth.com/v2";
+
+/// Mod loaders this crate knows how to query Modrinth for.
+pub(crate) const VALID_LOADERS: &[&str] = &["fabric", "quilt", "forge", "neoforge"];
+
+/// Validate a loader string against [`VALID_LOADERS`], falling back to
+/// `"fabric"` (the long-standing default before per-loader support) for
+/// anything unrecognized instead of sending Modrinth a facet it'll just
+/// return zero results for.
+pub(crate) fn normalize_loader(loader: &str) -> &'static str {
+    VALID_LOADERS
+        .iter()
+        .find(|&&l| l == loader)
+        .copied()
+        .unwrap_or("fabric")
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModrinthProject {
+    pub id: String,
     pub slug: String,
     pub title: String,
     pub description: String,
@@ -71,6 +92,16 @@ pub struct ModrinthVersion {
     pub files: Vec<ModrinthFile>,
     pub downloads: i64,
     pub date_published: String,
+    #[serde(default)]
+    pub dependencies: Vec<ModrinthVersionDependency>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModrinthVersionDependency {
+    pub version_id: Option<String>,
+    pub project_id: Option<String>,
+    pub file_name: Option<String>,
+    pub dependency_type: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -88,6 +119,30 @@ pub struct ModrinthHashes {
     pub sha512: String,
 }
 
+/// Verify downloaded bytes against the file's expected hashes, preferring
+/// sha512 and falling back to sha1, so a corrupted or tampered download never
+/// lands on disk.
+pub(crate) fn verify_modrinth_hashes(bytes: &[u8], hashes: &ModrinthHashes) -> Result<(), String> {
+    let mut sha512 = Sha512::new();
+    sha512.update(bytes);
+    let sha512_hash = format!("{:x}", sha512.finalize());
+    if sha512_hash.eq_ignore_ascii_case(&hashes.sha512) {
+        return Ok(());
+    }
+
+    let mut sha1 = Sha1::new();
+    sha1.update(bytes);
+    let sha1_hash = format!("{:x}", sha1.finalize());
+    if sha1_hash.eq_ignore_ascii_case(&hashes.sha1) {
+        return Ok(());
+    }
+
+    Err(format!(
+        "Hash mismatch: expected sha512={} (or sha1={}), got sha512={} sha1={}",
+        hashes.sha512, hashes.sha1, sha512_hash, sha1_hash
+    ))
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModrinthCategory {
     pub icon: String,
@@ -96,7 +151,7 @@ pub struct ModrinthCategory {
     pub header: String,
 }
 
-fn create_client() -> Result<Client, String> {
+pub(crate) fn create_client() -> Result<Client, String> {
     Client::builder()
         .timeout(std::time::Duration::from_secs(30))
         .user_agent("MiracleClient/1.0 (https://github.com/miracle-client)")
@@ -112,6 +167,7 @@ pub async fn search_modrinth(
     categories: Vec<String>,
     sort: String, // "relevance", "downloads", "follows", "newest", "updated"
     version: String,
+    loader: String, // "fabric", "quilt", "forge", "neoforge"
     offset: u32,
     limit: u32,
 ) -> Result<ModrinthSearchResponse, String> {
@@ -145,9 +201,10 @@ pub async fn search_modrinth(
         facets.push(cat_facet);
     }
 
-    // For mods and modpacks, add fabric loader filter
+    // For mods and modpacks, filter by loader so a Quilt/Forge/NeoForge
+    // profile isn't shown (or sent) Fabric-only results.
     if content_type == "mod" || content_type == "modpack" {
-        facets.push(vec!["categories:fabric".to_string()]);
+        facets.push(vec![format!("categories:{}", normalize_loader(&loader))]);
     }
 
     // Convert facets to JSON string
@@ -174,8 +231,12 @@ pub async fn search_modrinth(
 
     tracing::info!("Searching Modrinth: {}", url);
 
-    let response = client
-        .get(&url)
+    let mut request = client.get(&url);
+    if let Some(pat) = super::modrinth_auth::stored_token() {
+        request = request.header("Authorization", pat);
+    }
+
+    let response = request
         .send()
         .await
         .map_err(|e| format!("Failed to search Modrinth: {}", e))?;
@@ -202,16 +263,10 @@ pub async fn get_modrinth_project(id_or_slug: String) -> Result<ModrinthProject,
 
     let url = format!("{}/project/{}", MODRINTH_API_BASE, id_or_slug);
 
-    let response = client
-        .get(&url)
-        .send()
+    let response = send_with_retry(|| client.get(&url), &RetryConfig::default())
         .await
         .map_err(|e| format!("Failed to get project: {}", e))?;
 
-    if !response.status().is_success() {
-        return Err(format!("Failed to get project: HTTP {}", response.status()));
-    }
-
     let project: ModrinthProject = response
         .json()
         .await
@@ -220,6 +275,52 @@ pub async fn get_modrinth_project(id_or_slug: String) -> Result<ModrinthProject,
     Ok(project)
 }
 
+/// Get multiple projects in a single request (bulk lookup by id or slug)
+pub async fn get_modrinth_projects_bulk(ids: Vec<String>) -> Result<Vec<ModrinthProject>, String> {
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let client = create_client()?;
+
+    let ids_json = serde_json::to_string(&ids).unwrap_or_else(|_| "[]".to_string());
+    let url = format!(
+        "{}/projects?ids={}",
+        MODRINTH_API_BASE,
+        urlencoding::encode(&ids_json)
+    );
+
+    let response = send_with_retry(|| client.get(&url), &RetryConfig::default())
+        .await
+        .map_err(|e| format!("Failed to get projects: {}", e))?;
+
+    let projects: Vec<ModrinthProject> = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse projects: {}", e))?;
+
+    Ok(projects)
+}
+
+/// Get a single version by its id, e.g. to resolve a dependency pinned to an
+/// exact `version_id` rather than "newest compatible".
+pub async fn get_modrinth_version(version_id: &str) -> Result<ModrinthVersion, String> {
+    let client = create_client()?;
+
+    let url = format!("{}/version/{}", MODRINTH_API_BASE, version_id);
+
+    let response = send_with_retry(|| client.get(&url), &RetryConfig::default())
+        .await
+        .map_err(|e| format!("Failed to get version {}: {}", version_id, e))?;
+
+    let version: ModrinthVersion = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse version {}: {}", version_id, e))?;
+
+    Ok(version)
+}
+
 /// Get versions for a project
 #[tauri::command]
 pub async fn get_modrinth_versions(
@@ -244,19 +345,10 @@ pub async fn get_modrinth_versions(
         url = format!("{}?{}", url, params.join("&"));
     }
 
-    let response = client
-        .get(&url)
-        .send()
+    let response = send_with_retry(|| client.get(&url), &RetryConfig::default())
         .await
         .map_err(|e| format!("Failed to get versions: {}", e))?;
 
-    if !response.status().is_success() {
-        return Err(format!(
-            "Failed to get versions: HTTP {}",
-            response.status()
-        ));
-    }
-
     let versions: Vec<ModrinthVersion> = response
         .json()
         .await
@@ -265,6 +357,100 @@ pub async fn get_modrinth_versions(
     Ok(versions)
 }
 
+/// Walk a version's `required` dependencies (and their own transitive
+/// `required` dependencies), resolving each to the pinned `version_id` when
+/// given or otherwise the newest version compatible with `game_version`/
+/// `loader`. De-duplicates by `project_id` and stops re-queuing a project
+/// it's already seen, which doubles as cycle detection for dependency loops.
+/// `already_installed` maps the project id of each mod already present in
+/// the destination mods directory to its installed filename, so a
+/// dependency that's already installed is skipped rather than re-downloaded,
+/// and so an `incompatible` entry that conflicts with one of them can name
+/// the conflicting file in its error.
+async fn resolve_required_dependencies(
+    root: &ModrinthVersion,
+    game_version: &str,
+    loader: Option<&str>,
+    already_installed: &std::collections::HashMap<String, String>,
+) -> Result<Vec<ModrinthVersion>, String> {
+    let mut seen_projects: std::collections::HashSet<String> =
+        already_installed.keys().cloned().collect();
+    seen_projects.insert(root.project_id.clone());
+
+    check_incompatible_dependencies(&root.dependencies, already_installed)?;
+
+    let mut queue: Vec<ModrinthVersionDependency> = root
+        .dependencies
+        .iter()
+        .filter(|d| d.dependency_type == "required")
+        .cloned()
+        .collect();
+
+    let mut resolved = Vec::new();
+
+    while let Some(dep) = queue.pop() {
+        if let Some(ref project_id) = dep.project_id {
+            if seen_projects.contains(project_id) {
+                continue;
+            }
+        }
+
+        let dep_version = if let Some(ref version_id) = dep.version_id {
+            get_modrinth_version(version_id).await?
+        } else if let Some(ref project_id) = dep.project_id {
+            let versions = get_modrinth_versions(
+                project_id.clone(),
+                Some(game_version.to_string()),
+                loader.map(str::to_string),
+            )
+            .await?;
+            match versions.into_iter().next() {
+                Some(v) => v,
+                None => continue, // no compatible version published; skip rather than fail the whole install
+            }
+        } else {
+            continue;
+        };
+
+        if !seen_projects.insert(dep_version.project_id.clone()) {
+            continue;
+        }
+
+        check_incompatible_dependencies(&dep_version.dependencies, already_installed)?;
+
+        queue.extend(
+            dep_version
+                .dependencies
+                .iter()
+                .filter(|d| d.dependency_type == "required")
+                .cloned(),
+        );
+        resolved.push(dep_version);
+    }
+
+    Ok(resolved)
+}
+
+/// Fail fast if any of `dependencies` is marked `incompatible` with a
+/// project that's already installed, naming the conflicting filename rather
+/// than letting the install proceed into a broken state.
+fn check_incompatible_dependencies(
+    dependencies: &[ModrinthVersionDependency],
+    already_installed: &std::collections::HashMap<String, String>,
+) -> Result<(), String> {
+    for dep in dependencies.iter().filter(|d| d.dependency_type == "incompatible") {
+        if let Some(ref project_id) = dep.project_id {
+            if let Some(filename) = already_installed.get(project_id) {
+                return Err(format!(
+                    "Incompatible with already-installed mod: {}",
+                    filename
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Get available categories from Modrinth
 #[tauri::command]
 pub async fn get_modrinth_categories() -> Result<Vec<ModrinthCategory>, String> {
@@ -293,22 +479,40 @@ pub async fn get_modrinth_categories() -> Result<Vec<ModrinthCategory>, String>
     Ok(categories)
 }
 
-/// Download a mod to a specific directory (for performance mods installation)
+/// Download a mod to a specific directory (for performance mods installation).
+/// When `loader` is `"quilt"` and no Quilt-tagged version exists, retries
+/// with `"fabric"` since Quilt can load most Fabric mods.
 pub async fn download_mod_to_dir(
     project_slug: &str,
     game_version: &str,
+    loader: &str,
     dest_dir: &std::path::Path,
 ) -> Result<String, String> {
     let client = create_client()?;
+    let loader = normalize_loader(loader);
 
     // Get the appropriate version
-    let versions = get_modrinth_versions(
+    let mut versions = get_modrinth_versions(
         project_slug.to_string(),
         Some(game_version.to_string()),
-        Some("fabric".to_string()),
+        Some(loader.to_string()),
     )
     .await?;
 
+    if versions.is_empty() && loader == "quilt" {
+        tracing::info!(
+            "No Quilt version of {} for {}, falling back to Fabric",
+            project_slug,
+            game_version
+        );
+        versions = get_modrinth_versions(
+            project_slug.to_string(),
+            Some(game_version.to_string()),
+            Some("fabric".to_string()),
+        )
+        .await?;
+    }
+
     let version = versions.first().ok_or_else(|| {
         format!(
             "No compatible version found for {} on {}",
@@ -338,8 +542,12 @@ pub async fn download_mod_to_dir(
     tracing::info!("Downloading {} to {:?}", file.filename, dest_path);
 
     // Download the file
-    let response = client
-        .get(&file.url)
+    let mut download_request = client.get(&file.url);
+    if let Some(pat) = super::modrinth_auth::stored_token() {
+        download_request = download_request.header("Authorization", pat);
+    }
+
+    let response = download_request
         .send()
         .await
         .map_err(|e| format!("Failed to download: {}", e))?;
@@ -358,6 +566,10 @@ pub async fn download_mod_to_dir(
         return Err("Downloaded file is too small".to_string());
     }
 
+    // Verify hash before it ever touches disk
+    verify_modrinth_hashes(&bytes, &file.hashes)
+        .map_err(|e| format!("{} failed verification: {}", file.filename, e))?;
+
     // Write file
     tokio::fs::write(&dest_path, bytes)
         .await
@@ -367,25 +579,62 @@ pub async fn download_mod_to_dir(
     Ok(file.filename.clone())
 }
 
-/// Download and install content from Modrinth
+/// Result of [`download_modrinth_content`]: the message shown for the
+/// requested content, plus the filenames of any `required` dependencies that
+/// had to be installed alongside it so the UI can surface an "also
+/// installed: X, Y" notice.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModrinthInstallResult {
+    pub message: String,
+    #[serde(default)]
+    pub dependencies_installed: Vec<String>,
+}
+
+/// Download and install content from Modrinth. For mods, also resolves and
+/// installs the chosen version's `required` dependencies (see
+/// [`resolve_required_dependencies`]) so the install isn't left broken
+/// waiting on a library mod the user never browsed to.
 #[tauri::command]
 pub async fn download_modrinth_content(
     app: tauri::AppHandle,
     project_slug: String,
     content_type: String,
     game_version: String,
+    loader: String,
     profile_id: Option<String>,
-) -> Result<String, String> {
+) -> Result<ModrinthInstallResult, String> {
     let client = create_client()?;
 
     // Get the appropriate version
     let loader = if content_type == "mod" {
-        Some("fabric".to_string())
+        Some(normalize_loader(&loader).to_string())
     } else {
         None
     };
-    let versions =
-        get_modrinth_versions(project_slug.clone(), Some(game_version.clone()), loader).await?;
+    let mut versions = get_modrinth_versions(
+        project_slug.clone(),
+        Some(game_version.clone()),
+        loader.clone(),
+    )
+    .await?;
+
+    // Quilt can load most Fabric mods, so when nothing's published for Quilt
+    // specifically, fall back to the Fabric build and mark it as such.
+    let mut used_fallback_loader = false;
+    if versions.is_empty() && loader.as_deref() == Some("quilt") {
+        tracing::info!(
+            "No Quilt version of {} for {}, falling back to Fabric",
+            project_slug,
+            game_version
+        );
+        versions = get_modrinth_versions(
+            project_slug.clone(),
+            Some(game_version.clone()),
+            Some("fabric".to_string()),
+        )
+        .await?;
+        used_fallback_loader = !versions.is_empty();
+    }
 
     let version = versions
         .first()
@@ -406,11 +655,19 @@ pub async fn download_modrinth_content(
     let dest_dir = match content_type.as_str() {
         "mod" => {
             if let Some(ref pid) = profile_id {
-                // Get sanitized profile directory name
+                // Per-profile installs are already disambiguated by profile
+                // directory, and a profile has exactly one loader, so the
+                // destination here must match `launch_game`'s mods-directory
+                // lookup (`mods/<game_version>/<profile_dir>`, no loader
+                // segment) or the jar would never be found at launch.
                 let profile_dir = super::get_profile_dir_name(&app.state(), pid);
                 game_dir.join("mods").join(&game_version).join(&profile_dir)
             } else {
-                game_dir.join("mods").join(&game_version)
+                // No profile to disambiguate by, so a Fabric and a Quilt
+                // install of the same mod/version for the same game version
+                // would otherwise collide in the same shared directory.
+                let loader_dir = loader.as_deref().unwrap_or("fabric");
+                game_dir.join("mods").join(&game_version).join(loader_dir)
             }
         }
         "resourcepack" => {
@@ -458,14 +715,21 @@ pub async fn download_modrinth_content(
 
     // Check if already exists
     if dest_path.exists() {
-        return Ok(format!("Already installed: {}", file.filename));
+        return Ok(ModrinthInstallResult {
+            message: format!("Already installed: {}", file.filename),
+            dependencies_installed: Vec::new(),
+        });
     }
 
     tracing::info!("Downloading {} to {:?}", file.filename, dest_path);
 
     // Download the file
-    let response = client
-        .get(&file.url)
+    let mut download_request = client.get(&file.url);
+    if let Some(pat) = super::modrinth_auth::stored_token() {
+        download_request = download_request.header("Authorization", pat);
+    }
+
+    let response = download_request
         .send()
         .await
         .map_err(|e| format!("Failed to download: {}", e))?;
@@ -484,6 +748,10 @@ pub async fn download_modrinth_content(
         return Err("Downloaded file is too small".to_string());
     }
 
+    // Verify hash before it ever touches disk
+    verify_modrinth_hashes(&bytes, &file.hashes)
+        .map_err(|e| format!("{} failed verification: {}", file.filename, e))?;
+
     // Write file
     tokio::fs::write(&dest_path, bytes)
         .await
@@ -501,6 +769,14 @@ pub async fn download_modrinth_content(
                 installed_version: version.version_number.clone(),
                 version_id: version.id.clone(),
                 installed_at: chrono::Utc::now().to_rfc3339(),
+                repo_base: None,
+                asset_pattern: None,
+                loader_fallback: used_fallback_loader.then(|| "fabric".to_string()),
+                loader: if used_fallback_loader {
+                    "fabric".to_string()
+                } else {
+                    loader.clone().unwrap_or_else(|| "fabric".to_string())
+                },
             };
             if let Err(e) = super::mod_updates::update_mod_metadata(
                 &game_version,
@@ -513,5 +789,273 @@ pub async fn download_modrinth_content(
         }
     }
 
-    Ok(format!("Installed: {}", file.filename))
+    // Resolve and install any required dependencies (e.g. Fabric API) the
+    // chosen version declares, so the install isn't silently left broken.
+    let mut dependencies_installed = Vec::new();
+    if content_type == "mod" {
+        let already_installed: std::collections::HashMap<String, String> = profile_id
+            .as_ref()
+            .map(|pid| {
+                super::mod_updates::load_metadata(&game_version, pid)
+                    .mods
+                    .into_iter()
+                    .map(|(filename, m)| (m.project_id, filename))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let dep_loader = if used_fallback_loader {
+            Some("fabric")
+        } else {
+            loader.as_deref()
+        };
+        match resolve_required_dependencies(version, &game_version, dep_loader, &already_installed)
+            .await
+        {
+            Ok(deps) => {
+                for dep_version in deps {
+                    let dep_file = match dep_version
+                        .files
+                        .iter()
+                        .find(|f| f.primary)
+                        .or_else(|| dep_version.files.first())
+                    {
+                        Some(f) => f,
+                        None => continue,
+                    };
+
+                    let dep_dest_path = dest_dir.join(&dep_file.filename);
+                    if dep_dest_path.exists() {
+                        continue;
+                    }
+
+                    if let Err(e) = install_dependency_file(&client, dep_file, &dep_dest_path).await
+                    {
+                        tracing::warn!("Failed to install dependency {}: {}", dep_file.filename, e);
+                        continue;
+                    }
+
+                    if let Some(ref pid) = profile_id {
+                        let metadata = super::mod_updates::ModMetadata {
+                            source: "modrinth".to_string(),
+                            project_slug: dep_version.project_id.clone(),
+                            project_id: dep_version.project_id.clone(),
+                            installed_version: dep_version.version_number.clone(),
+                            version_id: dep_version.id.clone(),
+                            installed_at: chrono::Utc::now().to_rfc3339(),
+                            repo_base: None,
+                            asset_pattern: None,
+                            loader_fallback: None,
+                            loader: dep_loader.unwrap_or("fabric").to_string(),
+                        };
+                        if let Err(e) = super::mod_updates::update_mod_metadata(
+                            &game_version,
+                            pid,
+                            &dep_file.filename,
+                            metadata,
+                        ) {
+                            tracing::warn!("Failed to save dependency mod metadata: {}", e);
+                        }
+                    }
+
+                    dependencies_installed.push(dep_file.filename.clone());
+                }
+            }
+            Err(e) => tracing::warn!("Failed to resolve dependencies for {}: {}", project_slug, e),
+        }
+    }
+
+    Ok(ModrinthInstallResult {
+        message: format!("Installed: {}", file.filename),
+        dependencies_installed,
+    })
+}
+
+/// Resolve a specific Modrinth project version (or, if `version` is `None`,
+/// the newest build compatible with the profile) and install it along with
+/// its full `required` dependency chain, independent of the content-browser
+/// download flow in [`download_modrinth_content`]. Unlike that flow, an
+/// `incompatible` dependency conflict aborts the whole install rather than
+/// being logged and skipped, since this is meant to be safe to call from the
+/// update path where silently leaving a mod half-updated is worse than
+/// refusing the update.
+#[tauri::command]
+pub async fn resolve_and_install(
+    app: tauri::AppHandle,
+    project_slug: String,
+    version: Option<String>,
+    profile_id: String,
+) -> Result<Vec<String>, String> {
+    let state = app.state::<AppState>();
+    let (profile_dir, loader) = {
+        let manager = state.profile_manager.lock().unwrap();
+        let profile = manager.get_profile(&profile_id);
+        let profile_dir = profile
+            .map(|p| crate::profiles::sanitize_profile_name(&p.name))
+            .unwrap_or_else(|| crate::profiles::sanitize_profile_name(&profile_id));
+        let loader = profile
+            .map(|p| p.loader.clone())
+            .unwrap_or_else(|| "fabric".to_string());
+        (profile_dir, loader)
+    };
+    let game_version = {
+        let manager = state.profile_manager.lock().unwrap();
+        manager
+            .get_profile(&profile_id)
+            .map(|p| p.version.clone())
+            .ok_or_else(|| format!("Unknown profile: {}", profile_id))?
+    };
+
+    let client = create_client()?;
+
+    let target_version = match version {
+        Some(ref version_id) => get_modrinth_version(version_id).await?,
+        None => get_modrinth_versions(
+            project_slug.clone(),
+            Some(game_version.clone()),
+            Some(loader.clone()),
+        )
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| format!("No compatible version found for {}", game_version))?,
+    };
+
+    let dest_dir = super::get_mods_directory(Some(&game_version), Some(&profile_dir));
+    tokio::fs::create_dir_all(&dest_dir)
+        .await
+        .map_err(|e| format!("Failed to create directory: {}", e))?;
+
+    let already_installed: std::collections::HashMap<String, String> =
+        super::mod_updates::load_metadata(&game_version, &profile_id)
+            .mods
+            .into_iter()
+            .map(|(filename, m)| (m.project_id, filename))
+            .collect();
+
+    check_incompatible_dependencies(&target_version.dependencies, &already_installed)?;
+
+    let mut files_added = Vec::new();
+
+    if !already_installed.contains_key(&target_version.project_id) {
+        let file = target_version
+            .files
+            .iter()
+            .find(|f| f.primary)
+            .or_else(|| target_version.files.first())
+            .ok_or("No download file found")?;
+
+        let dest_path = dest_dir.join(&file.filename);
+        if !dest_path.exists() {
+            install_dependency_file(&client, file, &dest_path).await?;
+
+            let metadata = super::mod_updates::ModMetadata {
+                source: "modrinth".to_string(),
+                project_slug: project_slug.clone(),
+                project_id: target_version.project_id.clone(),
+                installed_version: target_version.version_number.clone(),
+                version_id: target_version.id.clone(),
+                installed_at: chrono::Utc::now().to_rfc3339(),
+                repo_base: None,
+                asset_pattern: None,
+                loader_fallback: None,
+                loader: loader.clone(),
+            };
+            super::mod_updates::update_mod_metadata(
+                &game_version,
+                &profile_id,
+                &file.filename,
+                metadata,
+            )?;
+
+            files_added.push(file.filename.clone());
+        }
+    }
+
+    let deps =
+        resolve_required_dependencies(&target_version, &game_version, Some(&loader), &already_installed)
+            .await?;
+
+    for dep_version in deps {
+        let dep_file = match dep_version
+            .files
+            .iter()
+            .find(|f| f.primary)
+            .or_else(|| dep_version.files.first())
+        {
+            Some(f) => f,
+            None => continue,
+        };
+
+        let dep_dest_path = dest_dir.join(&dep_file.filename);
+        if dep_dest_path.exists() {
+            continue;
+        }
+
+        install_dependency_file(&client, dep_file, &dep_dest_path).await?;
+
+        let metadata = super::mod_updates::ModMetadata {
+            source: "modrinth".to_string(),
+            project_slug: dep_version.project_id.clone(),
+            project_id: dep_version.project_id.clone(),
+            installed_version: dep_version.version_number.clone(),
+            version_id: dep_version.id.clone(),
+            installed_at: chrono::Utc::now().to_rfc3339(),
+            repo_base: None,
+            asset_pattern: None,
+            loader_fallback: None,
+            loader: loader.clone(),
+        };
+        super::mod_updates::update_mod_metadata(
+            &game_version,
+            &profile_id,
+            &dep_file.filename,
+            metadata,
+        )?;
+
+        files_added.push(dep_file.filename.clone());
+    }
+
+    Ok(files_added)
+}
+
+/// Download an already-resolved dependency's file into `dest_path`, applying
+/// the same size/hash verification as the primary download.
+async fn install_dependency_file(
+    client: &Client,
+    file: &ModrinthFile,
+    dest_path: &std::path::Path,
+) -> Result<(), String> {
+    let mut download_request = client.get(&file.url);
+    if let Some(pat) = super::modrinth_auth::stored_token() {
+        download_request = download_request.header("Authorization", pat);
+    }
+
+    let response = download_request
+        .send()
+        .await
+        .map_err(|e| format!("Failed to download: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Download failed: HTTP {}", response.status()));
+    }
+
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| format!("Failed to read download: {}", e))?;
+
+    if bytes.len() < 1000 {
+        return Err("Downloaded file is too small".to_string());
+    }
+
+    verify_modrinth_hashes(&bytes, &file.hashes)
+        .map_err(|e| format!("{} failed verification: {}", file.filename, e))?;
+
+    tokio::fs::write(dest_path, bytes)
+        .await
+        .map_err(|e| format!("Failed to write file: {}", e))?;
+
+    tracing::info!("Downloaded dependency {} successfully", file.filename);
+    Ok(())
 }
@@ -0,0 +1,126 @@
+//! Authenticated Modrinth account integration: stores a user's personal
+//! access token and sends it as an `Authorization` header on Modrinth
+//! search and download requests instead of racing anonymous rate limits.
+//! No command here lists the user's follows, drafts or own projects.
+
+use serde::{Deserialize, Serialize};
+
+use super::modrinth::{create_client, MODRINTH_API_BASE};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModrinthUser {
+    pub id: String,
+    pub username: String,
+    pub email: Option<String>,
+    pub avatar_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct StoredModrinthToken {
+    pat: Option<String>,
+}
+
+/// Persists the user's Modrinth PAT to disk, alongside (but separate from)
+/// `auth::TokenStore`'s Microsoft account tokens.
+struct ModrinthTokenStore {
+    path: std::path::PathBuf,
+}
+
+impl ModrinthTokenStore {
+    fn new() -> Self {
+        let path = dirs::data_dir()
+            .unwrap_or_else(|| std::path::PathBuf::from("."))
+            .join("MiracleClient")
+            .join("auth")
+            .join("modrinth.json");
+
+        Self { path }
+    }
+
+    fn load(&self) -> StoredModrinthToken {
+        std::fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self, token: &StoredModrinthToken) -> Result<(), String> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create auth directory: {}", e))?;
+        }
+
+        let contents = serde_json::to_string_pretty(token)
+            .map_err(|e| format!("Failed to serialize Modrinth token: {}", e))?;
+
+        std::fs::write(&self.path, contents)
+            .map_err(|e| format!("Failed to write Modrinth token: {}", e))
+    }
+
+    fn get(&self) -> Option<String> {
+        self.load().pat
+    }
+
+    fn set(&self, pat: String) -> Result<(), String> {
+        self.save(&StoredModrinthToken { pat: Some(pat) })
+    }
+
+    fn clear(&self) -> Result<(), String> {
+        self.save(&StoredModrinthToken { pat: None })
+    }
+}
+
+/// Read the stored Modrinth PAT, if any, for `modrinth::search_modrinth` and
+/// `download_modrinth_content` to send as an `Authorization` header.
+pub(crate) fn stored_token() -> Option<String> {
+    ModrinthTokenStore::new().get()
+}
+
+async fn fetch_user(client: &reqwest::Client, pat: &str) -> Result<ModrinthUser, String> {
+    let response = client
+        .get(format!("{}/user", MODRINTH_API_BASE))
+        .header("Authorization", pat)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach Modrinth: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "Modrinth rejected this token: HTTP {}",
+            response.status()
+        ));
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Modrinth user: {}", e))
+}
+
+/// Validate and store a Modrinth personal access token, returning the
+/// account it belongs to.
+#[tauri::command]
+pub async fn login(pat: String) -> Result<ModrinthUser, String> {
+    let client = create_client()?;
+    let user = fetch_user(&client, &pat).await?;
+    ModrinthTokenStore::new().set(pat)?;
+    Ok(user)
+}
+
+/// Forget the stored Modrinth token.
+#[tauri::command]
+pub async fn logout() -> Result<(), String> {
+    ModrinthTokenStore::new().clear()
+}
+
+/// Get the currently logged-in Modrinth account, if a token is stored and
+/// still valid.
+#[tauri::command]
+pub async fn get_user() -> Result<Option<ModrinthUser>, String> {
+    let Some(pat) = ModrinthTokenStore::new().get() else {
+        return Ok(None);
+    };
+
+    let client = create_client()?;
+    Ok(fetch_user(&client, &pat).await.ok())
+}
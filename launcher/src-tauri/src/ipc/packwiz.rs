@@ -0,0 +1,533 @@
+//! packwiz (https://packwiz.infra.link) `pack.toml`/`index.toml` import and
+//! export. Unlike `.mrpack`/CurseForge's single-lockfile approach, packwiz
+//! spreads each mod into its own small `.pw.toml` metafile so a pack can be
+//! committed to git and diffed file-by-file, mirroring the `interop/packwiz`
+//! module other launchers ship.
+
+use serde::{Deserialize, Serialize};
+use sha1::{Digest as _, Sha1};
+use sha2::{Sha256, Sha512};
+use std::path::PathBuf;
+
+use crate::profiles::ProfileManager;
+
+use super::manifest::load_lockfile;
+use super::modrinth::{self, MODRINTH_API_BASE};
+
+fn create_client() -> Result<reqwest::Client, String> {
+    reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(120))
+        .user_agent("MiracleClient/1.0 (https://github.com/miracle-client)")
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PackToml {
+    name: String,
+    #[serde(default)]
+    author: Option<String>,
+    #[serde(default)]
+    version: Option<String>,
+    #[serde(rename = "pack-format")]
+    pack_format: String,
+    index: PackIndexRef,
+    #[serde(default)]
+    versions: std::collections::HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PackIndexRef {
+    file: String,
+    #[serde(rename = "hash-format")]
+    hash_format: String,
+    hash: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct IndexToml {
+    #[serde(rename = "hash-format")]
+    hash_format: String,
+    #[serde(default)]
+    files: Vec<IndexFileEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IndexFileEntry {
+    file: String,
+    hash: String,
+    #[serde(default)]
+    metafile: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ModToml {
+    name: String,
+    filename: String,
+    #[serde(default)]
+    side: Option<String>,
+    #[serde(default)]
+    download: Option<ModDownload>,
+    #[serde(default)]
+    update: Option<ModUpdate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ModDownload {
+    url: String,
+    #[serde(rename = "hash-format")]
+    hash_format: String,
+    hash: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct ModUpdate {
+    #[serde(default)]
+    modrinth: Option<ModUpdateModrinth>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ModUpdateModrinth {
+    #[serde(rename = "mod-id")]
+    mod_id: String,
+    version: String,
+}
+
+/// Verify `bytes` against a packwiz-style `hash-format`/`hash` pair. packwiz
+/// packs mostly use `sha256`, but `sha1`/`sha512` show up too since the
+/// format just stores whatever the source host published.
+fn verify_packwiz_hash(bytes: &[u8], hash_format: &str, expected: &str) -> Result<(), String> {
+    let actual = match hash_format {
+        "sha256" => {
+            let mut hasher = Sha256::new();
+            hasher.update(bytes);
+            format!("{:x}", hasher.finalize())
+        }
+        "sha512" => {
+            let mut hasher = Sha512::new();
+            hasher.update(bytes);
+            format!("{:x}", hasher.finalize())
+        }
+        "sha1" => {
+            let mut hasher = Sha1::new();
+            hasher.update(bytes);
+            format!("{:x}", hasher.finalize())
+        }
+        other => return Err(format!("Unsupported hash format: {}", other)),
+    };
+
+    if actual.eq_ignore_ascii_case(expected) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Hash mismatch: expected {}={}, got {}",
+            hash_format, expected, actual
+        ))
+    }
+}
+
+/// Join a path relative to a pack's base URL - `pack.toml`/`index.toml`
+/// entries are always same-host relative paths, never `../`, so plain
+/// last-segment replacement is enough (no need to pull in the `url` crate).
+fn join_relative(base_url: &str, relative: &str) -> String {
+    let base = match base_url.rfind('/') {
+        Some(idx) => &base_url[..idx],
+        None => base_url,
+    };
+    format!("{}/{}", base, relative.replace('\\', "/"))
+}
+
+async fn fetch_text(client: &reqwest::Client, url: &str) -> Result<String, String> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch {}: {}", url, e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Failed to fetch {}: HTTP {}", url, response.status()));
+    }
+
+    response
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", url, e))
+}
+
+/// Fetch a single Modrinth version by its ID, used to resolve a packwiz
+/// mod's `[update.modrinth]` block when it has no usable `[download]` URL.
+async fn fetch_modrinth_version(
+    client: &reqwest::Client,
+    version_id: &str,
+) -> Result<modrinth::ModrinthVersion, String> {
+    let url = format!("{}/version/{}", MODRINTH_API_BASE, version_id);
+    let response = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch Modrinth version {}: {}", version_id, e))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "Failed to fetch Modrinth version {}: HTTP {}",
+            version_id,
+            response.status()
+        ));
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Modrinth version {}: {}", version_id, e))
+}
+
+/// Install a modpack from a packwiz `pack.toml` URL (e.g. a raw GitHub link
+/// into a git-hosted pack repo).
+#[tauri::command]
+pub async fn install_packwiz_modpack(pack_url: String) -> Result<String, String> {
+    let client = create_client()?;
+
+    tracing::info!("Installing packwiz modpack from: {}", pack_url);
+
+    let pack_toml_text = fetch_text(&client, &pack_url).await?;
+    let pack: PackToml =
+        toml::from_str(&pack_toml_text).map_err(|e| format!("Failed to parse pack.toml: {}", e))?;
+
+    let index_url = join_relative(&pack_url, &pack.index.file);
+    let index_text = fetch_text(&client, &index_url).await?;
+    verify_packwiz_hash(index_text.as_bytes(), &pack.index.hash_format, &pack.index.hash)?;
+    let index: IndexToml =
+        toml::from_str(&index_text).map_err(|e| format!("Failed to parse index.toml: {}", e))?;
+
+    let game_version = pack
+        .versions
+        .get("minecraft")
+        .cloned()
+        .ok_or("pack.toml is missing a [versions] minecraft entry")?;
+
+    let mut profile_manager = ProfileManager::new();
+    let profile = profile_manager.create_modpack_profile(&pack.name, &game_version, "packwiz")?;
+    let profile_id = profile.id.clone();
+    let mods_dir = profile_manager.get_mods_dir(&game_version, &profile_id);
+
+    tokio::fs::create_dir_all(&mods_dir)
+        .await
+        .map_err(|e| format!("Failed to create mods directory: {}", e))?;
+
+    let mut installed: Vec<String> = Vec::new();
+    let mut failed: Vec<String> = Vec::new();
+
+    for entry in index.files.iter().filter(|f| f.metafile && f.file.starts_with("mods/")) {
+        let metafile_url = join_relative(&pack_url, &entry.file);
+        let metafile_text = match fetch_text(&client, &metafile_url).await {
+            Ok(text) => text,
+            Err(e) => {
+                tracing::warn!("Failed to fetch {}: {}", entry.file, e);
+                failed.push(entry.file.clone());
+                continue;
+            }
+        };
+
+        if let Err(e) = verify_packwiz_hash(
+            metafile_text.as_bytes(),
+            &index.hash_format,
+            &entry.hash,
+        ) {
+            tracing::warn!("Metafile integrity check failed for {}: {}", entry.file, e);
+            failed.push(entry.file.clone());
+            continue;
+        }
+
+        let mod_toml: ModToml = match toml::from_str(&metafile_text) {
+            Ok(m) => m,
+            Err(e) => {
+                tracing::warn!("Failed to parse {}: {}", entry.file, e);
+                failed.push(entry.file.clone());
+                continue;
+            }
+        };
+
+        if mod_toml.side.as_deref() == Some("server") {
+            continue;
+        }
+
+        let resolved = resolve_mod_download(&client, &mod_toml, &metafile_url).await;
+        match resolved {
+            Ok(bytes) => {
+                let dest_path = mods_dir.join(&mod_toml.filename);
+                if let Err(e) = tokio::fs::write(&dest_path, &bytes).await {
+                    tracing::warn!("Failed to write {}: {}", mod_toml.filename, e);
+                    failed.push(mod_toml.filename.clone());
+                } else {
+                    installed.push(mod_toml.filename.clone());
+                }
+            }
+            Err(e) => {
+                tracing::warn!("Failed to resolve {}: {}", mod_toml.filename, e);
+                failed.push(mod_toml.filename.clone());
+            }
+        }
+    }
+
+    tracing::info!(
+        "install_packwiz_modpack: {} installed, {} failed",
+        installed.len(),
+        failed.len()
+    );
+
+    Ok(profile_id)
+}
+
+/// Download a mod's bytes using its explicit `[download]` URL when present,
+/// falling back to resolving the `[update.modrinth]` version ID otherwise.
+async fn resolve_mod_download(
+    client: &reqwest::Client,
+    mod_toml: &ModToml,
+    metafile_url: &str,
+) -> Result<Vec<u8>, String> {
+    if let Some(download) = &mod_toml.download {
+        let url = if download.url.is_empty() {
+            None
+        } else {
+            Some(download.url.clone())
+        };
+
+        if let Some(url) = url {
+            let response = client
+                .get(&url)
+                .send()
+                .await
+                .map_err(|e| format!("Download failed: {}", e))?;
+            let bytes = response
+                .bytes()
+                .await
+                .map_err(|e| format!("Failed to read download: {}", e))?;
+            verify_packwiz_hash(&bytes, &download.hash_format, &download.hash)?;
+            return Ok(bytes.to_vec());
+        }
+    }
+
+    let modrinth_ref = mod_toml
+        .update
+        .as_ref()
+        .and_then(|u| u.modrinth.as_ref())
+        .ok_or_else(|| format!("{} has no usable download source", metafile_url))?;
+
+    let version = fetch_modrinth_version(client, &modrinth_ref.version).await?;
+    let file = version
+        .files
+        .iter()
+        .find(|f| f.primary)
+        .or_else(|| version.files.first())
+        .ok_or("No download file found on resolved Modrinth version")?;
+
+    let response = client
+        .get(&file.url)
+        .send()
+        .await
+        .map_err(|e| format!("Download failed: {}", e))?;
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| format!("Failed to read download: {}", e))?;
+    verify_packwiz_hash(&bytes, "sha512", &file.hashes.sha512)?;
+    Ok(bytes.to_vec())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackwizExportResult {
+    pub tracked: u32,
+    pub bundled: u32,
+}
+
+/// Export a profile's mods as a packwiz pack: one `<slug>.pw.toml` metafile
+/// per mod we can still trace back to a Modrinth version (via the profile's
+/// `miracle.lock.toml` lockfile), plus a bundled raw jar entry for anything
+/// the lockfile doesn't know about, same "link what we can, bundle the
+/// rest" split as `export_profile_as_mrpack`.
+#[tauri::command]
+pub async fn export_profile_to_packwiz(
+    profile_id: String,
+    out_dir: String,
+) -> Result<PackwizExportResult, String> {
+    let profile_manager = ProfileManager::new();
+    let profile = profile_manager
+        .get_profile(&profile_id)
+        .cloned()
+        .ok_or_else(|| format!("Profile {} not found", profile_id))?;
+
+    let mods_dir = profile_manager.get_mods_dir(&profile.version, &profile_id);
+    let lockfile = load_lockfile(&mods_dir)?;
+
+    let loader_version = crate::minecraft::MinecraftManager::new()
+        .get_fabric_loader(&profile.version)
+        .await
+        .map_err(|e| format!("Failed to resolve Fabric loader version: {}", e))?;
+
+    let client = create_client()?;
+
+    let out = PathBuf::from(&out_dir);
+    let mods_out = out.join("mods");
+    tokio::fs::create_dir_all(&mods_out)
+        .await
+        .map_err(|e| format!("Failed to create {}: {}", mods_out.display(), e))?;
+
+    let mut index_files: Vec<IndexFileEntry> = Vec::new();
+    let mut tracked = 0u32;
+    let mut bundled = 0u32;
+
+    let mut entries = tokio::fs::read_dir(&mods_dir)
+        .await
+        .map_err(|e| format!("Failed to read mods directory: {}", e))?;
+
+    while let Some(entry) = entries
+        .next_entry()
+        .await
+        .map_err(|e| format!("Failed to read mods directory: {}", e))?
+    {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("jar") {
+            continue;
+        }
+        let filename = entry.file_name().to_string_lossy().to_string();
+        let bytes = tokio::fs::read(&path)
+            .await
+            .map_err(|e| format!("Failed to read {}: {}", filename, e))?;
+
+        let locked_mod = lockfile
+            .as_ref()
+            .and_then(|l| l.mods.iter().find(|m| m.filename == filename));
+
+        match locked_mod.map(|m| m.version_id.clone()) {
+            Some(version_id) => {
+                let slug = locked_mod.map(|m| m.slug.clone()).unwrap_or_else(|| filename.clone());
+                let version = match fetch_modrinth_version(&client, &version_id).await {
+                    Ok(v) => v,
+                    Err(e) => {
+                        tracing::warn!(
+                            "Failed to resolve {} ({}) for packwiz export, bundling jar instead: {}",
+                            slug, version_id, e
+                        );
+                        bundle_raw_mod(&mods_out, &filename, &bytes, &mut index_files).await?;
+                        bundled += 1;
+                        continue;
+                    }
+                };
+                let file = version
+                    .files
+                    .iter()
+                    .find(|f| f.primary)
+                    .or_else(|| version.files.first());
+
+                let Some(file) = file else {
+                    bundle_raw_mod(&mods_out, &filename, &bytes, &mut index_files).await?;
+                    bundled += 1;
+                    continue;
+                };
+
+                let mod_toml = ModToml {
+                    name: slug.clone(),
+                    filename: filename.clone(),
+                    side: Some("client".to_string()),
+                    download: Some(ModDownload {
+                        url: file.url.clone(),
+                        hash_format: "sha512".to_string(),
+                        hash: file.hashes.sha512.clone(),
+                    }),
+                    update: Some(ModUpdate {
+                        modrinth: Some(ModUpdateModrinth {
+                            mod_id: version.project_id.clone(),
+                            version: version.id.clone(),
+                        }),
+                    }),
+                };
+
+                let mod_toml_text = toml::to_string_pretty(&mod_toml)
+                    .map_err(|e| format!("Failed to serialize {}.pw.toml: {}", slug, e))?;
+                let mod_toml_path = mods_out.join(format!("{}.pw.toml", slug));
+                tokio::fs::write(&mod_toml_path, &mod_toml_text)
+                    .await
+                    .map_err(|e| format!("Failed to write {}.pw.toml: {}", slug, e))?;
+
+                index_files.push(IndexFileEntry {
+                    file: format!("mods/{}.pw.toml", slug),
+                    hash: sha256_hex(mod_toml_text.as_bytes()),
+                    metafile: true,
+                });
+                tracked += 1;
+            }
+            None => {
+                bundle_raw_mod(&mods_out, &filename, &bytes, &mut index_files).await?;
+                bundled += 1;
+            }
+        }
+    }
+
+    let index = IndexToml {
+        hash_format: "sha256".to_string(),
+        files: index_files,
+    };
+    let index_text = toml::to_string_pretty(&index)
+        .map_err(|e| format!("Failed to serialize index.toml: {}", e))?;
+    tokio::fs::write(out.join("index.toml"), &index_text)
+        .await
+        .map_err(|e| format!("Failed to write index.toml: {}", e))?;
+
+    let mut versions = std::collections::HashMap::new();
+    versions.insert("minecraft".to_string(), profile.version.clone());
+    versions.insert("fabric".to_string(), loader_version);
+
+    let pack = PackToml {
+        name: profile.name.clone(),
+        author: None,
+        version: Some(chrono::Utc::now().format("%Y.%m.%d").to_string()),
+        pack_format: "packwiz:1.1.0".to_string(),
+        index: PackIndexRef {
+            file: "index.toml".to_string(),
+            hash_format: "sha256".to_string(),
+            hash: sha256_hex(index_text.as_bytes()),
+        },
+        versions,
+    };
+    let pack_text = toml::to_string_pretty(&pack)
+        .map_err(|e| format!("Failed to serialize pack.toml: {}", e))?;
+    tokio::fs::write(out.join("pack.toml"), &pack_text)
+        .await
+        .map_err(|e| format!("Failed to write pack.toml: {}", e))?;
+
+    tracing::info!(
+        "export_profile_to_packwiz: {} tracked, {} bundled",
+        tracked,
+        bundled
+    );
+
+    Ok(PackwizExportResult { tracked, bundled })
+}
+
+/// Bundle a mod jar we have no Modrinth version to trace back to: written
+/// directly under `mods/` with a plain (non-metafile) index entry, since
+/// there's nothing to resolve on a future install.
+async fn bundle_raw_mod(
+    mods_out: &std::path::Path,
+    filename: &str,
+    bytes: &[u8],
+    index_files: &mut Vec<IndexFileEntry>,
+) -> Result<(), String> {
+    tokio::fs::write(mods_out.join(filename), bytes)
+        .await
+        .map_err(|e| format!("Failed to write {}: {}", filename, e))?;
+
+    index_files.push(IndexFileEntry {
+        file: format!("mods/{}", filename),
+        hash: sha256_hex(bytes),
+        metafile: false,
+    });
+
+    Ok(())
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    format!("{:x}", hasher.finalize())
+}
@@ -0,0 +1,104 @@
+use rand::Rng;
+use reqwest::{RequestBuilder, Response, StatusCode};
+use std::time::Duration;
+
+/// Shared HTTP retry policy for the CurseForge/Modrinth APIs, which are
+/// intermittently flaky, so a single transient failure doesn't abort
+/// dependency resolution or a launch.
+pub struct RetryConfig {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(8),
+        }
+    }
+}
+
+/// Send a request, retrying retryable outcomes (HTTP 429/5xx, connection
+/// reset/timeout) with exponential backoff + jitter, honoring `Retry-After`
+/// when present. 404s, other 4xx statuses, and non-retryable errors (e.g.
+/// auth failures) return immediately on the first attempt.
+///
+/// `build_request` is called fresh on every attempt since `RequestBuilder`
+/// can't be cloned or reused after `send()`.
+pub async fn send_with_retry(
+    build_request: impl Fn() -> RequestBuilder,
+    config: &RetryConfig,
+) -> Result<Response, String> {
+    let mut last_error = String::new();
+
+    for attempt in 0..config.max_attempts {
+        match build_request().send().await {
+            Ok(response) => {
+                let status = response.status();
+                if status.is_success() {
+                    return Ok(response);
+                }
+                if !is_retryable_status(status) {
+                    return Err(format!("HTTP {}", status));
+                }
+
+                let retry_after = response
+                    .headers()
+                    .get("Retry-After")
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .map(Duration::from_secs);
+
+                last_error = format!("HTTP {}", status);
+                if attempt + 1 < config.max_attempts {
+                    sleep_with_backoff(attempt, config, retry_after).await;
+                }
+            }
+            Err(e) => {
+                if !is_retryable_error(&e) {
+                    return Err(e.to_string());
+                }
+                last_error = e.to_string();
+                if attempt + 1 < config.max_attempts {
+                    sleep_with_backoff(attempt, config, None).await;
+                }
+            }
+        }
+    }
+
+    Err(format!(
+        "Exceeded retry budget after {} attempts: {}",
+        config.max_attempts, last_error
+    ))
+}
+
+fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+fn is_retryable_error(error: &reqwest::Error) -> bool {
+    error.is_timeout() || error.is_connect() || (error.is_request() && !error.is_builder())
+}
+
+async fn sleep_with_backoff(attempt: u32, config: &RetryConfig, retry_after: Option<Duration>) {
+    let backoff = retry_after.unwrap_or_else(|| {
+        config
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(config.max_delay)
+    });
+
+    let jitter_ms = rand::thread_rng().gen_range(0..=config.base_delay.as_millis() as u64);
+    let delay = backoff + Duration::from_millis(jitter_ms);
+
+    tracing::warn!(
+        "Retrying request in {:?} (attempt {}/{})",
+        delay,
+        attempt + 1,
+        config.max_attempts
+    );
+    tokio::time::sleep(delay).await;
+}
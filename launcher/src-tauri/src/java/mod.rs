@@ -0,0 +1,490 @@
+use reqwest::Client;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+use thiserror::Error;
+use tokio::fs;
+
+/// Adoptium's "assets" API, which (unlike `/binary/latest`) returns JSON
+/// metadata we can check a checksum against before extracting anything.
+const ADOPTIUM_ASSETS_URL: &str = "https://api.adoptium.net/v3/assets/latest";
+
+#[derive(Error, Debug)]
+pub enum JavaError {
+    #[error("HTTP request failed: {0}")]
+    HttpError(#[from] reqwest::Error),
+    #[error("I/O error: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("No Temurin {major} runtime available for this platform")]
+    NoRuntimeAvailable { major: u32 },
+    #[error("Hash mismatch for {url}: expected {expected}, got {actual}")]
+    HashMismatch {
+        url: String,
+        expected: String,
+        actual: String,
+    },
+    #[error("Failed to extract runtime archive: {0}")]
+    ExtractionFailed(String),
+    #[error("{0} is not a valid Java executable")]
+    InvalidJavaExecutable(String),
+}
+
+/// A Java runtime discovered on disk (system install, `JAVA_HOME`, PATH, or
+/// one of our own bundled downloads under `MiracleClient/java/<major>/`).
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct JreInfo {
+    pub path: String,
+    pub major: u32,
+}
+
+/// Maps a Minecraft version to the Java major version it requires, mirroring
+/// `get_mod_version_for_minecraft`'s table of Minecraft version -> mod build.
+fn required_major_for_minecraft(mc_version: &str) -> u32 {
+    let mut parts = mc_version.split('.').skip(1);
+    let minor: u32 = parts.next().and_then(|s| s.parse().ok()).unwrap_or(21);
+
+    match minor {
+        0..=16 => 8,
+        17..=19 => 17,
+        _ => 21,
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct AdoptiumAsset {
+    binary: AdoptiumBinary,
+}
+
+#[derive(Debug, Deserialize)]
+struct AdoptiumBinary {
+    package: AdoptiumPackage,
+}
+
+#[derive(Debug, Deserialize)]
+struct AdoptiumPackage {
+    link: String,
+    checksum: String,
+    name: String,
+}
+
+pub struct JavaManager {
+    client: Client,
+    java_dir: PathBuf,
+}
+
+impl JavaManager {
+    pub fn new() -> Self {
+        let java_dir = dirs::data_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("MiracleClient")
+            .join("java");
+
+        Self {
+            client: Client::builder()
+                .user_agent("MiracleClient/1.0")
+                .build()
+                .unwrap_or_else(|_| Client::new()),
+            java_dir,
+        }
+    }
+
+    /// Resolve a Java executable for `mc_version`: an already-installed JRE
+    /// matching the required major version if one can be found (a bundled
+    /// download we already fetched, or one on the system), otherwise a
+    /// freshly downloaded Temurin runtime.
+    ///
+    /// `required_major` should be the version manifest's own `javaVersion`
+    /// field when the caller has it (see `MinecraftManager::required_java_major`) -
+    /// it's the authoritative answer. Pass `None` to fall back to our
+    /// Minecraft-version-based guess, for old versions that predate Mojang
+    /// recording this.
+    pub async fn resolve_for<F>(
+        &self,
+        mc_version: &str,
+        required_major: Option<u32>,
+        progress_callback: F,
+    ) -> Result<PathBuf, JavaError>
+    where
+        F: Fn(u64, u64, &str) + Send + Sync,
+    {
+        let major = required_major.unwrap_or_else(|| required_major_for_minecraft(mc_version));
+
+        if let Some(bundled) = self.find_bundled(major).await {
+            return Ok(bundled);
+        }
+
+        if let Some(system) = self.find_system_java(major).await {
+            return Ok(system);
+        }
+
+        self.download_runtime(major, progress_callback).await
+    }
+
+    /// Scan every place we know to look (bundled downloads, `JAVA_HOME`,
+    /// common platform install dirs, PATH) and report every distinct Java
+    /// executable found, with its major version, for the UI's JRE picker.
+    pub async fn get_all_jre(&self) -> Vec<JreInfo> {
+        let mut candidates = Vec::new();
+
+        for major in [8u32, 11, 17, 21] {
+            let bundled = self.java_home_bin(major);
+            if bundled.exists() {
+                candidates.push(bundled);
+            }
+        }
+
+        if let Ok(java_home) = std::env::var("JAVA_HOME") {
+            let bin = if cfg!(target_os = "windows") {
+                "java.exe"
+            } else {
+                "java"
+            };
+            candidates.push(PathBuf::from(java_home).join("bin").join(bin));
+        }
+
+        candidates.extend(Self::common_install_locations());
+
+        let which = if cfg!(target_os = "windows") {
+            "where"
+        } else {
+            "which"
+        };
+        if let Ok(output) = std::process::Command::new(which).arg("java").output() {
+            if output.status.success() {
+                if let Ok(text) = String::from_utf8(output.stdout) {
+                    if let Some(first) = text.lines().next() {
+                        candidates.push(PathBuf::from(first.trim()));
+                    }
+                }
+            }
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        let mut found = Vec::new();
+        for candidate in candidates {
+            if !candidate.exists() {
+                continue;
+            }
+            let Some(canonical) = candidate.canonicalize().ok().or(Some(candidate.clone())) else {
+                continue;
+            };
+            if !seen.insert(canonical.clone()) {
+                continue;
+            }
+            if let Some(major) = Self::java_major_version(&candidate) {
+                found.push(JreInfo {
+                    path: candidate.display().to_string(),
+                    major,
+                });
+            }
+        }
+
+        found
+    }
+
+    /// Find the best installed JRE for `mc_version` without downloading
+    /// anything: the required major if present, or (when `allow_higher`) the
+    /// closest newer major available.
+    pub async fn find_filtered_jre(&self, mc_version: &str, allow_higher: bool) -> Option<JreInfo> {
+        let required = required_major_for_minecraft(mc_version);
+        let mut candidates = self.get_all_jre().await;
+        candidates.retain(|jre| jre.major == required || (allow_higher && jre.major > required));
+        candidates.into_iter().min_by_key(|jre| jre.major)
+    }
+
+    /// Best-effort autodetection for `mc_version`: equivalent to
+    /// `find_filtered_jre` with `allow_higher` on, used by the UI to suggest
+    /// a default before the user picks one explicitly.
+    pub async fn autodetect_java(&self, mc_version: &str) -> Option<JreInfo> {
+        self.find_filtered_jre(mc_version, true).await
+    }
+
+    /// Validate a user-supplied Java path by actually invoking it, returning
+    /// the major version reported if it looks like a real `java` binary.
+    pub fn test_jre(path: &str) -> Result<u32, JavaError> {
+        Self::java_major_version(&PathBuf::from(path))
+            .ok_or_else(|| JavaError::InvalidJavaExecutable(path.to_string()))
+    }
+
+    /// Look for a runtime we already downloaded into `MiracleClient/java/<major>/`.
+    async fn find_bundled(&self, major: u32) -> Option<PathBuf> {
+        let java_bin = self.java_home_bin(major);
+        if java_bin.exists() {
+            return Some(java_bin);
+        }
+        None
+    }
+
+    fn java_home_bin(&self, major: u32) -> PathBuf {
+        let major_dir = self.java_dir.join(major.to_string());
+        if cfg!(target_os = "windows") {
+            major_dir.join("bin").join("java.exe")
+        } else {
+            major_dir.join("bin").join("java")
+        }
+    }
+
+    /// Scan `JAVA_HOME` and common platform install locations for a system
+    /// JRE/JDK whose `java -version` output reports the required major.
+    async fn find_system_java(&self, major: u32) -> Option<PathBuf> {
+        let mut candidates = Vec::new();
+
+        if let Ok(java_home) = std::env::var("JAVA_HOME") {
+            let bin = if cfg!(target_os = "windows") {
+                "java.exe"
+            } else {
+                "java"
+            };
+            candidates.push(PathBuf::from(java_home).join("bin").join(bin));
+        }
+
+        candidates.extend(Self::common_install_locations());
+
+        // Also try whatever `java` resolves to on PATH.
+        let which = if cfg!(target_os = "windows") {
+            "where"
+        } else {
+            "which"
+        };
+        if let Ok(output) = std::process::Command::new(which).arg("java").output() {
+            if output.status.success() {
+                if let Ok(text) = String::from_utf8(output.stdout) {
+                    if let Some(first) = text.lines().next() {
+                        candidates.push(PathBuf::from(first.trim()));
+                    }
+                }
+            }
+        }
+
+        for candidate in candidates {
+            if !candidate.exists() {
+                continue;
+            }
+            if Self::java_major_version(&candidate) == Some(major) {
+                return Some(candidate);
+            }
+        }
+
+        None
+    }
+
+    #[cfg(target_os = "windows")]
+    fn common_install_locations() -> Vec<PathBuf> {
+        let program_files =
+            std::env::var("ProgramFiles").unwrap_or_else(|_| "C:\\Program Files".to_string());
+        ["Eclipse Adoptium", "Java"]
+            .iter()
+            .flat_map(|vendor| {
+                let vendor_dir = PathBuf::from(&program_files).join(vendor);
+                std::fs::read_dir(&vendor_dir)
+                    .into_iter()
+                    .flatten()
+                    .flatten()
+                    .map(|entry| entry.path().join("bin").join("java.exe"))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    #[cfg(target_os = "macos")]
+    fn common_install_locations() -> Vec<PathBuf> {
+        std::fs::read_dir("/Library/Java/JavaVirtualMachines")
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path().join("Contents").join("Home").join("bin").join("java"))
+            .collect()
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    fn common_install_locations() -> Vec<PathBuf> {
+        std::fs::read_dir("/usr/lib/jvm")
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path().join("bin").join("java"))
+            .collect()
+    }
+
+    /// Run `java -version` and parse its major version out of stderr, e.g.
+    /// `openjdk version "21.0.1" ...` -> 21, or the legacy `"1.8.0_392"` -> 8.
+    fn java_major_version(java_bin: &PathBuf) -> Option<u32> {
+        let output = std::process::Command::new(java_bin)
+            .arg("-version")
+            .output()
+            .ok()?;
+        let text = String::from_utf8_lossy(&output.stderr);
+        let version_str = text.split('"').nth(1)?;
+
+        let mut segments = version_str.split('.');
+        let first: u32 = segments.next()?.parse().ok()?;
+        if first == 1 {
+            // Legacy "1.8.0_392" style version string.
+            segments.next()?.parse().ok()
+        } else {
+            Some(first)
+        }
+    }
+
+    fn platform_os_name() -> &'static str {
+        if cfg!(target_os = "windows") {
+            "windows"
+        } else if cfg!(target_os = "macos") {
+            "mac"
+        } else {
+            "linux"
+        }
+    }
+
+    async fn fetch_release(&self, major: u32) -> Result<AdoptiumAsset, JavaError> {
+        let url = format!(
+            "{}/{}/hotspot?architecture=x64&heap_size=normal&image_type=jre&os={}&vendor=eclipse",
+            ADOPTIUM_ASSETS_URL,
+            major,
+            Self::platform_os_name()
+        );
+
+        let releases: Vec<AdoptiumAsset> = self.client.get(&url).send().await?.json().await?;
+        releases
+            .into_iter()
+            .next()
+            .ok_or(JavaError::NoRuntimeAvailable { major })
+    }
+
+    async fn download_runtime<F>(
+        &self,
+        major: u32,
+        progress_callback: F,
+    ) -> Result<PathBuf, JavaError>
+    where
+        F: Fn(u64, u64, &str) + Send + Sync,
+    {
+        progress_callback(0, 100, "Resolving Java runtime...");
+        let release = self.fetch_release(major).await?;
+
+        progress_callback(10, 100, &format!("Downloading {}", release.binary.package.name));
+        let response = self.client.get(&release.binary.package.link).send().await?;
+        if !response.status().is_success() {
+            return Err(JavaError::NoRuntimeAvailable { major });
+        }
+        let bytes = response.bytes().await?;
+
+        let mut hasher = Sha256::new();
+        hasher.update(&bytes);
+        let actual = format!("{:x}", hasher.finalize());
+        if actual != release.binary.package.checksum {
+            return Err(JavaError::HashMismatch {
+                url: release.binary.package.link.clone(),
+                expected: release.binary.package.checksum.clone(),
+                actual,
+            });
+        }
+
+        progress_callback(60, 100, "Extracting Java runtime...");
+        let major_dir = self.java_dir.join(major.to_string());
+        fs::create_dir_all(&major_dir).await?;
+
+        let extract_dir = major_dir.clone();
+        let archive_name = release.binary.package.name.clone();
+        tokio::task::spawn_blocking(move || Self::extract_archive(&bytes, &archive_name, &extract_dir))
+            .await
+            .map_err(|e| JavaError::ExtractionFailed(e.to_string()))??;
+
+        progress_callback(100, 100, "Java runtime ready");
+
+        let java_bin = self.java_home_bin(major);
+        if !java_bin.exists() {
+            return Err(JavaError::ExtractionFailed(format!(
+                "Extracted archive did not contain {}",
+                java_bin.display()
+            )));
+        }
+
+        Ok(java_bin)
+    }
+
+    /// Extract a downloaded runtime archive so its top-level JDK/JRE folder's
+    /// contents land directly under `extract_dir` (i.e. `extract_dir/bin/java`),
+    /// regardless of the archive's own top-level folder name.
+    fn extract_archive(
+        bytes: &[u8],
+        archive_name: &str,
+        extract_dir: &PathBuf,
+    ) -> Result<(), JavaError> {
+        if archive_name.ends_with(".zip") {
+            let cursor = std::io::Cursor::new(bytes);
+            let mut archive = zip::ZipArchive::new(cursor)
+                .map_err(|e| JavaError::ExtractionFailed(e.to_string()))?;
+
+            for i in 0..archive.len() {
+                let mut entry = archive
+                    .by_index(i)
+                    .map_err(|e| JavaError::ExtractionFailed(e.to_string()))?;
+                let Some(relative) = strip_top_level_dir(entry.name()) else {
+                    continue;
+                };
+                if relative.is_empty() {
+                    continue;
+                }
+                let dest = extract_dir.join(relative);
+                if entry.is_dir() {
+                    std::fs::create_dir_all(&dest)?;
+                    continue;
+                }
+                if let Some(parent) = dest.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                let mut out = std::fs::File::create(&dest)?;
+                std::io::copy(&mut entry, &mut out)?;
+            }
+        } else {
+            let decoder = flate2::read::GzDecoder::new(bytes);
+            let mut archive = tar::Archive::new(decoder);
+
+            for entry in archive
+                .entries()
+                .map_err(|e| JavaError::ExtractionFailed(e.to_string()))?
+            {
+                let mut entry = entry.map_err(|e| JavaError::ExtractionFailed(e.to_string()))?;
+                let path = entry.path().map_err(|e| JavaError::ExtractionFailed(e.to_string()))?;
+                let Some(relative) = strip_top_level_dir(&path.to_string_lossy()) else {
+                    continue;
+                };
+                if relative.is_empty() {
+                    continue;
+                }
+                let dest = extract_dir.join(relative);
+                if let Some(parent) = dest.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                entry.unpack(&dest)?;
+            }
+
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                let bin = extract_dir.join("bin").join("java");
+                if bin.exists() {
+                    let _ = std::fs::set_permissions(&bin, std::fs::Permissions::from_mode(0o755));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Drop the archive's top-level folder (e.g. `jdk-21.0.1+12-jre/bin/java` ->
+/// `bin/java`) so extraction lands directly in the per-major directory.
+fn strip_top_level_dir(name: &str) -> Option<String> {
+    let name = name.replace('\\', "/");
+    let mut parts = name.splitn(2, '/');
+    parts.next()?;
+    parts.next().map(|rest| rest.to_string())
+}
+
+impl Default for JavaManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
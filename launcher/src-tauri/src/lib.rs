@@ -1,8 +1,11 @@
 mod auth;
 mod clips;
 mod ipc;
+mod java;
 mod minecraft;
+mod notifications;
 mod profiles;
+mod realtime;
 mod supabase;
 mod updater;
 
@@ -29,16 +32,34 @@ pub fn run() {
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_clipboard_manager::init())
         .manage(AppState::default())
+        .setup(|app| {
+            // Periodic background sweep that refreshes any stored account's
+            // token before it actually expires, so long-lived sessions stay
+            // usable across days instead of silently going stale between
+            // launches.
+            let handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                let mut interval = tokio::time::interval(std::time::Duration::from_secs(300));
+                loop {
+                    interval.tick().await;
+                    ipc::refresh_expiring_accounts(&handle).await;
+                }
+            });
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             // Auth commands
             ipc::auth_start_device_flow,
             ipc::auth_poll_device_flow,
             ipc::auth_logout,
             ipc::auth_refresh,
+            ipc::auth_get_valid_profile,
+            ipc::auth_start_pkce_flow,
             ipc::write_accounts_for_game,
             // Game commands
             ipc::get_minecraft_versions,
             ipc::launch_game,
+            ipc::cancel_launch,
             ipc::stop_game,
             // Update commands
             ipc::check_updates,
@@ -51,10 +72,18 @@ pub fn run() {
             ipc::delete_all_mod_folders,
             ipc::open_mods_folder,
             ipc::resolve_dependencies_for_version,
+            // Java runtime commands
+            ipc::get_all_jre,
+            ipc::autodetect_java,
+            ipc::find_filtered_jre,
+            ipc::test_jre,
             // Miracle Client update commands
             ipc::check_miracle_update,
+            ipc::check_for_launcher_update,
             ipc::download_miracle_update,
             ipc::is_update_service_configured,
+            ipc::get_supabase_config,
+            ipc::set_supabase_config,
             // Friends commands
             ipc::friends_register_user,
             ipc::friends_search_users,
@@ -62,13 +91,24 @@ pub fn run() {
             ipc::friends_send_request,
             ipc::friends_accept_request,
             ipc::friends_remove,
+            ipc::friends_block_user,
+            ipc::friends_unblock_user,
+            ipc::friends_get_blocked,
+            ipc::friends_get_mutual,
             ipc::friends_update_status,
+            ipc::start_friend_presence_stream,
+            ipc::start_friend_notifications,
+            ipc::get_notification_settings,
+            ipc::set_notification_settings,
             // Profile commands
             ipc::get_profiles,
+            ipc::get_profiles_by_group,
+            ipc::set_profile_groups,
             ipc::get_active_profile,
             ipc::set_active_profile,
             ipc::create_profile,
             ipc::create_preset_profile,
+            ipc::set_profile_java_config,
             ipc::delete_profile,
             ipc::duplicate_profile,
             ipc::export_profile,
@@ -76,30 +116,59 @@ pub fn run() {
             ipc::get_profile_mods_dir,
             ipc::get_performance_mods,
             ipc::ensure_performance_mods,
+            ipc::verify_profile,
+            ipc::verify_library_installation,
+            ipc::scan_profile_conflicts,
+            ipc::export_profile_mrpack,
+            ipc::import_profile_mrpack,
             // Content browser commands - Modrinth
             ipc::modrinth::search_modrinth,
             ipc::modrinth::get_modrinth_project,
             ipc::modrinth::get_modrinth_versions,
             ipc::modrinth::get_modrinth_categories,
             ipc::modrinth::download_modrinth_content,
+            ipc::modrinth::resolve_and_install,
             // Content browser commands - CurseForge
             ipc::curseforge::search_curseforge,
             ipc::curseforge::download_curseforge_content,
+            // Content browser commands - pluggable source abstraction
+            ipc::content_source::search_content,
+            // Modrinth account commands
+            ipc::modrinth_auth::login,
+            ipc::modrinth_auth::logout,
+            ipc::modrinth_auth::get_user,
             // Modpack commands
             ipc::modpack::install_modpack,
             ipc::modpack::get_modpack_info,
             ipc::modpack::preview_modpack_file,
             ipc::modpack::import_modpack_file,
+            ipc::modpack::install_mrpack,
             ipc::modpack::detect_installed_instances,
+            ipc::modpack::import_profile,
+            ipc::modpack::update_linked_profile,
+            ipc::modpack::export_profile_as_mrpack,
+            ipc::packwiz::install_packwiz_modpack,
+            ipc::packwiz::export_profile_to_packwiz,
+            ipc::manifest::install_from_manifest,
+            ipc::manifest::sync_profile,
+            // Log/crash-report commands
+            ipc::logs::get_logs_for_profile,
+            ipc::logs::get_log_by_filename,
+            ipc::logs::delete_log,
             // Mod update commands
             ipc::mod_updates::check_mod_updates,
+            ipc::mod_updates::check_modrinth_mod_updates,
             ipc::mod_updates::update_mod,
             ipc::mod_updates::update_all_mods,
             ipc::mod_updates::get_mod_metadata,
+            ipc::mod_updates::adopt_untracked_mods,
             // Profile sharing commands
             ipc::share_profile_online,
+            ipc::update_shared_profile_online,
+            ipc::check_profile_update,
             ipc::get_shared_profile,
             ipc::import_shared_profile,
+            ipc::verify_shared_profile_mods,
             // Theme sync commands
             ipc::get_mod_theme,
             // Clips commands
@@ -109,6 +178,7 @@ pub fn run() {
             clips::get_clips_directory,
             clips::open_clips_folder,
             clips::refresh_clips,
+            clips::process_clip_thumbnails,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -1,16 +1,82 @@
+use futures::stream::{self, StreamExt};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use sha1::{Digest, Sha1};
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::time::Duration;
 use thiserror::Error;
 use tokio::fs;
 
 const VERSION_MANIFEST_URL: &str =
     "https://launchermeta.mojang.com/mc/game/version_manifest_v2.json";
 const FABRIC_META_URL: &str = "https://meta.fabricmc.net/v2";
+const QUILT_META_URL: &str = "https://meta.quiltmc.org/v3";
 const RESOURCES_URL: &str = "https://resources.download.minecraft.net";
 
+/// How many [`DownloadJob`]s [`MinecraftManager::run_download_jobs`] drives
+/// at once.
+const DOWNLOAD_CONCURRENCY: usize = 10;
+const DOWNLOAD_MAX_ATTEMPTS: u32 = 4;
+const DOWNLOAD_BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// A single file to fetch as part of a bounded, retrying download batch -
+/// the client JAR, a library artifact/native, or an asset object.
+struct DownloadJob {
+    url: String,
+    /// The unmirrored origin URL, tried if `url` is a mirror and fails -
+    /// see [`mirrored_url`].
+    fallback_url: Option<String>,
+    path: PathBuf,
+    /// Expected sha1, when the manifest records one - verified after
+    /// download (and used to skip re-downloading an already-correct file).
+    expected_sha1: Option<String>,
+    label: String,
+}
+
+impl DownloadJob {
+    fn new(url: String, path: PathBuf, expected_sha1: Option<String>, label: String) -> Self {
+        Self { url, fallback_url: None, path, expected_sha1, label }
+    }
+
+    /// Same as [`Self::new`], but `url` is run through the configured
+    /// libraries mirror (if any), with `url` itself kept as the fallback.
+    fn new_mirrored(url: String, path: PathBuf, expected_sha1: Option<String>, label: String) -> Self {
+        match mirrored_url(&url, &libraries_mirror_base()) {
+            Some(mirror) => Self { url: mirror, fallback_url: Some(url), path, expected_sha1, label },
+            None => Self::new(url, path, expected_sha1, label),
+        }
+    }
+}
+
+fn sha1_hex(bytes: &[u8]) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(bytes);
+    format!("{:x}", hasher.finalize())
+}
+
+/// Base URL to rewrite Mojang metadata requests (version manifest, version
+/// JSON, Fabric/Quilt loader meta) through, for offline/LAN mirrors or
+/// private proxies. Unset by default.
+fn meta_mirror_base() -> Option<String> {
+    std::env::var("MIRACLE_META_MIRROR").ok().filter(|s| !s.is_empty())
+}
+
+/// Base URL to rewrite library/asset/Maven downloads through. Unset by
+/// default.
+fn libraries_mirror_base() -> Option<String> {
+    std::env::var("MIRACLE_LIBRARIES_MIRROR").ok().filter(|s| !s.is_empty())
+}
+
+/// Rewrite `url` to the same path on `mirror_base` instead, or return `None`
+/// if no mirror is configured (or `url` can't be parsed, in which case the
+/// caller should just use the original).
+fn mirrored_url(url: &str, mirror_base: &Option<String>) -> Option<String> {
+    let mirror = mirror_base.as_ref()?;
+    let parsed = reqwest::Url::parse(url).ok()?;
+    Some(format!("{}{}", mirror.trim_end_matches('/'), parsed.path()))
+}
+
 #[derive(Error, Debug)]
 pub enum MinecraftError {
     #[error("HTTP request failed: {0}")]
@@ -27,6 +93,91 @@ pub enum MinecraftError {
     LaunchFailed(String),
     #[error("Java not found")]
     JavaNotFound,
+    #[error("{0} is not supported yet")]
+    UnsupportedLoader(String),
+}
+
+/// One line of captured game stdout/stderr, for live streaming to the
+/// frontend via whatever event channel the caller is forwarding through.
+#[derive(Debug, Clone, Serialize)]
+pub struct GameLogLine {
+    pub stream: String, // "stdout" | "stderr"
+    pub line: String,
+}
+
+/// Where `launch` should persist captured stdout/stderr, and (optionally) a
+/// channel to also forward each line out live so the frontend can tail the
+/// game's output while it runs.
+pub struct LogCapture {
+    pub log_path: PathBuf,
+    pub line_tx: Option<tokio::sync::mpsc::UnboundedSender<GameLogLine>>,
+}
+
+/// Drain a launched game's stdout/stderr on background threads (the
+/// standard-library `Child` streams aren't async), appending every line to
+/// `capture.log_path` and forwarding it through `capture.line_tx` if set.
+fn spawn_log_capture(
+    capture: LogCapture,
+    stdout: Option<std::process::ChildStdout>,
+    stderr: Option<std::process::ChildStderr>,
+) {
+    if let Some(parent) = capture.log_path.parent() {
+        if let Err(e) = std::fs::create_dir_all(parent) {
+            tracing::warn!("Failed to create game log directory: {}", e);
+            return;
+        }
+    }
+
+    let log_file = match std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&capture.log_path)
+    {
+        Ok(file) => file,
+        Err(e) => {
+            tracing::warn!(
+                "Failed to open game log file {}: {}",
+                capture.log_path.display(),
+                e
+            );
+            return;
+        }
+    };
+    let writer = std::sync::Arc::new(std::sync::Mutex::new(std::io::BufWriter::new(log_file)));
+
+    let streams: [(&str, Option<Box<dyn std::io::Read + Send>>); 2] = [
+        (
+            "stdout",
+            stdout.map(|s| Box::new(s) as Box<dyn std::io::Read + Send>),
+        ),
+        (
+            "stderr",
+            stderr.map(|s| Box::new(s) as Box<dyn std::io::Read + Send>),
+        ),
+    ];
+
+    for (stream_name, reader) in streams {
+        let Some(reader) = reader else { continue };
+        let writer = writer.clone();
+        let tx = capture.line_tx.clone();
+
+        std::thread::spawn(move || {
+            use std::io::{BufRead, BufReader, Write};
+
+            for line in BufReader::new(reader).lines().map_while(Result::ok) {
+                if let Ok(mut w) = writer.lock() {
+                    let _ = writeln!(w, "{}", line);
+                    let _ = w.flush();
+                }
+                if let Some(tx) = &tx {
+                    let _ = tx.send(GameLogLine {
+                        stream: stream_name.to_string(),
+                        line,
+                    });
+                }
+            }
+        });
+    }
 }
 
 // ==================== Version Manifest ====================
@@ -65,6 +216,43 @@ struct VersionDetails {
     minecraft_arguments: Option<String>,
     #[serde(default)]
     arguments: Option<Arguments>,
+    #[serde(default)]
+    logging: Option<LoggingInfo>,
+    #[serde(rename = "javaVersion", default)]
+    java_version: Option<JavaVersionInfo>,
+}
+
+/// The version manifest's own record of which Java runtime it needs -
+/// absent on old versions that predate Mojang tracking this, in which case
+/// callers fall back to a Minecraft-version-based table instead.
+#[derive(Debug, Deserialize)]
+struct JavaVersionInfo {
+    component: String,
+    #[serde(rename = "majorVersion")]
+    major_version: u32,
+}
+
+/// The `logging` block of a version manifest - only ever has a `client`
+/// entry (there's no server-side equivalent relevant to this launcher).
+#[derive(Debug, Deserialize, Clone)]
+struct LoggingInfo {
+    client: Option<LoggingClient>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct LoggingClient {
+    /// JVM argument template, e.g. `-Dlog4j.configurationFile=${path}` -
+    /// `${path}` is substituted with the local path the config was
+    /// downloaded to.
+    argument: String,
+    file: LoggingFile,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct LoggingFile {
+    id: String,
+    sha1: String,
+    url: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -97,6 +285,17 @@ struct Library {
     rules: Option<Vec<Rule>>,
     #[serde(default)]
     natives: Option<HashMap<String, String>>,
+    #[serde(default)]
+    extract: Option<LibraryExtract>,
+}
+
+/// Archive paths to leave out when unpacking a native library's jar -
+/// Mojang always sets `exclude: ["META-INF/"]` on native libraries so we
+/// don't unpack their jar metadata alongside the actual natives.
+#[derive(Debug, Deserialize)]
+struct LibraryExtract {
+    #[serde(default)]
+    exclude: Vec<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -119,6 +318,10 @@ struct Rule {
     action: String,
     #[serde(default)]
     os: Option<OsRule>,
+    /// Only present on 1.13+ `arguments.game`/`arguments.jvm` entries, e.g.
+    /// `is_demo_user`/`has_custom_resolution` - not used by library rules.
+    #[serde(default)]
+    features: Option<HashMap<String, bool>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -152,6 +355,63 @@ struct AssetObject {
     size: u64,
 }
 
+// ==================== Mod loaders ====================
+
+/// Which mod loader a profile/pack targets. Fabric and Quilt expose
+/// near-identical meta APIs and profile-JSON shapes, so [`FabricProfile`]
+/// and the loader-install logic below serve both; Forge (and NeoForge)
+/// resolve their version from an installer jar instead and aren't
+/// supported yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoaderKind {
+    Fabric,
+    Quilt,
+    Forge,
+}
+
+impl LoaderKind {
+    /// Parse a profile's `loader` string (see `Profile.loader`). `"forge"` and
+    /// `"neoforge"` both map to [`Self::Forge`], since both need installer-jar
+    /// resolution, which this module does not implement. Anything else,
+    /// including `"fabric"` and unrecognized strings, maps to [`Self::Fabric`].
+    pub fn parse(loader: &str) -> Self {
+        match loader {
+            "quilt" => LoaderKind::Quilt,
+            "forge" | "neoforge" => LoaderKind::Forge,
+            _ => LoaderKind::Fabric,
+        }
+    }
+
+    /// Base URL for this loader's Fabric-Meta-shaped API, if it has one.
+    fn meta_base(self) -> &'static str {
+        match self {
+            LoaderKind::Fabric => FABRIC_META_URL,
+            LoaderKind::Quilt => QUILT_META_URL,
+            LoaderKind::Forge => "",
+        }
+    }
+
+    /// Default Maven base for libraries that don't carry their own `url`.
+    fn maven_base(self) -> &'static str {
+        match self {
+            LoaderKind::Fabric => "https://maven.fabricmc.net/",
+            LoaderKind::Quilt => "https://maven.quiltmc.org/repository/release/",
+            LoaderKind::Forge => "",
+        }
+    }
+
+    /// This loader's `versions/<id>/` directory name, matching the
+    /// convention each installer uses for its own profile JSON.
+    fn version_id(self, loader_version: &str, mc_version: &str) -> String {
+        let prefix = match self {
+            LoaderKind::Fabric => "fabric-loader",
+            LoaderKind::Quilt => "quilt-loader",
+            LoaderKind::Forge => "forge",
+        };
+        format!("{}-{}-{}", prefix, loader_version, mc_version)
+    }
+}
+
 // ==================== Fabric ====================
 
 #[derive(Debug, Deserialize)]
@@ -188,6 +448,228 @@ struct FabricArguments {
     jvm: Vec<String>,
 }
 
+// ==================== Installation integrity ====================
+
+/// Result of checking one classpath artifact against its expected hash -
+/// see [`MinecraftManager::verify_installation`].
+#[derive(Debug, Clone, Serialize)]
+pub struct IntegrityEntry {
+    pub label: String,
+    pub path: String,
+    pub status: IntegrityStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegrityStatus {
+    Ok,
+    Missing,
+    Corrupt,
+    /// Present on disk but there's no recorded hash to check it against -
+    /// loader libraries don't carry a sha1 in their profile, so this isn't
+    /// necessarily a problem.
+    Unverified,
+}
+
+/// Report returned by [`MinecraftManager::verify_installation`].
+#[derive(Debug, Default, Serialize)]
+pub struct IntegrityReport {
+    pub entries: Vec<IntegrityEntry>,
+    /// Labels of entries that were missing/corrupt and got re-downloaded
+    /// successfully (only populated when `repair` was requested).
+    pub repaired: Vec<String>,
+}
+
+impl IntegrityReport {
+    pub fn is_healthy(&self) -> bool {
+        self.entries
+            .iter()
+            .all(|e| matches!(e.status, IntegrityStatus::Ok | IntegrityStatus::Unverified))
+    }
+}
+
+// ==================== Platform detection ====================
+
+/// Mojang's `rules[].os.name`/native-map key for the running OS - `"osx"`
+/// rather than Rust's own `"macos"`, everything else matches
+/// `std::env::consts::OS` as-is.
+fn mojang_os_key() -> &'static str {
+    match std::env::consts::OS {
+        "macos" => "osx",
+        other => other,
+    }
+}
+
+/// Candidate keys to probe a legacy `natives` map with, in priority order.
+/// Mojang's own manifests only ever use `"osx"`, but some third-party ones
+/// spell it `"macos"` - try both there.
+fn native_map_os_keys() -> &'static [&'static str] {
+    match std::env::consts::OS {
+        "macos" => &["osx", "macos"],
+        "windows" => &["windows"],
+        _ => &["linux"],
+    }
+}
+
+/// Does a library `Rule`'s `os.arch` (Mojang only ever uses the legacy
+/// `"x86"` meaning 32-bit, or a literal Rust arch string) match the running
+/// process?
+fn arch_matches(rule_arch: &str) -> bool {
+    match rule_arch {
+        "x86" => cfg!(target_pointer_width = "32"),
+        other => other == std::env::consts::ARCH,
+    }
+}
+
+/// `${arch}` substitution value for old-style `natives` maps: Mojang only
+/// ever used `"32"`/`"64"` here, keyed off pointer width rather than the
+/// specific architecture.
+fn arch_bits() -> &'static str {
+    if cfg!(target_pointer_width = "64") {
+        "64"
+    } else {
+        "32"
+    }
+}
+
+fn is_windows() -> bool {
+    std::env::consts::OS == "windows"
+}
+
+/// Classpath entry separator: `;` on Windows, `:` everywhere else.
+fn classpath_separator() -> &'static str {
+    if is_windows() {
+        ";"
+    } else {
+        ":"
+    }
+}
+
+/// Shared allow/disallow evaluation for Mojang's `rules` arrays - used for
+/// both [`Library`] rules (os-only) and 1.13+ `arguments.game`/`jvm` entries
+/// (os and/or `features`). The last rule whose conditions match wins; if no
+/// rule matches (including an empty list), the default is "don't allow",
+/// matching the official launcher.
+fn rules_allow(rules: &[Rule], feature_flags: &HashMap<String, bool>) -> bool {
+    let mut allowed = None;
+
+    for rule in rules {
+        let os_matches = match &rule.os {
+            None => true,
+            Some(os_rule) => {
+                let name_matches = os_rule
+                    .name
+                    .as_deref()
+                    .is_none_or(|name| name == mojang_os_key());
+                let arch_ok = os_rule.arch.as_deref().is_none_or(arch_matches);
+                name_matches && arch_ok
+            }
+        };
+        let features_match = match &rule.features {
+            None => true,
+            Some(required) => required
+                .iter()
+                .all(|(key, &want)| feature_flags.get(key.as_str()).copied().unwrap_or(false) == want),
+        };
+
+        if os_matches && features_match {
+            allowed = Some(rule.action == "allow");
+        }
+    }
+
+    allowed.unwrap_or(false)
+}
+
+// ==================== Argument templating ====================
+
+/// Substitute every `${key}` placeholder in `template` using `values`.
+/// Placeholders without a matching entry are left as-is (Mojang's manifests
+/// never reference a key we don't populate).
+fn substitute_placeholders(template: &str, values: &HashMap<String, String>) -> String {
+    let mut result = template.to_string();
+    for (key, value) in values {
+        result = result.replace(&format!("${{{}}}", key), value);
+    }
+    result
+}
+
+/// Tokenize a legacy (pre-1.13) `minecraftArguments` string - its tokens are
+/// plain space-separated words, each substituted against `values`.
+fn tokenize_legacy_arguments(raw: &str, values: &HashMap<String, String>) -> Vec<String> {
+    raw.split_whitespace()
+        .map(|token| substitute_placeholders(token, values))
+        .collect()
+}
+
+/// Build one of the 1.13+ `arguments.game`/`arguments.jvm` arrays: each
+/// element is either a plain string, or `{ "rules": [...], "value": string |
+/// [string] }` gated by [`rules_allow`]. No optional features (demo mode,
+/// custom resolution, quick play, ...) are currently supported, so every
+/// feature-gated entry is evaluated against an empty flag set.
+fn build_templated_arguments(
+    entries: &[serde_json::Value],
+    values: &HashMap<String, String>,
+) -> Vec<String> {
+    let feature_flags = HashMap::new();
+    let mut out = Vec::new();
+
+    for entry in entries {
+        match entry {
+            serde_json::Value::String(s) => out.push(substitute_placeholders(s, values)),
+            serde_json::Value::Object(obj) => {
+                let rules: Vec<Rule> = obj
+                    .get("rules")
+                    .and_then(|r| serde_json::from_value(r.clone()).ok())
+                    .unwrap_or_default();
+                if !rules_allow(&rules, &feature_flags) {
+                    continue;
+                }
+
+                match obj.get("value") {
+                    Some(serde_json::Value::String(s)) => {
+                        out.push(substitute_placeholders(s, values));
+                    }
+                    Some(serde_json::Value::Array(items)) => {
+                        for item in items {
+                            if let Some(s) = item.as_str() {
+                                out.push(substitute_placeholders(s, values));
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    out
+}
+
+/// Build the JVM and game argument lists for launching `details`: 1.13+
+/// versions walk `arguments.jvm`/`arguments.game`, older versions tokenize
+/// the legacy `minecraftArguments` string as the game args (pre-1.13 never
+/// templated JVM args - the launcher supplied those directly).
+fn build_version_arguments(
+    details: &VersionDetails,
+    values: &HashMap<String, String>,
+) -> (Vec<String>, Vec<String>) {
+    match &details.arguments {
+        Some(arguments) => (
+            build_templated_arguments(&arguments.jvm, values),
+            build_templated_arguments(&arguments.game, values),
+        ),
+        None => {
+            let game_args = details
+                .minecraft_arguments
+                .as_deref()
+                .map(|raw| tokenize_legacy_arguments(raw, values))
+                .unwrap_or_default();
+            (Vec::new(), game_args)
+        }
+    }
+}
+
 // ==================== Manager ====================
 
 pub struct MinecraftManager {
@@ -218,13 +700,7 @@ impl MinecraftManager {
 
     pub async fn get_versions(&self) -> Result<Vec<GameVersion>, MinecraftError> {
         tracing::info!("Fetching version manifest...");
-        let manifest: VersionManifest = self
-            .client
-            .get(VERSION_MANIFEST_URL)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let manifest: VersionManifest = self.get_json_meta(VERSION_MANIFEST_URL).await?;
 
         let versions: Vec<GameVersion> = manifest
             .versions
@@ -238,13 +714,7 @@ impl MinecraftManager {
 
     async fn get_version_details(&self, version: &str) -> Result<VersionDetails, MinecraftError> {
         // First get the manifest to find the version URL
-        let manifest: VersionManifest = self
-            .client
-            .get(VERSION_MANIFEST_URL)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let manifest: VersionManifest = self.get_json_meta(VERSION_MANIFEST_URL).await?;
 
         let version_info = manifest
             .versions
@@ -253,17 +723,50 @@ impl MinecraftManager {
             .ok_or_else(|| MinecraftError::VersionNotFound(version.to_string()))?;
 
         // Download version details
-        let details: VersionDetails = self
-            .client
-            .get(&version_info.url)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let details: VersionDetails = self.get_json_meta(&version_info.url).await?;
 
         Ok(details)
     }
 
+    /// Fetch and parse JSON from `url`, trying the configured metadata
+    /// mirror first (see [`meta_mirror_base`]) and falling back to `url`
+    /// itself if the mirror request fails or none is configured.
+    async fn get_json_meta<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+    ) -> Result<T, MinecraftError> {
+        if let Some(mirror) = mirrored_url(url, &meta_mirror_base()) {
+            match self.client.get(&mirror).send().await {
+                Ok(response) => match response.error_for_status() {
+                    Ok(response) => return Ok(response.json().await?),
+                    Err(e) => {
+                        tracing::warn!(
+                            "Metadata mirror returned an error for {} ({}), falling back to origin: {}",
+                            url, mirror, e
+                        );
+                    }
+                },
+                Err(e) => {
+                    tracing::warn!(
+                        "Metadata mirror request failed for {} ({}), falling back to origin: {}",
+                        url, mirror, e
+                    );
+                }
+            }
+        }
+
+        Ok(self.client.get(url).send().await?.json().await?)
+    }
+
+    /// The Java major version `mc_version`'s own manifest says it needs
+    /// (e.g. 17 for `java-runtime-gamma`), if it records one - old versions
+    /// predate Mojang tracking this, so callers should fall back to their
+    /// own Minecraft-version-based guess when this is `None`.
+    pub async fn required_java_major(&self, mc_version: &str) -> Result<Option<u32>, MinecraftError> {
+        let details = self.get_version_details(mc_version).await?;
+        Ok(details.java_version.map(|v| v.major_version))
+    }
+
     // ==================== Download Minecraft ====================
 
     pub async fn download_minecraft<F>(
@@ -296,70 +799,67 @@ impl MinecraftManager {
         }))?;
         fs::write(&version_json_path, version_json).await?;
 
-        // Download client JAR
-        progress_callback(5, 100, "Downloading client...");
-        let client_path = versions_dir.join(format!("{}.jar", version));
-        self.download_file_verified(
-            &details.downloads.client.url,
-            &client_path,
-            &details.downloads.client.sha1,
-        )
-        .await?;
-        tracing::info!("Downloaded client JAR");
-
-        // Download libraries
-        let total_libs = details.libraries.len();
-        for (i, lib) in details.libraries.iter().enumerate() {
-            let progress = 10 + (i * 60 / total_libs) as u64;
+        // Collect the client JAR, every library artifact and every native
+        // classifier into one job list so they all share the same bounded,
+        // retrying, hash-verified download pool instead of downloading one
+        // at a time.
+        let mut jobs = vec![DownloadJob::new_mirrored(
+            details.downloads.client.url.clone(),
+            versions_dir.join(format!("{}.jar", version)),
+            Some(details.downloads.client.sha1.clone()),
+            "client.jar".to_string(),
+        )];
 
+        for lib in &details.libraries {
             if !self.should_use_library(lib) {
                 continue;
             }
 
-            if let Some(downloads) = &lib.downloads {
-                // Download main artifact
-                if let Some(artifact) = &downloads.artifact {
-                    let lib_path = libraries_dir.join(&artifact.path);
-                    if !lib_path.exists() {
-                        progress_callback(progress, 100, &format!("Downloading {}", lib.name));
-                        if let Some(parent) = lib_path.parent() {
-                            fs::create_dir_all(parent).await?;
-                        }
-                        self.download_file_verified(&artifact.url, &lib_path, &artifact.sha1)
-                            .await?;
-                    }
-                }
+            let Some(downloads) = &lib.downloads else {
+                continue;
+            };
+
+            if let Some(artifact) = &downloads.artifact {
+                jobs.push(DownloadJob::new_mirrored(
+                    artifact.url.clone(),
+                    libraries_dir.join(&artifact.path),
+                    Some(artifact.sha1.clone()),
+                    lib.name.clone(),
+                ));
+            }
 
-                // Download natives if needed
-                if let Some(natives) = &lib.natives {
-                    if let Some(native_key) = natives.get("windows") {
-                        if let Some(classifiers) = &downloads.classifiers {
-                            let native_key = native_key.replace("${arch}", "64");
-                            if let Some(native_artifact) = classifiers.get(&native_key) {
-                                let native_path = libraries_dir.join(&native_artifact.path);
-                                if !native_path.exists() {
-                                    progress_callback(
-                                        progress,
-                                        100,
-                                        &format!("Downloading native {}", lib.name),
-                                    );
-                                    if let Some(parent) = native_path.parent() {
-                                        fs::create_dir_all(parent).await?;
-                                    }
-                                    self.download_file_verified(
-                                        &native_artifact.url,
-                                        &native_path,
-                                        &native_artifact.sha1,
-                                    )
-                                    .await?;
-                                }
-                            }
+            if let Some(natives) = &lib.natives {
+                let native_key = native_map_os_keys()
+                    .iter()
+                    .find_map(|key| natives.get(*key))
+                    .map(|key| key.replace("${arch}", arch_bits()));
+
+                if let Some(native_key) = native_key {
+                    if let Some(classifiers) = &downloads.classifiers {
+                        if let Some(native_artifact) = classifiers.get(&native_key) {
+                            jobs.push(DownloadJob::new_mirrored(
+                                native_artifact.url.clone(),
+                                libraries_dir.join(&native_artifact.path),
+                                Some(native_artifact.sha1.clone()),
+                                format!("{} (native)", lib.name),
+                            ));
                         }
                     }
                 }
             }
         }
-        tracing::info!("Downloaded all libraries");
+
+        progress_callback(
+            5,
+            100,
+            &format!("Downloading client and {} libraries...", jobs.len() - 1),
+        );
+        self.run_required_download_jobs(jobs, &|done, total| {
+            let progress = 5 + (done * 65 / total.max(1));
+            progress_callback(progress, 100, "Downloading client and libraries...");
+        })
+        .await?;
+        tracing::info!("Downloaded client JAR and all libraries");
 
         // Download assets
         progress_callback(70, 100, "Downloading assets...");
@@ -369,6 +869,21 @@ impl MinecraftManager {
         })
         .await?;
 
+        // Download the version's log4j2 config, if it records one - used by
+        // `launch` to get clean, structured logs (and a patched config on
+        // versions vulnerable to Log4Shell) instead of raw stdout.
+        if let Some(logging) = &details.logging {
+            if let Some(client) = &logging.client {
+                progress_callback(96, 100, "Downloading logging configuration...");
+                let config_path = self.log_config_path(&client.file.id);
+                if let Some(parent) = config_path.parent() {
+                    fs::create_dir_all(parent).await?;
+                }
+                self.download_file_verified(&client.file.url, &config_path, &client.file.sha1)
+                    .await?;
+            }
+        }
+
         progress_callback(100, 100, "Download complete!");
         tracing::info!("Minecraft {} download complete", version);
 
@@ -376,23 +891,18 @@ impl MinecraftManager {
     }
 
     fn should_use_library(&self, lib: &Library) -> bool {
-        if let Some(rules) = &lib.rules {
-            let mut dominated = None;
-
-            for rule in rules {
-                let dominated_match = match &rule.os {
-                    None => true,
-                    Some(os_rule) => os_rule.name.as_deref() == Some("windows"),
-                };
-
-                if dominated_match {
-                    dominated = Some(rule.action == "allow");
-                }
-            }
-
-            return dominated.unwrap_or(false);
+        match &lib.rules {
+            Some(rules) => rules_allow(rules, &HashMap::new()),
+            None => true,
         }
-        true
+    }
+
+    /// Where a version's `logging.client.file` config lives once downloaded.
+    fn log_config_path(&self, config_id: &str) -> PathBuf {
+        self.game_dir
+            .join("assets")
+            .join("log_configs")
+            .join(config_id)
     }
 
     async fn download_assets<F>(
@@ -422,22 +932,33 @@ impl MinecraftManager {
         let index: AssetIndex = serde_json::from_str(&index_content)?;
 
         let total = index.objects.len() as u64;
-        let mut current = 0u64;
 
-        for (_name, object) in &index.objects {
-            let hash_prefix = &object.hash[..2];
-            let object_dir = objects_dir.join(hash_prefix);
-            let object_path = object_dir.join(&object.hash);
-
-            if !object_path.exists() {
-                fs::create_dir_all(&object_dir).await?;
-                let url = format!("{}/{}/{}", RESOURCES_URL, hash_prefix, object.hash);
-                self.download_file(&url, &object_path).await?;
-            }
+        // Asset object hashes are themselves a sha1, so they double as the
+        // verification target - same job pipeline as download_minecraft's
+        // libraries, just skipping anything already on disk up front so
+        // `current` starts from how much was already satisfied.
+        let jobs: Vec<DownloadJob> = index
+            .objects
+            .values()
+            .filter(|object| {
+                let hash_prefix = &object.hash[..2];
+                !objects_dir.join(hash_prefix).join(&object.hash).exists()
+            })
+            .map(|object| {
+                let hash_prefix = &object.hash[..2];
+                DownloadJob::new_mirrored(
+                    format!("{}/{}/{}", RESOURCES_URL, hash_prefix, object.hash),
+                    objects_dir.join(hash_prefix).join(&object.hash),
+                    Some(object.hash.clone()),
+                    object.hash.clone(),
+                )
+            })
+            .collect();
 
-            current += 1;
-            progress(current, total);
-        }
+        let already_present = total - jobs.len() as u64;
+        progress(already_present, total);
+        self.run_required_download_jobs(jobs, &|done, _| progress(already_present + done, total))
+            .await?;
 
         tracing::info!("Downloaded {} assets", total);
         Ok(())
@@ -446,17 +967,52 @@ impl MinecraftManager {
     // ==================== Fabric ====================
 
     pub async fn get_fabric_loader(&self, mc_version: &str) -> Result<String, MinecraftError> {
-        let url = format!("{}/versions/loader/{}", FABRIC_META_URL, mc_version);
-        let versions: Vec<FabricVersionInfo> = self.client.get(&url).send().await?.json().await?;
+        self.get_loader_version(LoaderKind::Fabric, mc_version).await
+    }
+
+    pub async fn download_fabric<F>(
+        &self,
+        mc_version: &str,
+        loader_version: &str,
+        progress_callback: F,
+    ) -> Result<(), MinecraftError>
+    where
+        F: Fn(u64, u64, &str),
+    {
+        self.download_loader(LoaderKind::Fabric, mc_version, loader_version, progress_callback)
+            .await
+    }
+
+    /// Fetch the latest loader version for `mc_version` from `kind`'s meta
+    /// API. Fabric and Quilt both expose a Fabric-Meta-shaped
+    /// `/versions/loader/{mc}` endpoint; Forge has no such API.
+    pub async fn get_loader_version(
+        &self,
+        kind: LoaderKind,
+        mc_version: &str,
+    ) -> Result<String, MinecraftError> {
+        if kind == LoaderKind::Forge {
+            return Err(MinecraftError::UnsupportedLoader(
+                "Forge loader resolution".to_string(),
+            ));
+        }
+
+        let url = format!("{}/versions/loader/{}", kind.meta_base(), mc_version);
+        let versions: Vec<FabricVersionInfo> = self.get_json_meta(&url).await?;
 
         versions
             .first()
             .map(|v| v.loader.version.clone())
-            .ok_or_else(|| MinecraftError::VersionNotFound("Fabric loader".to_string()))
+            .ok_or_else(|| MinecraftError::VersionNotFound(format!("{:?} loader", kind)))
     }
 
-    pub async fn download_fabric<F>(
+    /// Install `kind`'s loader profile and libraries for `mc_version`,
+    /// mirroring Mojang's own `versions/<id>/<id>.json` layout so `launch`
+    /// and `build_classpath` can read it back the same way regardless of
+    /// which loader it is.
+    pub async fn download_loader<F>(
         &self,
+        kind: LoaderKind,
         mc_version: &str,
         loader_version: &str,
         progress_callback: F,
@@ -464,53 +1020,62 @@ impl MinecraftManager {
     where
         F: Fn(u64, u64, &str),
     {
-        tracing::info!("Installing Fabric {} for MC {}", loader_version, mc_version);
-        progress_callback(0, 100, "Fetching Fabric profile...");
+        if kind == LoaderKind::Forge {
+            return Err(MinecraftError::UnsupportedLoader(
+                "Forge installation".to_string(),
+            ));
+        }
+
+        tracing::info!("Installing {:?} {} for MC {}", kind, loader_version, mc_version);
+        progress_callback(0, 100, "Fetching loader profile...");
 
-        // Download Fabric profile
+        // Download loader profile
         let profile_url = format!(
             "{}/versions/loader/{}/{}/profile/json",
-            FABRIC_META_URL, mc_version, loader_version
+            kind.meta_base(),
+            mc_version,
+            loader_version
         );
 
-        let profile: FabricProfile = self.client.get(&profile_url).send().await?.json().await?;
+        let profile: FabricProfile = self.get_json_meta(&profile_url).await?;
 
-        // Save Fabric profile
-        let fabric_id = format!("fabric-loader-{}-{}", loader_version, mc_version);
-        let fabric_dir = self.game_dir.join("versions").join(&fabric_id);
-        fs::create_dir_all(&fabric_dir).await?;
+        // Save loader profile
+        let loader_id = kind.version_id(loader_version, mc_version);
+        let loader_dir = self.game_dir.join("versions").join(&loader_id);
+        fs::create_dir_all(&loader_dir).await?;
 
-        let profile_path = fabric_dir.join(format!("{}.json", fabric_id));
+        let profile_path = loader_dir.join(format!("{}.json", loader_id));
         let profile_json = serde_json::to_string_pretty(&profile)?;
         fs::write(&profile_path, &profile_json).await?;
 
-        // Download Fabric libraries
+        // Download loader libraries through the same bounded/retrying job
+        // pool used for client/asset/vanilla-library downloads, rather than
+        // fetching them one at a time.
         let libraries_dir = self.game_dir.join("libraries");
-        let total = profile.libraries.len();
-
-        for (i, lib) in profile.libraries.iter().enumerate() {
-            let progress = ((i + 1) * 100 / total) as u64;
-            progress_callback(progress, 100, &format!("Installing {}", lib.name));
-
-            let lib_path = self.maven_to_path(&lib.name);
-            let full_path = libraries_dir.join(&lib_path);
-
-            if !full_path.exists() {
-                if let Some(parent) = full_path.parent() {
-                    fs::create_dir_all(parent).await?;
-                }
-
-                let base_url = lib.url.as_deref().unwrap_or("https://maven.fabricmc.net/");
-                let url = format!("{}{}", base_url, lib_path);
+        let jobs: Vec<DownloadJob> = profile
+            .libraries
+            .iter()
+            .map(|lib| {
+                let lib_path = self.maven_to_path(&lib.name);
+                let base_url = lib.url.as_deref().unwrap_or_else(|| kind.maven_base());
+                DownloadJob::new_mirrored(
+                    format!("{}{}", base_url, lib_path),
+                    libraries_dir.join(&lib_path),
+                    None,
+                    lib.name.clone(),
+                )
+            })
+            .collect();
 
-                if let Err(e) = self.download_file(&url, &full_path).await {
-                    tracing::warn!("Failed to download {}: {}", lib.name, e);
-                }
-            }
-        }
+        // Best-effort, same as before: a library that fails to download is
+        // logged and skipped rather than failing the whole install.
+        self.run_download_jobs(jobs, &|done, total| {
+            progress_callback(done * 100 / total.max(1), 100, "Installing loader libraries...");
+        })
+        .await;
 
-        progress_callback(100, 100, "Fabric installed!");
-        tracing::info!("Fabric installation complete");
+        progress_callback(100, 100, "Loader installed!");
+        tracing::info!("{:?} installation complete", kind);
 
         Ok(())
     }
@@ -536,40 +1101,55 @@ impl MinecraftManager {
     pub async fn launch(
         &self,
         mc_version: &str,
-        fabric_version: &str,
+        loader: LoaderKind,
+        loader_version: &str,
         access_token: &str,
         username: &str,
         uuid: &str,
         ram_mb: u32,
         show_logs: bool,
         profile_id: Option<&str>,
+        java_path: Option<&str>,
+        log_capture: Option<LogCapture>,
     ) -> Result<std::process::Child, MinecraftError> {
         tracing::info!(
-            "Launching Minecraft {} with Fabric {}",
+            "Launching Minecraft {} with {:?} {}",
             mc_version,
-            fabric_version
+            loader,
+            loader_version
         );
 
-        // Find Java
-        let java = self.find_java().await?;
+        // Needed to build the 1.13+ `arguments` struct (or the legacy
+        // `minecraftArguments` string) below.
+        let details = self.get_version_details(mc_version).await?;
+
+        // Use the resolved/user-chosen Java if given, otherwise fall back to
+        // our own best-effort system scan. Callers normally resolve (and, if
+        // needed, provision) a Java runtime themselves via `java::JavaManager`
+        // and always pass one in - this is just a fallback for callers that
+        // don't.
+        let java = match java_path {
+            Some(path) => path.to_string(),
+            None => self.find_java().await?,
+        };
         tracing::info!("Using Java: {}", java);
 
         // Build classpath
-        let classpath = self.build_classpath(mc_version, fabric_version).await?;
+        let classpath = self.build_classpath(mc_version, loader, loader_version).await?;
         tracing::info!("Classpath entries: {}", classpath.len());
 
-        // Get main class from Fabric profile
-        let fabric_id = format!("fabric-loader-{}-{}", fabric_version, mc_version);
-        let fabric_profile_path = self
+        // Get main class from the loader's profile
+        let loader_id = loader.version_id(loader_version, mc_version);
+        let loader_profile_path = self
             .game_dir
             .join("versions")
-            .join(&fabric_id)
-            .join(format!("{}.json", fabric_id));
+            .join(&loader_id)
+            .join(format!("{}.json", loader_id));
 
-        let fabric_profile: FabricProfile =
-            serde_json::from_str(&fs::read_to_string(&fabric_profile_path).await?)?;
+        let loader_profile: FabricProfile =
+            serde_json::from_str(&fs::read_to_string(&loader_profile_path).await?)?;
 
-        let main_class = fabric_profile.main_class;
+        let main_class = loader_profile.main_class;
 
         // Get asset index from version JSON
         let version_json_path = self
@@ -589,7 +1169,7 @@ impl MinecraftManager {
         self.extract_natives(mc_version, &natives_dir).await?;
 
         // Build arguments
-        let classpath_str = classpath.join(";"); // Windows uses ;
+        let classpath_str = classpath.join(classpath_separator());
 
         // Point Fabric to the profile-specific mods directory
         let mods_dir = match profile_id {
@@ -624,10 +1204,42 @@ impl MinecraftManager {
         let temp_dir = self.game_dir.join("temp");
         std::fs::create_dir_all(&temp_dir).ok();
 
-        let args: Vec<String> = vec![
+        // Placeholder values for every `${...}` token Mojang's legacy and
+        // 1.13+ argument templates reference.
+        let mut placeholders = HashMap::new();
+        placeholders.insert("auth_player_name".to_string(), username.to_string());
+        placeholders.insert("version_name".to_string(), loader_id.clone());
+        placeholders.insert("game_directory".to_string(), self.game_dir.display().to_string());
+        placeholders.insert(
+            "assets_root".to_string(),
+            self.game_dir.join("assets").display().to_string(),
+        );
+        placeholders.insert("assets_index_name".to_string(), asset_index.to_string());
+        placeholders.insert("auth_uuid".to_string(), uuid.replace("-", ""));
+        placeholders.insert("auth_access_token".to_string(), access_token.to_string());
+        placeholders.insert("user_type".to_string(), "msa".to_string());
+        placeholders.insert("version_type".to_string(), "release".to_string());
+        placeholders.insert("classpath".to_string(), classpath_str.clone());
+        placeholders.insert("natives_directory".to_string(), natives_dir.display().to_string());
+        placeholders.insert("launcher_name".to_string(), "MiracleClient".to_string());
+        placeholders.insert("launcher_version".to_string(), "1.0.0".to_string());
+
+        let logging_client = details.logging.as_ref().and_then(|l| l.client.as_ref());
+        if let Some(logging_client) = logging_client {
+            placeholders.insert(
+                "path".to_string(),
+                self.log_config_path(&logging_client.file.id)
+                    .display()
+                    .to_string(),
+            );
+        }
+
+        let (templated_jvm_args, templated_game_args) =
+            build_version_arguments(&details, &placeholders);
+
+        let mut args: Vec<String> = vec![
             format!("-Xmx{}M", ram_mb),
             format!("-Xms{}M", ram_mb / 2),
-            format!("-Djava.library.path={}", natives_dir.display()),
             format!("-Djava.io.tmpdir={}", self.game_dir.join("temp").display()),
             format!("-Dfabric.modsFolder={}", mods_dir.display()),
             format!(
@@ -639,31 +1251,30 @@ impl MinecraftManager {
             "-Dminecraft.launcher.brand=MiracleClient".to_string(),
             "-Dminecraft.launcher.version=1.0.0".to_string(),
             "-Dorg.lwjgl.opengl.Display.title=Miracle Client".to_string(),
-            "-cp".to_string(),
-            classpath_str,
-            main_class,
-            "--username".to_string(),
-            username.to_string(),
-            "--version".to_string(),
-            fabric_id.clone(),
-            "--gameDir".to_string(),
-            self.game_dir.display().to_string(),
-            "--assetsDir".to_string(),
-            self.game_dir.join("assets").display().to_string(),
-            "--assetIndex".to_string(),
-            asset_index.to_string(),
-            "--uuid".to_string(),
-            uuid.replace("-", ""),
-            "--accessToken".to_string(),
-            access_token.to_string(),
-            "--userType".to_string(),
-            "msa".to_string(),
-            "--versionType".to_string(),
-            "release".to_string(),
-            "--clientId".to_string(),
-            "Miracle Client".to_string(),
         ];
 
+        if templated_jvm_args.is_empty() {
+            // Pre-1.13 versions never templated JVM args - supply the two
+            // the launcher has always had to pass directly.
+            args.push(format!("-Djava.library.path={}", natives_dir.display()));
+            args.push("-cp".to_string());
+            args.push(classpath_str);
+        } else {
+            args.extend(templated_jvm_args);
+        }
+
+        if let Some(logging_client) = logging_client {
+            args.push(substitute_placeholders(
+                &logging_client.argument,
+                &placeholders,
+            ));
+        }
+
+        args.push(main_class);
+        args.extend(templated_game_args);
+        args.push("--clientId".to_string());
+        args.push("Miracle Client".to_string());
+
         tracing::info!("Launch command: {} {}", java, args.join(" "));
 
         let mut command = std::process::Command::new(&java);
@@ -683,40 +1294,79 @@ impl MinecraftManager {
             }
         }
 
-        let child = command
+        // Pipe stdout/stderr so we can capture them to a per-launch log file
+        // and stream live lines to the frontend. Skipped when `show_logs`
+        // opened its own console window above, since that already owns the
+        // process's standard streams.
+        let capture = log_capture.filter(|_| !show_logs);
+        if capture.is_some() {
+            command
+                .stdout(std::process::Stdio::piped())
+                .stderr(std::process::Stdio::piped());
+        }
+
+        let mut child = command
             .spawn()
             .map_err(|e| MinecraftError::LaunchFailed(e.to_string()))?;
 
         tracing::info!("Game launched with PID: {}", child.id());
 
+        if let Some(capture) = capture {
+            let stdout = child.stdout.take();
+            let stderr = child.stderr.take();
+            spawn_log_capture(capture, stdout, stderr);
+        }
+
         Ok(child)
     }
 
     async fn find_java(&self) -> Result<String, MinecraftError> {
+        let java_exe = if is_windows() { "java.exe" } else { "java" };
+
         // Check JAVA_HOME
         if let Ok(java_home) = std::env::var("JAVA_HOME") {
-            let java_path = PathBuf::from(&java_home).join("bin").join("java.exe");
+            let java_path = PathBuf::from(&java_home).join("bin").join(java_exe);
             if java_path.exists() {
                 return Ok(java_path.display().to_string());
             }
         }
 
-        // Check common locations on Windows
-        let program_files =
-            std::env::var("ProgramFiles").unwrap_or_else(|_| "C:\\Program Files".to_string());
-        let common_paths = [
-            format!(
-                "{}\\Eclipse Adoptium\\jdk-21.0.1.12-hotspot\\bin\\java.exe",
-                program_files
-            ),
-            format!("{}\\Java\\jdk-21\\bin\\java.exe", program_files),
-            format!("{}\\Java\\jre-21\\bin\\java.exe", program_files),
-            format!(
-                "{}\\Eclipse Adoptium\\jdk-17.0.9.9-hotspot\\bin\\java.exe",
-                program_files
-            ),
-            format!("{}\\Java\\jdk-17\\bin\\java.exe", program_files),
-        ];
+        // Check common per-OS install locations
+        let common_paths: Vec<String> = if is_windows() {
+            let program_files =
+                std::env::var("ProgramFiles").unwrap_or_else(|_| "C:\\Program Files".to_string());
+            vec![
+                format!(
+                    "{}\\Eclipse Adoptium\\jdk-21.0.1.12-hotspot\\bin\\java.exe",
+                    program_files
+                ),
+                format!("{}\\Java\\jdk-21\\bin\\java.exe", program_files),
+                format!("{}\\Java\\jre-21\\bin\\java.exe", program_files),
+                format!(
+                    "{}\\Eclipse Adoptium\\jdk-17.0.9.9-hotspot\\bin\\java.exe",
+                    program_files
+                ),
+                format!("{}\\Java\\jdk-17\\bin\\java.exe", program_files),
+            ]
+        } else if std::env::consts::OS == "macos" {
+            vec![
+                "/Library/Java/JavaVirtualMachines/temurin-21.jdk/Contents/Home/bin/java"
+                    .to_string(),
+                "/Library/Java/JavaVirtualMachines/temurin-17.jdk/Contents/Home/bin/java"
+                    .to_string(),
+                "/opt/homebrew/opt/openjdk@21/bin/java".to_string(),
+                "/opt/homebrew/opt/openjdk@17/bin/java".to_string(),
+                "/usr/local/opt/openjdk@21/bin/java".to_string(),
+                "/usr/local/opt/openjdk@17/bin/java".to_string(),
+            ]
+        } else {
+            vec![
+                "/usr/lib/jvm/java-21-openjdk/bin/java".to_string(),
+                "/usr/lib/jvm/java-21-openjdk-amd64/bin/java".to_string(),
+                "/usr/lib/jvm/java-17-openjdk/bin/java".to_string(),
+                "/usr/lib/jvm/java-17-openjdk-amd64/bin/java".to_string(),
+            ]
+        };
 
         for path in &common_paths {
             if PathBuf::from(path).exists() {
@@ -725,7 +1375,11 @@ impl MinecraftManager {
         }
 
         // Try java from PATH
-        if let Ok(output) = std::process::Command::new("where").arg("java").output() {
+        let lookup_command = if is_windows() { "where" } else { "which" };
+        if let Ok(output) = std::process::Command::new(lookup_command)
+            .arg("java")
+            .output()
+        {
             if output.status.success() {
                 if let Ok(path) = String::from_utf8(output.stdout) {
                     if let Some(first_line) = path.lines().next() {
@@ -741,7 +1395,8 @@ impl MinecraftManager {
     async fn build_classpath(
         &self,
         mc_version: &str,
-        fabric_version: &str,
+        loader: LoaderKind,
+        loader_version: &str,
     ) -> Result<Vec<String>, MinecraftError> {
         let mut classpath = Vec::new();
         let mut added_artifacts = std::collections::HashSet::new();
@@ -767,19 +1422,19 @@ impl MinecraftManager {
             classpath.push(client_jar.display().to_string());
         }
 
-        // Add Fabric libraries (these take priority)
-        let fabric_id = format!("fabric-loader-{}-{}", fabric_version, mc_version);
-        let fabric_profile_path = self
+        // Add the loader's libraries (these take priority)
+        let loader_id = loader.version_id(loader_version, mc_version);
+        let loader_profile_path = self
             .game_dir
             .join("versions")
-            .join(&fabric_id)
-            .join(format!("{}.json", fabric_id));
+            .join(&loader_id)
+            .join(format!("{}.json", loader_id));
 
-        if fabric_profile_path.exists() {
-            let fabric_profile: FabricProfile =
-                serde_json::from_str(&fs::read_to_string(&fabric_profile_path).await?)?;
+        if loader_profile_path.exists() {
+            let loader_profile: FabricProfile =
+                serde_json::from_str(&fs::read_to_string(&loader_profile_path).await?)?;
 
-            for lib in &fabric_profile.libraries {
+            for lib in &loader_profile.libraries {
                 let artifact_key = get_artifact_key(&lib.name);
                 if added_artifacts.insert(artifact_key) {
                     let lib_path = libraries_dir.join(self.maven_to_path(&lib.name));
@@ -790,7 +1445,7 @@ impl MinecraftManager {
             }
         }
 
-        // Add vanilla libraries (skip if artifact already added by Fabric)
+        // Add vanilla libraries (skip if artifact already added by the loader)
         let details = self.get_version_details(mc_version).await?;
         for lib in &details.libraries {
             if !self.should_use_library(lib) {
@@ -813,6 +1468,134 @@ impl MinecraftManager {
         Ok(classpath)
     }
 
+    /// Recompute SHA1 for every artifact [`Self::build_classpath`] would put
+    /// on the classpath - the client JAR and the vanilla/loader libraries -
+    /// against the hashes recorded in their manifests, reporting which are
+    /// missing or corrupt. Loader libraries don't carry a hash in their
+    /// profile, so those can only be confirmed present, not verified (see
+    /// [`IntegrityStatus::Unverified`]). When `repair` is true, anything
+    /// missing or corrupt is re-queued through [`Self::run_download_jobs`]
+    /// and the report's `repaired` list records what got fixed.
+    pub async fn verify_installation(
+        &self,
+        mc_version: &str,
+        loader: LoaderKind,
+        loader_version: &str,
+        repair: bool,
+    ) -> Result<IntegrityReport, MinecraftError> {
+        let libraries_dir = self.game_dir.join("libraries");
+
+        // (label, path, expected_sha1, url) for every artifact that should
+        // exist on disk for this version/loader combination.
+        let mut checks: Vec<(String, PathBuf, Option<String>, String)> = Vec::new();
+
+        let details = self.get_version_details(mc_version).await?;
+
+        checks.push((
+            "client.jar".to_string(),
+            self.game_dir
+                .join("versions")
+                .join(mc_version)
+                .join(format!("{}.jar", mc_version)),
+            Some(details.downloads.client.sha1.clone()),
+            details.downloads.client.url.clone(),
+        ));
+
+        for lib in &details.libraries {
+            if !self.should_use_library(lib) {
+                continue;
+            }
+            if let Some(artifact) = lib.downloads.as_ref().and_then(|d| d.artifact.as_ref()) {
+                checks.push((
+                    lib.name.clone(),
+                    libraries_dir.join(&artifact.path),
+                    Some(artifact.sha1.clone()),
+                    artifact.url.clone(),
+                ));
+            }
+        }
+
+        let loader_id = loader.version_id(loader_version, mc_version);
+        let loader_profile_path = self
+            .game_dir
+            .join("versions")
+            .join(&loader_id)
+            .join(format!("{}.json", loader_id));
+        if loader_profile_path.exists() {
+            let loader_profile: FabricProfile =
+                serde_json::from_str(&fs::read_to_string(&loader_profile_path).await?)?;
+            for lib in &loader_profile.libraries {
+                let lib_path = self.maven_to_path(&lib.name);
+                let base_url = lib.url.as_deref().unwrap_or_else(|| loader.maven_base());
+                checks.push((
+                    lib.name.clone(),
+                    libraries_dir.join(&lib_path),
+                    None,
+                    format!("{}{}", base_url, lib_path),
+                ));
+            }
+        }
+
+        let mut entries = Vec::with_capacity(checks.len());
+        let mut repair_jobs = Vec::new();
+        let mut repair_indices = Vec::new();
+
+        for (label, path, expected_sha1, url) in &checks {
+            let status = if !path.exists() {
+                IntegrityStatus::Missing
+            } else {
+                match expected_sha1 {
+                    None => IntegrityStatus::Unverified,
+                    Some(expected) => match fs::read(path).await {
+                        Ok(bytes) if sha1_hex(&bytes) == *expected => IntegrityStatus::Ok,
+                        _ => IntegrityStatus::Corrupt,
+                    },
+                }
+            };
+
+            if repair && matches!(status, IntegrityStatus::Missing | IntegrityStatus::Corrupt) {
+                repair_indices.push(entries.len());
+                repair_jobs.push(DownloadJob::new_mirrored(
+                    url.clone(),
+                    path.clone(),
+                    expected_sha1.clone(),
+                    label.clone(),
+                ));
+            }
+
+            entries.push(IntegrityEntry {
+                label: label.clone(),
+                path: path.display().to_string(),
+                status,
+            });
+        }
+
+        let mut report = IntegrityReport { entries, repaired: Vec::new() };
+
+        if !repair_jobs.is_empty() {
+            let has_hash: Vec<bool> = repair_jobs
+                .iter()
+                .map(|job| job.expected_sha1.is_some())
+                .collect();
+            let results = self.run_download_jobs(repair_jobs, &|_, _| {}).await;
+
+            for ((&entry_idx, verified), result) in
+                repair_indices.iter().zip(has_hash).zip(results)
+            {
+                if result.is_ok() {
+                    report.entries[entry_idx].status = if verified {
+                        IntegrityStatus::Ok
+                    } else {
+                        IntegrityStatus::Unverified
+                    };
+                    report.repaired.push(report.entries[entry_idx].label.clone());
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
     async fn extract_natives(
         &self,
         mc_version: &str,
@@ -820,26 +1603,30 @@ impl MinecraftManager {
     ) -> Result<(), MinecraftError> {
         let libraries_dir = self.game_dir.join("libraries");
 
-        // Helper function to extract DLLs from a JAR
-        let extract_jar = |jar_path: &PathBuf| -> Result<(), MinecraftError> {
+        // Unpack a native library's jar into `natives_dir`, skipping entries
+        // under any of its `extract.exclude` prefixes (Mojang always
+        // excludes `META-INF/`) and preserving each entry's relative path
+        // rather than flattening everything into one directory.
+        let extract_jar = |jar_path: &PathBuf, exclude: &[String]| -> Result<(), MinecraftError> {
             if let Ok(file) = std::fs::File::open(jar_path) {
                 if let Ok(mut archive) = zip::ZipArchive::new(file) {
                     for i in 0..archive.len() {
                         if let Ok(mut entry) = archive.by_index(i) {
                             let name = entry.name().to_string();
-                            // Extract all DLLs and so files
-                            if name.ends_with(".dll")
-                                || name.ends_with(".so")
-                                || name.ends_with(".dylib")
-                            {
-                                // Get just the filename (remove any directory structure)
-                                if let Some(filename) = name.split('/').last() {
-                                    let out_path = natives_dir.join(filename);
-                                    if let Ok(mut out_file) = std::fs::File::create(&out_path) {
-                                        let _ = std::io::copy(&mut entry, &mut out_file);
-                                        tracing::debug!("Extracted native: {}", filename);
-                                    }
-                                }
+                            if !(name.ends_with(".dll") || name.ends_with(".so") || name.ends_with(".dylib")) {
+                                continue;
+                            }
+                            if exclude.iter().any(|prefix| name.starts_with(prefix.as_str())) {
+                                continue;
+                            }
+
+                            let out_path = natives_dir.join(&name);
+                            if let Some(parent) = out_path.parent() {
+                                let _ = std::fs::create_dir_all(parent);
+                            }
+                            if let Ok(mut out_file) = std::fs::File::create(&out_path) {
+                                let _ = std::io::copy(&mut entry, &mut out_file);
+                                tracing::debug!("Extracted native: {}", name);
                             }
                         }
                     }
@@ -848,50 +1635,194 @@ impl MinecraftManager {
             Ok(())
         };
 
-        // Extract vanilla Minecraft natives
         let details = self.get_version_details(mc_version).await?;
         for lib in &details.libraries {
             if !self.should_use_library(lib) {
                 continue;
             }
+            let exclude = lib.extract.as_ref().map(|e| e.exclude.clone()).unwrap_or_default();
 
             if let Some(natives) = &lib.natives {
-                if let Some(native_key) = natives.get("windows") {
-                    if let Some(downloads) = &lib.downloads {
-                        if let Some(classifiers) = &downloads.classifiers {
-                            let native_key = native_key.replace("${arch}", "64");
-                            if let Some(native_artifact) = classifiers.get(&native_key) {
-                                let native_jar = libraries_dir.join(&native_artifact.path);
-                                if native_jar.exists() {
-                                    extract_jar(&native_jar)?;
-                                }
-                            }
-                        }
+                // Legacy (pre-1.19) style: the platform-specific jar is a
+                // classifier on the same library entry.
+                let Some(downloads) = &lib.downloads else { continue };
+                let Some(classifiers) = &downloads.classifiers else { continue };
+
+                let native_key = native_map_os_keys()
+                    .iter()
+                    .find_map(|key| natives.get(*key))
+                    .map(|key| key.replace("${arch}", arch_bits()));
+                let Some(native_key) = native_key else { continue };
+
+                if let Some(native_artifact) = classifiers.get(&native_key) {
+                    let native_jar = libraries_dir.join(&native_artifact.path);
+                    if native_jar.exists() {
+                        extract_jar(&native_jar, &exclude)?;
+                    }
+                }
+            } else if lib.name.contains(":natives-") {
+                // Modern (LWJGL3) style: natives live in their own library
+                // entry, already OS/arch-filtered by `should_use_library`'s
+                // rules, so there's nothing left to match on here.
+                if let Some(artifact) = lib.downloads.as_ref().and_then(|d| d.artifact.as_ref()) {
+                    let native_jar = libraries_dir.join(&artifact.path);
+                    if native_jar.exists() {
+                        extract_jar(&native_jar, &exclude)?;
                     }
                 }
             }
         }
 
-        // Also extract LWJGL natives (look for -natives-windows.jar files)
-        let lwjgl_dir = libraries_dir.join("org").join("lwjgl");
-        if lwjgl_dir.exists() {
-            for entry in walkdir::WalkDir::new(&lwjgl_dir).max_depth(5) {
-                if let Ok(entry) = entry {
-                    let path = entry.path();
-                    if path.is_file()
-                        && path.to_string_lossy().contains("-natives-windows")
-                        && path.extension().and_then(|s| s.to_str()) == Some("jar")
-                    {
-                        tracing::info!("Extracting LWJGL native: {:?}", path.file_name());
-                        extract_jar(&path.to_path_buf())?;
+        Ok(())
+    }
+
+    // ==================== Bounded concurrent downloads ====================
+
+    /// One file to fetch into `path`, verified against `expected_sha1` when
+    /// present (asset objects and library jars are all sha1-addressed;
+    /// Fabric/Quilt Maven libraries, queued by [`Self::download_loader`]
+    /// without a hash, are not, and an existing file is kept as-is).
+    async fn download_single_job(&self, job: &DownloadJob) -> Result<(), MinecraftError> {
+        if let Some(expected) = &job.expected_sha1 {
+            if job.path.exists() {
+                if let Ok(existing) = fs::read(&job.path).await {
+                    if sha1_hex(&existing) == *expected {
+                        return Ok(());
+                    }
+                }
+            }
+        } else if job.path.exists() {
+            return Ok(());
+        }
+
+        if let Some(parent) = job.path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+
+        match self.fetch_and_verify_with_retry(job, &job.url).await {
+            Ok(()) => Ok(()),
+            Err(e) => match &job.fallback_url {
+                Some(origin) => {
+                    tracing::warn!(
+                        "Mirror exhausted for {}, falling back to origin host: {}",
+                        job.label,
+                        e
+                    );
+                    self.fetch_and_verify_with_retry(job, origin).await
+                }
+                None => Err(e),
+            },
+        }
+    }
+
+    /// Retry [`Self::fetch_and_verify`] against `url` up to `DOWNLOAD_MAX_ATTEMPTS`
+    /// times with exponential backoff.
+    async fn fetch_and_verify_with_retry(
+        &self,
+        job: &DownloadJob,
+        url: &str,
+    ) -> Result<(), MinecraftError> {
+        let mut last_error = None;
+
+        for attempt in 0..DOWNLOAD_MAX_ATTEMPTS {
+            match self.fetch_and_verify(job, url).await {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    tracing::warn!(
+                        "Download attempt {}/{} failed for {} ({}): {}",
+                        attempt + 1,
+                        DOWNLOAD_MAX_ATTEMPTS,
+                        job.label,
+                        url,
+                        e
+                    );
+                    last_error = Some(e);
+                    if attempt + 1 < DOWNLOAD_MAX_ATTEMPTS {
+                        let backoff = DOWNLOAD_BASE_DELAY * 2u32.pow(attempt);
+                        tokio::time::sleep(backoff).await;
                     }
                 }
             }
         }
 
+        Err(last_error.unwrap_or_else(|| MinecraftError::DownloadFailed(url.to_string())))
+    }
+
+    async fn fetch_and_verify(&self, job: &DownloadJob, url: &str) -> Result<(), MinecraftError> {
+        let response = self.client.get(url).send().await?;
+
+        if !response.status().is_success() {
+            return Err(MinecraftError::DownloadFailed(format!(
+                "HTTP {}: {}",
+                response.status(),
+                url
+            )));
+        }
+
+        let bytes = response.bytes().await?;
+
+        if let Some(expected) = &job.expected_sha1 {
+            let actual = sha1_hex(&bytes);
+            if actual != *expected {
+                let _ = fs::remove_file(&job.path).await;
+                return Err(MinecraftError::DownloadFailed(format!(
+                    "Hash mismatch for {}: expected {}, got {}",
+                    url, expected, actual
+                )));
+            }
+        }
+
+        fs::write(&job.path, &bytes).await?;
         Ok(())
     }
 
+    /// Run `jobs` through a bounded pool of `DOWNLOAD_CONCURRENCY` concurrent
+    /// downloads (each individually retried, see [`Self::download_single_job`]),
+    /// calling `on_progress(completed, total)` as each one finishes so the
+    /// caller can report true aggregate progress instead of one-at-a-time
+    /// increments. Every job runs to completion regardless of whether others
+    /// fail - callers that need "any failure is fatal" semantics should
+    /// check the returned per-job results themselves (see
+    /// [`Self::run_required_download_jobs`]); callers for which individual
+    /// jobs are best-effort can just ignore the failed ones.
+    async fn run_download_jobs(
+        &self,
+        jobs: Vec<DownloadJob>,
+        on_progress: &dyn Fn(u64, u64),
+    ) -> Vec<Result<(), MinecraftError>> {
+        let total = jobs.len() as u64;
+        let completed = std::sync::atomic::AtomicU64::new(0);
+
+        stream::iter(jobs.iter())
+            .map(|job| async move {
+                let result = self.download_single_job(job).await;
+                if let Err(e) = &result {
+                    tracing::warn!("Download failed for {} ({}): {}", job.label, job.url, e);
+                }
+                let done = completed.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+                on_progress(done, total);
+                result
+            })
+            .buffer_unordered(DOWNLOAD_CONCURRENCY)
+            .collect()
+            .await
+    }
+
+    /// [`Self::run_download_jobs`], but the first failure (once its retries
+    /// are exhausted) is returned as an error - for jobs the caller can't
+    /// proceed without, like the client JAR, vanilla libraries, and assets.
+    async fn run_required_download_jobs(
+        &self,
+        jobs: Vec<DownloadJob>,
+        on_progress: &dyn Fn(u64, u64),
+    ) -> Result<(), MinecraftError> {
+        self.run_download_jobs(jobs, on_progress)
+            .await
+            .into_iter()
+            .find_map(Result::err)
+            .map_or(Ok(()), Err)
+    }
+
     // ==================== File Downloads ====================
 
     async fn download_file(&self, url: &str, path: &PathBuf) -> Result<(), MinecraftError> {
@@ -929,6 +1860,37 @@ impl MinecraftManager {
             }
         }
 
+        let mut last_error = None;
+
+        for attempt in 0..DOWNLOAD_MAX_ATTEMPTS {
+            match self.fetch_file_verified(url, path, expected_sha1).await {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    tracing::warn!(
+                        "Download attempt {}/{} failed for {}: {}",
+                        attempt + 1,
+                        DOWNLOAD_MAX_ATTEMPTS,
+                        url,
+                        e
+                    );
+                    last_error = Some(e);
+                    if attempt + 1 < DOWNLOAD_MAX_ATTEMPTS {
+                        let backoff = DOWNLOAD_BASE_DELAY * 2u32.pow(attempt);
+                        tokio::time::sleep(backoff).await;
+                    }
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| MinecraftError::DownloadFailed(url.to_string())))
+    }
+
+    async fn fetch_file_verified(
+        &self,
+        url: &str,
+        path: &PathBuf,
+        expected_sha1: &str,
+    ) -> Result<(), MinecraftError> {
         let response = self.client.get(url).send().await?;
 
         if !response.status().is_success() {
@@ -0,0 +1,184 @@
+//! Desktop notifications for friend activity: a new incoming friend
+//! request, a friend coming online, or a friend joining the server you're
+//! on. Fans the realtime streams from `realtime` into native OS toasts,
+//! deduplicating repeated updates while a user stays online. Each
+//! offline-to-online change still raises a new toast.
+
+use crate::realtime::{FriendshipInsert, PresenceUpdate, SupabaseRealtimeClient};
+use crate::supabase::SupabaseClient;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// Per-event-type opt-in/out for desktop notifications, persisted locally so
+/// they survive a restart.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationSettings {
+    #[serde(default = "default_true")]
+    pub friend_requests: bool,
+    #[serde(default = "default_true")]
+    pub friend_online: bool,
+    #[serde(default = "default_true")]
+    pub friend_joined_server: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        Self {
+            friend_requests: true,
+            friend_online: true,
+            friend_joined_server: true,
+        }
+    }
+}
+
+fn settings_path() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("MiracleClient")
+        .join("notification_settings.json")
+}
+
+/// Load persisted notification settings, falling back to all-enabled
+/// defaults if nothing's been saved yet or the file can't be parsed.
+pub fn load_settings() -> NotificationSettings {
+    let path = settings_path();
+    if !path.exists() {
+        return NotificationSettings::default();
+    }
+
+    std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_settings(settings: &NotificationSettings) -> Result<(), String> {
+    let path = settings_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create settings directory: {}", e))?;
+    }
+
+    let contents = serde_json::to_string_pretty(settings)
+        .map_err(|e| format!("Failed to serialize notification settings: {}", e))?;
+
+    std::fs::write(&path, contents)
+        .map_err(|e| format!("Failed to write notification settings: {}", e))
+}
+
+/// Tracks what we've already told the user about, so repeated presence
+/// updates while someone stays online don't re-fire a toast. An offline
+/// update clears the entry, so coming back online notifies again.
+struct Dedup {
+    online: HashSet<String>,
+    last_notified_server: HashMap<String, Option<String>>,
+}
+
+impl Dedup {
+    fn new() -> Self {
+        Self {
+            online: HashSet::new(),
+            last_notified_server: HashMap::new(),
+        }
+    }
+}
+
+/// Subscribe to friend presence and incoming friend requests for the
+/// lifetime of the app, dispatching native OS notifications per `settings`.
+/// `my_user_id` is this account's Supabase `users.id` (not Minecraft UUID),
+/// used to filter friend requests server-side. `my_current_server` is kept
+/// up to date by the caller (e.g. on launch/join) so a friend joining it can
+/// be told apart from a friend joining some other server.
+pub fn subscribe(
+    supabase: &SupabaseClient,
+    my_user_id: String,
+    settings: NotificationSettings,
+    my_current_server: Arc<Mutex<Option<String>>>,
+) {
+    let realtime = SupabaseRealtimeClient::new(supabase);
+    let mut presence_rx = realtime.subscribe_presence();
+    let mut friend_request_rx = realtime.subscribe_friend_requests(&my_user_id);
+
+    tauri::async_runtime::spawn(async move {
+        let mut dedup = Dedup::new();
+
+        loop {
+            tokio::select! {
+                update = presence_rx.recv() => {
+                    if let Ok(update) = update {
+                        handle_presence_update(&settings, &mut dedup, &my_current_server, update);
+                    }
+                }
+                insert = friend_request_rx.recv() => {
+                    if let Ok(insert) = insert {
+                        handle_friendship_insert(&settings, insert);
+                    }
+                }
+            }
+        }
+    });
+}
+
+fn handle_presence_update(
+    settings: &NotificationSettings,
+    dedup: &mut Dedup,
+    my_current_server: &Arc<Mutex<Option<String>>>,
+    update: PresenceUpdate,
+) {
+    let was_online = dedup.online.contains(&update.minecraft_uuid);
+
+    if !update.is_online {
+        dedup.online.remove(&update.minecraft_uuid);
+        dedup.last_notified_server.remove(&update.minecraft_uuid);
+        return;
+    }
+
+    if !was_online {
+        dedup.online.insert(update.minecraft_uuid.clone());
+        if settings.friend_online {
+            notify("Friend online", &format!("{} just came online", update.minecraft_uuid));
+        }
+    }
+
+    let my_server = my_current_server.lock().unwrap().clone();
+    let joined_mine =
+        my_server.is_some() && update.current_server == my_server;
+    let already_notified =
+        dedup.last_notified_server.get(&update.minecraft_uuid) == Some(&update.current_server);
+
+    if joined_mine && !already_notified && settings.friend_joined_server {
+        notify(
+            "Friend joined your server",
+            &format!(
+                "{} joined {}",
+                update.minecraft_uuid,
+                my_server.unwrap_or_default()
+            ),
+        );
+    }
+
+    dedup
+        .last_notified_server
+        .insert(update.minecraft_uuid.clone(), update.current_server.clone());
+}
+
+fn handle_friendship_insert(settings: &NotificationSettings, insert: FriendshipInsert) {
+    if insert.status != "pending" || !settings.friend_requests {
+        return;
+    }
+    notify("New friend request", "Someone sent you a friend request");
+}
+
+/// Show a native OS toast. Failures are logged, not propagated - a missing
+/// notification daemon shouldn't take down the realtime subscription.
+fn notify(summary: &str, body: &str) {
+    if let Err(e) = notify_rust::Notification::new().summary(summary).body(body).show() {
+        tracing::warn!("Failed to show desktop notification: {}", e);
+    }
+}
@@ -51,6 +51,77 @@ pub struct Profile {
     pub preset_type: Option<String>, // "skyblock", "pvp", or None for custom
     pub mods: Vec<String>,           // User-selected mod slugs (excludes performance mods)
     pub created_at: String,
+    /// Collapsible category labels ("Skyblock", "Testing", ...), shown by the
+    /// frontend to group large profile lists. Absent in older profiles.json
+    /// files, which deserialize to an empty Vec.
+    #[serde(default)]
+    pub groups: Vec<String>,
+    /// Modrinth project ID/slug this profile was installed from, if it came
+    /// from a modpack import. Used by `update_linked_profile` to check for
+    /// and apply pack updates in place.
+    #[serde(default)]
+    pub linked_project_id: Option<String>,
+    /// The mrpack version ID currently installed for a linked profile.
+    #[serde(default)]
+    pub linked_version_id: Option<String>,
+    /// When true, this profile is pack-managed and manual mod add/remove is
+    /// refused to avoid drifting away from the linked pack's file set.
+    #[serde(default)]
+    pub locked: bool,
+    /// Relative mod filenames the linked pack installed (as opposed to
+    /// user-added mods), so pack updates only touch pack-managed files and
+    /// never delete a user's own additions.
+    #[serde(default)]
+    pub pack_files: Vec<String>,
+    /// mrpack-relative paths (e.g. `resourcepacks/foo.zip`, `config/bar.toml`)
+    /// the linked pack's `overrides`/`client-overrides` wrote, so
+    /// `remove_all_related_files` can delete the ones outside the profile's
+    /// own resourcepacks/shaderpacks directories when the profile is deleted.
+    #[serde(default)]
+    pub pack_override_files: Vec<String>,
+    /// Shell command run (with the profile's mods dir as CWD) right before
+    /// `launch_game` moves to the "launching" stage. Launch is aborted if it
+    /// exits non-zero.
+    #[serde(default)]
+    pub pre_launch_command: Option<String>,
+    /// Shell command run after the game process monitor detects the game has
+    /// exited, right before the user is set offline.
+    #[serde(default)]
+    pub post_exit_command: Option<String>,
+    /// Custom Java executable path for this profile, as recovered from an
+    /// imported launcher instance (e.g. Prism/MultiMC's `JavaPath`). `None`
+    /// means fall back to the auto-detected/downloaded runtime.
+    #[serde(default)]
+    pub java_path: Option<String>,
+    /// Extra JVM arguments for this profile, as recovered from an imported
+    /// launcher instance (e.g. Prism/MultiMC's `JvmArgs`).
+    #[serde(default)]
+    pub jvm_args: Option<String>,
+    /// Minimum JVM heap size in MB, as recovered from an imported launcher
+    /// instance (e.g. Prism/MultiMC's `MinMemAlloc`). Not yet consumed by
+    /// `launch_game`, which still takes its memory setting from the frontend.
+    #[serde(default)]
+    pub min_memory_mb: Option<u32>,
+    /// Maximum JVM heap size in MB, as recovered from an imported launcher
+    /// instance (e.g. Prism/MultiMC's `MaxMemAlloc`). Not yet consumed by
+    /// `launch_game`, which still takes its memory setting from the frontend.
+    #[serde(default)]
+    pub max_memory_mb: Option<u32>,
+    /// Path to a cached icon image for this profile, as recovered from an
+    /// imported pack (MultiMC/Prism's `IconKey`, or an embedded `icon.png`/
+    /// `pack.png` in a `.mrpack`/CurseForge zip). `None` means the UI falls
+    /// back to its default profile tile.
+    #[serde(default)]
+    pub icon_path: Option<String>,
+    /// Mod loader this profile targets ("fabric", "quilt", "forge", or
+    /// "neoforge"). Drives the `loaders` filter used when querying Modrinth.
+    /// Absent in older profiles.json files, which deserialize to "fabric".
+    #[serde(default = "default_loader")]
+    pub loader: String,
+}
+
+fn default_loader() -> String {
+    "fabric".to_string()
 }
 
 impl Profile {
@@ -64,6 +135,20 @@ impl Profile {
             preset_type: None,
             mods: Vec::new(),
             created_at: chrono::Utc::now().to_rfc3339(),
+            groups: Vec::new(),
+            linked_project_id: None,
+            linked_version_id: None,
+            locked: false,
+            pack_files: Vec::new(),
+            pack_override_files: Vec::new(),
+            pre_launch_command: None,
+            post_exit_command: None,
+            java_path: None,
+            jvm_args: None,
+            min_memory_mb: None,
+            max_memory_mb: None,
+            icon_path: None,
+            loader: default_loader(),
         }
     }
 
@@ -77,6 +162,20 @@ impl Profile {
             preset_type: None,
             mods: Vec::new(),
             created_at: chrono::Utc::now().to_rfc3339(),
+            groups: Vec::new(),
+            linked_project_id: None,
+            linked_version_id: None,
+            locked: false,
+            pack_files: Vec::new(),
+            pack_override_files: Vec::new(),
+            pre_launch_command: None,
+            post_exit_command: None,
+            java_path: None,
+            jvm_args: None,
+            min_memory_mb: None,
+            max_memory_mb: None,
+            icon_path: None,
+            loader: default_loader(),
         }
     }
 
@@ -96,6 +195,20 @@ impl Profile {
             preset_type: Some(preset_type.to_string()),
             mods,
             created_at: chrono::Utc::now().to_rfc3339(),
+            groups: Vec::new(),
+            linked_project_id: None,
+            linked_version_id: None,
+            locked: false,
+            pack_files: Vec::new(),
+            pack_override_files: Vec::new(),
+            pre_launch_command: None,
+            post_exit_command: None,
+            java_path: None,
+            jvm_args: None,
+            min_memory_mb: None,
+            max_memory_mb: None,
+            icon_path: None,
+            loader: default_loader(),
         }
     }
 
@@ -261,6 +374,222 @@ impl ProfileManager {
         self.save()
     }
 
+    /// Replace a profile's group labels
+    pub fn set_profile_groups(&mut self, profile_id: &str, groups: Vec<String>) -> Result<(), String> {
+        let profile = self
+            .index
+            .profiles
+            .get_mut(profile_id)
+            .ok_or_else(|| format!("Profile {} not found", profile_id))?;
+
+        profile.groups = groups;
+        self.save()
+    }
+
+    /// Remove all group labels from a profile
+    pub fn clear_profile_groups(&mut self, profile_id: &str) -> Result<(), String> {
+        self.set_profile_groups(profile_id, Vec::new())
+    }
+
+    /// Get all profiles for a version that carry the given group label
+    pub fn get_profiles_in_group(&self, version: &str, group: &str) -> Vec<Profile> {
+        self.index
+            .profiles
+            .values()
+            .filter(|p| p.version == version && p.groups.iter().any(|g| g == group))
+            .cloned()
+            .collect()
+    }
+
+    /// Record that a profile's mods were installed from a modpack, so later
+    /// calls can check for and apply pack updates in place. Locks the
+    /// profile by default since manual edits would drift from the pack.
+    pub fn record_pack_link(
+        &mut self,
+        profile_id: &str,
+        project_id: &str,
+        version_id: &str,
+        pack_files: Vec<String>,
+        pack_override_files: Vec<String>,
+    ) -> Result<(), String> {
+        let profile = self
+            .index
+            .profiles
+            .get_mut(profile_id)
+            .ok_or_else(|| format!("Profile {} not found", profile_id))?;
+
+        profile.linked_project_id = Some(project_id.to_string());
+        profile.linked_version_id = Some(version_id.to_string());
+        profile.pack_files = pack_files;
+        profile.pack_override_files = pack_override_files;
+        profile.locked = true;
+        self.save()
+    }
+
+    /// Record the upstream Modrinth pack/version a profile tracks, without
+    /// locking it or touching its pack file lists. Used when an import only
+    /// recovers the link itself (e.g. MultiMC/Prism's `ManagedPack*` keys)
+    /// and the mods still came in as ordinary, user-editable files rather
+    /// than through `install_from_manifest`/`update_linked_profile`.
+    pub fn set_profile_link(
+        &mut self,
+        profile_id: &str,
+        project_id: String,
+        version_id: String,
+    ) -> Result<(), String> {
+        let profile = self
+            .index
+            .profiles
+            .get_mut(profile_id)
+            .ok_or_else(|| format!("Profile {} not found", profile_id))?;
+
+        profile.linked_project_id = Some(project_id);
+        profile.linked_version_id = Some(version_id);
+        self.save()
+    }
+
+    /// Remove `profile_id`'s files: its mods/resourcepacks/shaderpacks
+    /// directories (each exclusive to this profile) are deleted whole,
+    /// user-added files in them included, and any `pack_override_files`
+    /// written outside of those (e.g. `config/`), which live in the shared
+    /// game directory, are removed by relative path rather than by wiping a
+    /// whole folder. Called from `delete_profile`; a pack reinstall or
+    /// update does not call it.
+    pub fn remove_all_related_files(&self, profile_id: &str) {
+        let Some(profile) = self.index.profiles.get(profile_id) else {
+            return;
+        };
+
+        let game_dir = dirs::data_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("MiracleClient");
+        let profile_dir = sanitize_profile_name(&profile.name);
+
+        for (category, dir) in [
+            ("mods", self.get_mods_dir(&profile.version, profile_id)),
+            (
+                "resourcepacks",
+                game_dir
+                    .join("resourcepacks")
+                    .join(&profile.version)
+                    .join(&profile_dir),
+            ),
+            (
+                "shaderpacks",
+                game_dir
+                    .join("shaderpacks")
+                    .join(&profile.version)
+                    .join(&profile_dir),
+            ),
+        ] {
+            if dir.exists() {
+                if let Err(e) = std::fs::remove_dir_all(&dir) {
+                    tracing::warn!("Failed to remove {} directory for profile: {}", category, e);
+                }
+            }
+        }
+
+        for relative in &profile.pack_override_files {
+            // Anything under resourcepacks/ or shaderpacks/ was already
+            // removed above with its profile-scoped directory.
+            if relative.starts_with("resourcepacks/") || relative.starts_with("shaderpacks/") {
+                continue;
+            }
+            let path = game_dir.join(relative);
+            if path.exists() {
+                let _ = std::fs::remove_file(&path);
+            }
+        }
+    }
+
+    /// Set a profile's custom Java path and/or extra JVM arguments, as
+    /// recovered from an imported launcher instance.
+    pub fn set_profile_java_config(
+        &mut self,
+        profile_id: &str,
+        java_path: Option<String>,
+        jvm_args: Option<String>,
+    ) -> Result<(), String> {
+        let profile = self
+            .index
+            .profiles
+            .get_mut(profile_id)
+            .ok_or_else(|| format!("Profile {} not found", profile_id))?;
+
+        profile.java_path = java_path;
+        profile.jvm_args = jvm_args;
+        self.save()
+    }
+
+    /// Set a profile's minimum/maximum JVM heap size in MB, as recovered from
+    /// an imported launcher instance.
+    pub fn set_profile_memory_config(
+        &mut self,
+        profile_id: &str,
+        min_memory_mb: Option<u32>,
+        max_memory_mb: Option<u32>,
+    ) -> Result<(), String> {
+        let profile = self
+            .index
+            .profiles
+            .get_mut(profile_id)
+            .ok_or_else(|| format!("Profile {} not found", profile_id))?;
+
+        profile.min_memory_mb = min_memory_mb;
+        profile.max_memory_mb = max_memory_mb;
+        self.save()
+    }
+
+    /// Set a profile's cached icon path, as recovered from an imported pack.
+    pub fn set_profile_icon(
+        &mut self,
+        profile_id: &str,
+        icon_path: Option<String>,
+    ) -> Result<(), String> {
+        let profile = self
+            .index
+            .profiles
+            .get_mut(profile_id)
+            .ok_or_else(|| format!("Profile {} not found", profile_id))?;
+
+        profile.icon_path = icon_path;
+        self.save()
+    }
+
+    /// Set the mod loader a profile targets ("fabric", "quilt", "forge", or
+    /// "neoforge").
+    pub fn set_profile_loader(&mut self, profile_id: &str, loader: String) -> Result<(), String> {
+        let profile = self
+            .index
+            .profiles
+            .get_mut(profile_id)
+            .ok_or_else(|| format!("Profile {} not found", profile_id))?;
+
+        profile.loader = loader;
+        self.save()
+    }
+
+    /// Lock or unlock a profile's manual mod add/remove
+    pub fn set_profile_locked(&mut self, profile_id: &str, locked: bool) -> Result<(), String> {
+        let profile = self
+            .index
+            .profiles
+            .get_mut(profile_id)
+            .ok_or_else(|| format!("Profile {} not found", profile_id))?;
+
+        profile.locked = locked;
+        self.save()
+    }
+
+    /// Whether manual mod add/remove should be refused for this profile
+    pub fn is_profile_locked(&self, profile_id: &str) -> bool {
+        self.index
+            .profiles
+            .get(profile_id)
+            .map(|p| p.locked)
+            .unwrap_or(false)
+    }
+
     /// Create a new custom profile
     pub fn create_profile(
         &mut self,
@@ -334,6 +663,7 @@ impl ProfileManager {
         }
 
         let version = profile.version.clone();
+        self.remove_all_related_files(profile_id);
         self.index.profiles.remove(profile_id);
 
         // If this was the active profile, switch to default
@@ -351,15 +681,7 @@ impl ProfileManager {
             }
         }
 
-        self.save()?;
-
-        // Also delete the mods folder for this profile
-        let mods_dir = self.get_mods_dir(&version, profile_id);
-        if mods_dir.exists() {
-            let _ = std::fs::remove_dir_all(&mods_dir);
-        }
-
-        Ok(())
+        self.save()
     }
 
     /// Duplicate a profile
@@ -414,6 +736,12 @@ impl ProfileManager {
             name: profile.name.clone(),
             version: profile.version.clone(),
             mods: profile.get_all_mods(),
+            groups: profile.groups.clone(),
+            linked_project_id: profile.linked_project_id.clone(),
+            linked_version_id: profile.linked_version_id.clone(),
+            // Populated by the `export_profile` command, which has access to
+            // the per-mod `ModMetadata` this manager doesn't track.
+            mod_sources: HashMap::new(),
         })
     }
 
@@ -431,6 +759,9 @@ impl ProfileManager {
             .into_iter()
             .filter(|m| !PERFORMANCE_MODS.contains(&m.as_str()))
             .collect();
+        profile.groups = export.groups;
+        profile.linked_project_id = export.linked_project_id;
+        profile.linked_version_id = export.linked_version_id;
 
         self.index
             .profiles
@@ -446,6 +777,20 @@ pub struct ProfileExport {
     pub name: String,
     pub version: String,
     pub mods: Vec<String>,
+    #[serde(default)]
+    pub groups: Vec<String>,
+    /// Modrinth project ID this profile is linked to, if it came from (or was
+    /// re-exported from) a modpack import. Carried through so a re-imported
+    /// profile can still be checked for pack updates.
+    #[serde(default)]
+    pub linked_project_id: Option<String>,
+    #[serde(default)]
+    pub linked_version_id: Option<String>,
+    /// Non-Modrinth download source for each mod slug that didn't come from
+    /// Modrinth, e.g. `{"somemod": "github"}`. Mirrors `ModMetadata::source`
+    /// in `ipc::mod_updates`; Modrinth/CurseForge mods are simply absent.
+    #[serde(default)]
+    pub mod_sources: HashMap<String, String>,
 }
 
 impl Default for ProfileManager {
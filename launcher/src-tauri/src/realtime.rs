@@ -0,0 +1,243 @@
+//! Supabase Realtime (Phoenix-channel) websocket client for live friend
+//! presence and friend-request notifications, so the UI doesn't have to
+//! re-poll `get_friends` to notice a friend coming online or a new request
+//! landing.
+
+use crate::supabase::SupabaseClient;
+use futures_util::{SinkExt, StreamExt};
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio_tungstenite::tungstenite::Message;
+
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(30);
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// A friend's presence changed, decoded from a `postgres_changes` `UPDATE`
+/// event on the `users` table.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PresenceUpdate {
+    pub minecraft_uuid: String,
+    pub is_online: bool,
+    pub current_server: Option<String>,
+}
+
+/// A new row landed in `friendships`, decoded from a `postgres_changes`
+/// `INSERT` event filtered server-side to rows where we're the recipient.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct FriendshipInsert {
+    pub id: String,
+    pub user_id: String,
+    pub friend_id: String,
+    pub status: String,
+}
+
+pub struct SupabaseRealtimeClient {
+    ws_url: String,
+    api_key: String,
+}
+
+impl SupabaseRealtimeClient {
+    pub fn new(supabase: &SupabaseClient) -> Self {
+        Self {
+            ws_url: supabase.realtime_ws_url(),
+            api_key: supabase.api_key().to_string(),
+        }
+    }
+
+    /// Stream `users` presence updates from a background task that runs for
+    /// the rest of the app's life, even if the returned receiver is dropped.
+    /// Reconnects with backoff and re-joins the channel on any socket error.
+    pub fn subscribe_presence(&self) -> broadcast::Receiver<PresenceUpdate> {
+        let (tx, rx) = broadcast::channel(64);
+        let ws_url = self.ws_url.clone();
+        let api_key = self.api_key.clone();
+
+        tauri::async_runtime::spawn(async move {
+            let mut attempt = 0u32;
+            loop {
+                if let Err(e) = run_presence_channel(&ws_url, &api_key, &tx).await {
+                    tracing::warn!("Realtime presence channel dropped: {}", e);
+                }
+                let delay = reconnect_delay(attempt);
+                tracing::info!("Reconnecting to realtime presence channel in {:?}", delay);
+                tokio::time::sleep(delay).await;
+                attempt = attempt.saturating_add(1);
+            }
+        });
+
+        rx
+    }
+
+    /// Stream new `friendships` rows where `my_user_id` is the recipient,
+    /// from a background task that runs for the rest of the app's life.
+    /// Reconnects the same way as `subscribe_presence`.
+    pub fn subscribe_friend_requests(
+        &self,
+        my_user_id: &str,
+    ) -> broadcast::Receiver<FriendshipInsert> {
+        let (tx, rx) = broadcast::channel(16);
+        let ws_url = self.ws_url.clone();
+        let api_key = self.api_key.clone();
+        let my_user_id = my_user_id.to_string();
+
+        tauri::async_runtime::spawn(async move {
+            let mut attempt = 0u32;
+            loop {
+                if let Err(e) = run_friend_request_channel(&ws_url, &api_key, &my_user_id, &tx).await
+                {
+                    tracing::warn!("Realtime friend-request channel dropped: {}", e);
+                }
+                let delay = reconnect_delay(attempt);
+                tracing::info!(
+                    "Reconnecting to realtime friend-request channel in {:?}",
+                    delay
+                );
+                tokio::time::sleep(delay).await;
+                attempt = attempt.saturating_add(1);
+            }
+        });
+
+        rx
+    }
+}
+
+fn reconnect_delay(attempt: u32) -> Duration {
+    Duration::from_secs(1)
+        .saturating_mul(2u32.saturating_pow(attempt.min(5)))
+        .min(RECONNECT_MAX_DELAY)
+}
+
+async fn run_presence_channel(
+    ws_url: &str,
+    api_key: &str,
+    tx: &broadcast::Sender<PresenceUpdate>,
+) -> Result<(), String> {
+    let join_config = serde_json::json!({
+        "postgres_changes": [
+            { "event": "UPDATE", "schema": "public", "table": "users" }
+        ]
+    });
+
+    run_channel(ws_url, api_key, "realtime:public:users", join_config, |record| {
+        let update = PresenceUpdate {
+            minecraft_uuid: record["minecraft_uuid"].as_str()?.to_string(),
+            is_online: record["is_online"].as_bool().unwrap_or(false),
+            current_server: record["current_server"].as_str().map(|s| s.to_string()),
+        };
+        tx.send(update).ok();
+        Some(())
+    })
+    .await
+}
+
+async fn run_friend_request_channel(
+    ws_url: &str,
+    api_key: &str,
+    my_user_id: &str,
+    tx: &broadcast::Sender<FriendshipInsert>,
+) -> Result<(), String> {
+    let join_config = serde_json::json!({
+        "postgres_changes": [
+            {
+                "event": "INSERT",
+                "schema": "public",
+                "table": "friendships",
+                "filter": format!("friend_id=eq.{}", my_user_id)
+            }
+        ]
+    });
+
+    run_channel(
+        ws_url,
+        api_key,
+        "realtime:public:friendships",
+        join_config,
+        |record| {
+            let insert = FriendshipInsert {
+                id: record["id"].as_str()?.to_string(),
+                user_id: record["user_id"].as_str()?.to_string(),
+                friend_id: record["friend_id"].as_str()?.to_string(),
+                status: record["status"].as_str().unwrap_or("pending").to_string(),
+            };
+            tx.send(insert).ok();
+            Some(())
+        },
+    )
+    .await
+}
+
+/// Open one Phoenix channel, join it with `postgres_changes: join_config`,
+/// keep it alive with periodic heartbeats, and hand every changed row to
+/// `on_record` until the socket closes or errors - at which point the caller
+/// is expected to reconnect.
+async fn run_channel(
+    ws_url: &str,
+    api_key: &str,
+    topic: &str,
+    join_config: serde_json::Value,
+    mut on_record: impl FnMut(&serde_json::Value) -> Option<()>,
+) -> Result<(), String> {
+    let url = format!("{}?apikey={}&vsn=1.0.0", ws_url, api_key);
+    let (ws_stream, _) = tokio_tungstenite::connect_async(&url)
+        .await
+        .map_err(|e| format!("Failed to connect to realtime websocket: {}", e))?;
+    let (mut write, mut read) = ws_stream.split();
+
+    let join_message = serde_json::json!({
+        "topic": topic,
+        "event": "phx_join",
+        "payload": { "config": join_config },
+        "ref": "1"
+    });
+    write
+        .send(Message::Text(join_message.to_string()))
+        .await
+        .map_err(|e| format!("Failed to join realtime channel {}: {}", topic, e))?;
+
+    let mut heartbeat = tokio::time::interval(HEARTBEAT_INTERVAL);
+    heartbeat.tick().await; // first tick fires immediately, joining above already keeps us alive
+
+    loop {
+        tokio::select! {
+            _ = heartbeat.tick() => {
+                let heartbeat_message = serde_json::json!({
+                    "topic": "phoenix",
+                    "event": "heartbeat",
+                    "payload": {},
+                    "ref": "hb"
+                });
+                write
+                    .send(Message::Text(heartbeat_message.to_string()))
+                    .await
+                    .map_err(|e| format!("Failed to send realtime heartbeat: {}", e))?;
+            }
+            message = read.next() => {
+                match message {
+                    Some(Ok(Message::Text(text))) => {
+                        if let Some(record) = parse_postgres_changes_record(&text) {
+                            on_record(&record);
+                        }
+                    }
+                    Some(Ok(Message::Close(_))) | None => {
+                        return Err(format!("Realtime channel {} closed", topic));
+                    }
+                    Some(Err(e)) => {
+                        return Err(format!("Realtime channel {} error: {}", topic, e));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// Pull the changed row (`payload.data.record`) out of a `postgres_changes`
+/// event envelope, ignoring every other Phoenix message (`phx_reply`,
+/// `presence_state`, etc).
+fn parse_postgres_changes_record(text: &str) -> Option<serde_json::Value> {
+    let value: serde_json::Value = serde_json::from_str(text).ok()?;
+    if value["event"].as_str()? != "postgres_changes" {
+        return None;
+    }
+    Some(value["payload"]["data"]["record"].clone())
+}
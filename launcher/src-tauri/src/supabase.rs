@@ -1,6 +1,12 @@
+use hickory_resolver::config::{NameServerConfigGroup, ResolverConfig, ResolverOpts};
+use hickory_resolver::TokioAsyncResolver;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest as _, Sha256};
+use std::net::SocketAddr;
 use std::path::PathBuf;
+use std::sync::Arc;
 use tokio::fs;
 
 // Supabase project configuration
@@ -28,7 +34,7 @@ pub struct Friendship {
     pub id: String,
     pub user_id: String,
     pub friend_id: String,
-    pub status: String, // "pending" or "accepted"
+    pub status: String, // "pending", "accepted", or "blocked"
     pub created_at: Option<String>,
     pub accepted_at: Option<String>,
 }
@@ -90,10 +96,221 @@ pub struct ModUpdateInfo {
     pub latest_version: String,
     pub download_url: String,
     pub changelog: Option<String>,
+    pub checksum: Option<String>,
     pub mandatory: bool,
     pub has_update: bool,
 }
 
+/// Which channel of published builds a user has opted into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReleaseTrack {
+    Stable,
+    Beta,
+    Nightly,
+}
+
+impl ReleaseTrack {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ReleaseTrack::Stable => "stable",
+            ReleaseTrack::Beta => "beta",
+            ReleaseTrack::Nightly => "nightly",
+        }
+    }
+}
+
+/// The newest published build on a given track, as returned by
+/// `check_for_update`.
+///
+/// Create this table in Supabase:
+/// ```sql
+/// CREATE TABLE releases (
+///     id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+///     version TEXT NOT NULL,
+///     track TEXT NOT NULL,            -- "stable", "beta", or "nightly"
+///     is_critical BOOLEAN DEFAULT false,
+///     download_url TEXT NOT NULL,
+///     sha256 TEXT,
+///     created_at TIMESTAMPTZ DEFAULT now()
+/// );
+///
+/// ALTER TABLE releases ENABLE ROW LEVEL SECURITY;
+/// CREATE POLICY "Allow public read" ON releases FOR SELECT USING (true);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseInfo {
+    pub version: String,
+    pub track: ReleaseTrack,
+    /// Forces a mandatory update prompt instead of an optional one, the same
+    /// way `ModUpdateInfo::mandatory` does for mod releases.
+    pub is_critical: bool,
+    pub download_url: String,
+    pub sha256: Option<String>,
+}
+
+/// Raw shape of a `releases` row, before the track is known to be the one
+/// that was queried for.
+#[derive(Debug, Clone, Deserialize)]
+struct ReleaseRow {
+    version: String,
+    is_critical: Option<bool>,
+    download_url: String,
+    sha256: Option<String>,
+}
+
+/// Where to reach a Supabase (or self-hosted Supabase-compatible) backend.
+/// Lets users who run their own instance of the friends/profile-sharing
+/// backend point the launcher at it instead of the baked-in project.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupabaseConfig {
+    pub base_url: String,
+    pub anon_key: String,
+    #[serde(default)]
+    pub dns: DnsConfig,
+}
+
+impl Default for SupabaseConfig {
+    fn default() -> Self {
+        Self {
+            base_url: SUPABASE_URL.to_string(),
+            anon_key: SUPABASE_ANON_KEY.to_string(),
+            dns: DnsConfig::default(),
+        }
+    }
+}
+
+/// DNS/connection hardening for networks with a broken, hijacked, or
+/// captive-portal'd system resolver.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DnsConfig {
+    /// Resolve hostnames via `hickory-resolver` against `bootstrap_servers`
+    /// instead of the OS resolver.
+    #[serde(default)]
+    pub custom_resolver: bool,
+    /// DoH bootstrap server IPs to resolve through when `custom_resolver` is
+    /// set, e.g. `["1.1.1.1", "1.0.0.1"]`. Every server is contacted with the
+    /// TLS name `cloudflare-dns.com`, so only Cloudflare's resolvers validate.
+    #[serde(default = "default_bootstrap_servers")]
+    pub bootstrap_servers: Vec<String>,
+    /// TCP connect timeout, separate from the overall request timeout, so a
+    /// network that accepts connections but never responds doesn't hold a
+    /// request open for the full 30s.
+    #[serde(default = "default_connect_timeout_secs")]
+    pub connect_timeout_secs: u64,
+}
+
+impl Default for DnsConfig {
+    fn default() -> Self {
+        Self {
+            custom_resolver: false,
+            bootstrap_servers: default_bootstrap_servers(),
+            connect_timeout_secs: default_connect_timeout_secs(),
+        }
+    }
+}
+
+fn default_bootstrap_servers() -> Vec<String> {
+    vec!["1.1.1.1".to_string(), "1.0.0.1".to_string()]
+}
+
+fn default_connect_timeout_secs() -> u64 {
+    10
+}
+
+/// A `reqwest` DNS resolver backed by `hickory-resolver` DoH lookups against
+/// a fixed set of bootstrap servers, bypassing the OS resolver entirely.
+#[derive(Clone)]
+struct HickoryResolver {
+    inner: Arc<TokioAsyncResolver>,
+}
+
+impl HickoryResolver {
+    fn new(bootstrap_servers: &[String]) -> Result<Self, String> {
+        let ips: Vec<std::net::IpAddr> = bootstrap_servers
+            .iter()
+            .filter_map(|s| s.parse().ok())
+            .collect();
+
+        if ips.is_empty() {
+            return Err("No valid bootstrap DNS server IPs configured".to_string());
+        }
+
+        let config = ResolverConfig::from_parts(
+            None,
+            vec![],
+            NameServerConfigGroup::from_ips_https(&ips, 443, "cloudflare-dns.com".to_string(), true),
+        );
+
+        Ok(Self {
+            inner: Arc::new(TokioAsyncResolver::tokio(config, ResolverOpts::default())),
+        })
+    }
+}
+
+impl Resolve for HickoryResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let resolver = self.inner.clone();
+        Box::pin(async move {
+            let lookup = resolver
+                .lookup_ip(name.as_str())
+                .await
+                .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> { Box::new(e) })?;
+            let addrs: Addrs = Box::new(lookup.into_iter().map(|ip| SocketAddr::new(ip, 0)));
+            Ok(addrs)
+        })
+    }
+}
+
+impl SupabaseConfig {
+    fn config_path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("MiracleClient")
+            .join("supabase_config.json")
+    }
+
+    /// Resolve the config to use at startup: environment variables take
+    /// priority (for scripted/CI setups), then a locally saved config file,
+    /// then the baked-in defaults.
+    pub fn load() -> Self {
+        if let (Ok(base_url), Ok(anon_key)) = (
+            std::env::var("MIRACLE_SUPABASE_URL"),
+            std::env::var("MIRACLE_SUPABASE_ANON_KEY"),
+        ) {
+            return Self {
+                base_url,
+                anon_key,
+                dns: DnsConfig::default(),
+            };
+        }
+
+        let path = Self::config_path();
+        if path.exists() {
+            if let Ok(contents) = std::fs::read_to_string(&path) {
+                if let Ok(config) = serde_json::from_str(&contents) {
+                    return config;
+                }
+            }
+        }
+
+        Self::default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = Self::config_path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create config directory: {}", e))?;
+        }
+
+        let contents = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize Supabase config: {}", e))?;
+
+        std::fs::write(&path, contents).map_err(|e| format!("Failed to write Supabase config: {}", e))
+    }
+}
+
 pub struct SupabaseClient {
     client: Client,
     base_url: String,
@@ -102,19 +319,60 @@ pub struct SupabaseClient {
 
 impl SupabaseClient {
     pub fn new() -> Self {
+        Self::with_config(SupabaseConfig::load())
+    }
+
+    /// Build a client against an explicit backend instead of the one
+    /// resolved by `SupabaseConfig::load`, e.g. after the user changes their
+    /// self-hosted URL in settings.
+    pub fn with_config(config: SupabaseConfig) -> Self {
+        let mut builder = Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .connect_timeout(std::time::Duration::from_secs(
+                config.dns.connect_timeout_secs,
+            ));
+
+        if config.dns.custom_resolver {
+            match HickoryResolver::new(&config.dns.bootstrap_servers) {
+                Ok(resolver) => builder = builder.dns_resolver(Arc::new(resolver)),
+                Err(e) => tracing::warn!(
+                    "Ignoring custom DNS resolver config, falling back to system resolver: {}",
+                    e
+                ),
+            }
+        }
+
         Self {
-            client: Client::builder()
-                .timeout(std::time::Duration::from_secs(30))
-                .build()
-                .expect("Failed to create HTTP client"),
-            base_url: SUPABASE_URL.to_string(),
-            api_key: SUPABASE_ANON_KEY.to_string(),
+            client: builder.build().expect("Failed to create HTTP client"),
+            base_url: config.base_url,
+            api_key: config.anon_key,
         }
     }
 
-    /// Check if Supabase is configured
+    /// Check if Supabase is configured: the URL must look like an `http(s)`
+    /// endpoint and the key must not be empty or the placeholder left in
+    /// source, rather than just checking it differs from the placeholder
+    /// string (a self-hosted key could coincidentally be anything).
     pub fn is_configured(&self) -> bool {
-        !self.api_key.contains("YOUR_ANON_KEY")
+        let url_looks_valid = (self.base_url.starts_with("http://")
+            || self.base_url.starts_with("https://"))
+            && self.base_url.len() > "https://".len();
+
+        let key_looks_valid = !self.api_key.is_empty() && !self.api_key.contains("YOUR_ANON_KEY");
+
+        url_looks_valid && key_looks_valid
+    }
+
+    /// Base `wss://` URL for this project's Realtime websocket endpoint.
+    pub(crate) fn realtime_ws_url(&self) -> String {
+        format!(
+            "{}/realtime/v1/websocket",
+            self.base_url.replacen("https://", "wss://", 1)
+        )
+    }
+
+    pub(crate) fn api_key(&self) -> &str {
+        &self.api_key
     }
 
     /// Get the latest release for a specific mod and Minecraft version
@@ -176,6 +434,7 @@ impl SupabaseClient {
                     latest_version: release.version,
                     download_url: release.download_url,
                     changelog: release.changelog,
+                    checksum: release.checksum,
                     mandatory: release.mandatory.unwrap_or(false),
                     has_update,
                 })
@@ -186,14 +445,74 @@ impl SupabaseClient {
                 latest_version: current_version.to_string(),
                 download_url: String::new(),
                 changelog: None,
+                checksum: None,
                 mandatory: false,
                 has_update: false,
             }),
         }
     }
 
-    /// Download a mod update to a specified path
-    pub async fn download_mod_update(
+    /// Check the `releases` table for a newer build on `track` than
+    /// `current_version`. Per-track filtering is what lets opt-in beta/
+    /// nightly testers receive those builds while stable users never see
+    /// them: each track is a disjoint query, not a client-side filter over
+    /// one combined list.
+    pub async fn check_for_update(
+        &self,
+        current_version: &str,
+        track: ReleaseTrack,
+    ) -> Result<Option<ReleaseInfo>, String> {
+        if !self.is_configured() {
+            tracing::warn!("Supabase not configured, skipping update check");
+            return Ok(None);
+        }
+
+        let url = format!(
+            "{}/rest/v1/releases?track=eq.{}&order=created_at.desc&limit=1",
+            self.base_url,
+            track.as_str()
+        );
+
+        let response = self
+            .client
+            .get(&url)
+            .header("apikey", &self.api_key)
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .send()
+            .await
+            .map_err(|e| format!("Failed to fetch from Supabase: {}", e))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(format!("Supabase request failed: {} - {}", status, body));
+        }
+
+        let rows: Vec<ReleaseRow> = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse Supabase response: {}", e))?;
+
+        let newest = match rows.into_iter().next() {
+            Some(row) if is_newer_version(&row.version, current_version) => row,
+            _ => return Ok(None),
+        };
+
+        Ok(Some(ReleaseInfo {
+            version: newest.version,
+            track,
+            is_critical: newest.is_critical.unwrap_or(false),
+            download_url: newest.download_url,
+            sha256: newest.sha256,
+        }))
+    }
+
+    /// Download a mod update to a specified path, verifying its SHA-256
+    /// checksum when `update_info.checksum` is present. Callers are
+    /// responsible for respecting `update_info.mandatory`: a failure here on
+    /// a mandatory update should abort rather than silently fall back to a
+    /// bundled copy.
+    pub async fn download_and_verify(
         &self,
         update_info: &ModUpdateInfo,
         dest_dir: &PathBuf,
@@ -202,6 +521,13 @@ impl SupabaseClient {
             return Err("No download URL provided".to_string());
         }
 
+        if !update_info.download_url.starts_with("https://") {
+            return Err(format!(
+                "Refusing to download mod update from a non-HTTPS URL: {}",
+                update_info.download_url
+            ));
+        }
+
         tracing::info!("Downloading mod update from: {}", update_info.download_url);
 
         let response = self
@@ -228,6 +554,19 @@ impl SupabaseClient {
             return Err("Downloaded file is too small, may be corrupted".to_string());
         }
 
+        if let Some(expected) = &update_info.checksum {
+            let mut hasher = Sha256::new();
+            hasher.update(&bytes);
+            let actual = format!("{:x}", hasher.finalize());
+
+            if !actual.eq_ignore_ascii_case(expected) {
+                return Err(format!(
+                    "Checksum mismatch for mod update: expected {}, got {}",
+                    expected, actual
+                ));
+            }
+        }
+
         // Create destination directory if needed
         fs::create_dir_all(dest_dir)
             .await
@@ -371,11 +710,10 @@ impl SupabaseClient {
         Ok(users)
     }
 
-    /// Get all friends and pending requests for a user
-    pub async fn get_friends(&self, minecraft_uuid: &str) -> Result<Vec<Friend>, String> {
+    /// Look up a user by Minecraft UUID, without creating one if absent.
+    async fn get_user_by_uuid(&self, minecraft_uuid: &str) -> Result<Option<User>, String> {
         let clean_uuid = minecraft_uuid.replace("-", "").to_lowercase();
 
-        // Look up the user (don't create if not exists)
         let url = format!(
             "{}/rest/v1/users?minecraft_uuid=eq.{}",
             self.base_url, clean_uuid
@@ -395,7 +733,275 @@ impl SupabaseClient {
             .await
             .map_err(|e| format!("Failed to parse user response: {}", e))?;
 
-        let user = match users.into_iter().next() {
+        Ok(users.into_iter().next())
+    }
+
+    /// The ids of every user `user_id` has an accepted friendship with,
+    /// regardless of who sent the original request.
+    async fn accepted_friend_ids(
+        &self,
+        user_id: &str,
+    ) -> Result<std::collections::HashSet<String>, String> {
+        let outgoing_url = format!(
+            "{}/rest/v1/friendships?user_id=eq.{}&status=eq.accepted&select=*",
+            self.base_url, user_id
+        );
+        let outgoing_response = self
+            .client
+            .get(&outgoing_url)
+            .header("apikey", &self.api_key)
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .send()
+            .await
+            .map_err(|e| format!("Failed to fetch outgoing friendships: {}", e))?;
+        let outgoing: Vec<Friendship> = outgoing_response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse outgoing friendships: {}", e))?;
+
+        let incoming_url = format!(
+            "{}/rest/v1/friendships?friend_id=eq.{}&status=eq.accepted&select=*",
+            self.base_url, user_id
+        );
+        let incoming_response = self
+            .client
+            .get(&incoming_url)
+            .header("apikey", &self.api_key)
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .send()
+            .await
+            .map_err(|e| format!("Failed to fetch incoming friendships: {}", e))?;
+        let incoming: Vec<Friendship> = incoming_response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse incoming friendships: {}", e))?;
+
+        let mut ids: std::collections::HashSet<String> =
+            outgoing.into_iter().map(|f| f.friend_id).collect();
+        ids.extend(incoming.into_iter().map(|f| f.user_id));
+        Ok(ids)
+    }
+
+    /// Users both `minecraft_uuid` and `other_user_id` have an accepted
+    /// friendship with.
+    pub async fn get_mutual_friends(
+        &self,
+        minecraft_uuid: &str,
+        other_user_id: &str,
+    ) -> Result<Vec<User>, String> {
+        let user = match self.get_user_by_uuid(minecraft_uuid).await? {
+            Some(u) => u,
+            None => return Ok(vec![]),
+        };
+
+        let mine = self.accepted_friend_ids(&user.id).await?;
+        let theirs = self.accepted_friend_ids(other_user_id).await?;
+
+        let mutual_ids: Vec<String> = mine.intersection(&theirs).cloned().collect();
+        if mutual_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let ids_param = mutual_ids
+            .iter()
+            .map(|id| format!("\"{}\"", id))
+            .collect::<Vec<_>>()
+            .join(",");
+        let users_url = format!("{}/rest/v1/users?id=in.({})", self.base_url, ids_param);
+
+        let response = self
+            .client
+            .get(&users_url)
+            .header("apikey", &self.api_key)
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .send()
+            .await
+            .map_err(|e| format!("Failed to fetch mutual friend profiles: {}", e))?;
+
+        response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse mutual friend profiles: {}", e))
+    }
+
+    /// Block another user, overwriting any existing friendship/request
+    /// between the two with a `"blocked"` row owned by `from_uuid`.
+    pub async fn block_user(
+        &self,
+        from_uuid: &str,
+        to_user_id: &str,
+    ) -> Result<FriendRequestResult, String> {
+        let from_user = match self.get_user_by_uuid(from_uuid).await? {
+            Some(u) => u,
+            None => {
+                return Ok(FriendRequestResult {
+                    success: false,
+                    message: "User not registered".to_string(),
+                })
+            }
+        };
+
+        let check_url = format!(
+            "{}/rest/v1/friendships?or=(and(user_id.eq.{},friend_id.eq.{}),and(user_id.eq.{},friend_id.eq.{}))",
+            self.base_url, from_user.id, to_user_id, to_user_id, from_user.id
+        );
+
+        let check_response = self
+            .client
+            .get(&check_url)
+            .header("apikey", &self.api_key)
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .send()
+            .await
+            .map_err(|e| format!("Failed to check existing friendship: {}", e))?;
+
+        let existing: Vec<Friendship> = check_response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse friendship check: {}", e))?;
+
+        let response = if let Some(friendship) = existing.first() {
+            let url = format!(
+                "{}/rest/v1/friendships?id=eq.{}",
+                self.base_url, friendship.id
+            );
+            self.client
+                .patch(&url)
+                .header("apikey", &self.api_key)
+                .header("Authorization", format!("Bearer {}", self.api_key))
+                .header("Content-Type", "application/json")
+                .json(&serde_json::json!({
+                    "user_id": from_user.id,
+                    "friend_id": to_user_id,
+                    "status": "blocked"
+                }))
+                .send()
+                .await
+                .map_err(|e| format!("Failed to update friendship to blocked: {}", e))?
+        } else {
+            let insert_url = format!("{}/rest/v1/friendships", self.base_url);
+            self.client
+                .post(&insert_url)
+                .header("apikey", &self.api_key)
+                .header("Authorization", format!("Bearer {}", self.api_key))
+                .header("Content-Type", "application/json")
+                .json(&serde_json::json!({
+                    "user_id": from_user.id,
+                    "friend_id": to_user_id,
+                    "status": "blocked"
+                }))
+                .send()
+                .await
+                .map_err(|e| format!("Failed to block user: {}", e))?
+        };
+
+        if response.status().is_success() {
+            Ok(FriendRequestResult {
+                success: true,
+                message: "User blocked".to_string(),
+            })
+        } else {
+            let body = response.text().await.unwrap_or_default();
+            Err(format!("Failed to block user: {}", body))
+        }
+    }
+
+    /// Remove a block previously created with `block_user`.
+    pub async fn unblock_user(
+        &self,
+        from_uuid: &str,
+        to_user_id: &str,
+    ) -> Result<FriendRequestResult, String> {
+        let from_user = match self.get_user_by_uuid(from_uuid).await? {
+            Some(u) => u,
+            None => {
+                return Ok(FriendRequestResult {
+                    success: false,
+                    message: "User not registered".to_string(),
+                })
+            }
+        };
+
+        let url = format!(
+            "{}/rest/v1/friendships?user_id=eq.{}&friend_id=eq.{}&status=eq.blocked",
+            self.base_url, from_user.id, to_user_id
+        );
+
+        let response = self
+            .client
+            .delete(&url)
+            .header("apikey", &self.api_key)
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .send()
+            .await
+            .map_err(|e| format!("Failed to unblock user: {}", e))?;
+
+        if response.status().is_success() {
+            Ok(FriendRequestResult {
+                success: true,
+                message: "User unblocked".to_string(),
+            })
+        } else {
+            let body = response.text().await.unwrap_or_default();
+            Err(format!("Failed to unblock user: {}", body))
+        }
+    }
+
+    /// Users `minecraft_uuid` has blocked.
+    pub async fn get_blocked_users(&self, minecraft_uuid: &str) -> Result<Vec<User>, String> {
+        let user = match self.get_user_by_uuid(minecraft_uuid).await? {
+            Some(u) => u,
+            None => return Ok(vec![]),
+        };
+
+        let url = format!(
+            "{}/rest/v1/friendships?user_id=eq.{}&status=eq.blocked&select=*",
+            self.base_url, user.id
+        );
+
+        let response = self
+            .client
+            .get(&url)
+            .header("apikey", &self.api_key)
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .send()
+            .await
+            .map_err(|e| format!("Failed to fetch blocked friendships: {}", e))?;
+
+        let blocked: Vec<Friendship> = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse blocked friendships: {}", e))?;
+
+        if blocked.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let ids_param = blocked
+            .iter()
+            .map(|f| format!("\"{}\"", f.friend_id))
+            .collect::<Vec<_>>()
+            .join(",");
+        let users_url = format!("{}/rest/v1/users?id=in.({})", self.base_url, ids_param);
+
+        let users_response = self
+            .client
+            .get(&users_url)
+            .header("apikey", &self.api_key)
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .send()
+            .await
+            .map_err(|e| format!("Failed to fetch blocked user profiles: {}", e))?;
+
+        users_response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse blocked user profiles: {}", e))
+    }
+
+    /// Get all friends and pending requests for a user
+    pub async fn get_friends(&self, minecraft_uuid: &str) -> Result<Vec<Friend>, String> {
+        let user = match self.get_user_by_uuid(minecraft_uuid).await? {
             Some(u) => u,
             None => return Ok(vec![]), // User not registered yet
         };
@@ -478,8 +1084,12 @@ impl SupabaseClient {
         // Build friend list
         let mut friends: Vec<Friend> = vec![];
 
-        // Add outgoing (we sent the request)
+        // Add outgoing (we sent the request). Blocked relationships are
+        // surfaced separately via `get_blocked_users`, not in this list.
         for friendship in outgoing {
+            if friendship.status == "blocked" {
+                continue;
+            }
             if let Some(friend_user) = users_map.get(&friendship.friend_id) {
                 friends.push(Friend {
                     friendship_id: friendship.id,
@@ -492,6 +1102,9 @@ impl SupabaseClient {
 
         // Add incoming (they sent the request)
         for friendship in incoming {
+            if friendship.status == "blocked" {
+                continue;
+            }
             if let Some(friend_user) = users_map.get(&friendship.user_id) {
                 friends.push(Friend {
                     friendship_id: friendship.id,
@@ -537,7 +1150,13 @@ impl SupabaseClient {
 
         if !existing.is_empty() {
             let friendship = &existing[0];
-            if friendship.status == "accepted" {
+            if friendship.status == "blocked" {
+                return Ok(FriendRequestResult {
+                    success: false,
+                    message: "Cannot send friend request: a block exists between you and this user"
+                        .to_string(),
+                });
+            } else if friendship.status == "accepted" {
                 return Ok(FriendRequestResult {
                     success: false,
                     message: "Already friends".to_string(),
@@ -696,10 +1315,12 @@ impl SupabaseClient {
 ///     name TEXT NOT NULL,
 ///     version TEXT NOT NULL,
 ///     mods JSONB NOT NULL,
+///     mod_hashes JSONB DEFAULT '[]'::jsonb,    -- SHA-256 of each mods[i]'s jar, creator-side
 ///     creator_uuid TEXT,
 ///     creator_username TEXT,
 ///     downloads INTEGER DEFAULT 0,
-///     created_at TIMESTAMPTZ DEFAULT now()
+///     created_at TIMESTAMPTZ DEFAULT now(),
+///     updated_at TIMESTAMPTZ                   -- set by update_shared_profile
 /// );
 ///
 /// ALTER TABLE shared_profiles ENABLE ROW LEVEL SECURITY;
@@ -714,10 +1335,22 @@ pub struct SharedProfile {
     pub name: String,
     pub version: String,
     pub mods: Vec<String>,
+    /// SHA-256 of `mods[i]`'s jar as it existed on the creator's machine at
+    /// share time, for content-addressed verification on download. Empty
+    /// (rather than missing per-entry) on profiles shared before this field
+    /// existed, and per-entry empty when the creator didn't have that mod's
+    /// jar downloaded locally to hash.
+    #[serde(default)]
+    pub mod_hashes: Vec<String>,
     pub creator_uuid: Option<String>,
     pub creator_username: Option<String>,
     pub downloads: i32,
     pub created_at: Option<String>,
+    /// Set by `update_shared_profile` each time the creator pushes a new
+    /// version under the same `short_code`. Absent on a profile that's
+    /// never been updated since it was first shared.
+    #[serde(default)]
+    pub updated_at: Option<String>,
 }
 
 /// Result of sharing a profile
@@ -748,6 +1381,7 @@ impl SupabaseClient {
         name: &str,
         version: &str,
         mods: &[String],
+        mod_hashes: &[String],
         creator_uuid: Option<&str>,
         creator_username: Option<&str>,
     ) -> Result<ShareProfileResult, String> {
@@ -776,6 +1410,7 @@ impl SupabaseClient {
                 "name": name,
                 "version": version,
                 "mods": mods,
+                "mod_hashes": mod_hashes,
                 "creator_uuid": clean_uuid,
                 "creator_username": creator_username,
                 "downloads": 0
@@ -810,6 +1445,7 @@ impl SupabaseClient {
                         "name": name,
                         "version": version,
                         "mods": mods,
+                        "mod_hashes": mod_hashes,
                         "creator_uuid": clean_uuid,
                         "creator_username": creator_username,
                         "downloads": 0
@@ -831,15 +1467,13 @@ impl SupabaseClient {
         }
     }
 
-    /// Get a shared profile by its short code
-    pub async fn get_shared_profile(
+    /// Fetch a shared profile row by its short code, without incrementing
+    /// its download counter - used internally by callers that are checking
+    /// or modifying the row rather than handing it to a downloader.
+    async fn fetch_shared_profile_row(
         &self,
         short_code: &str,
     ) -> Result<Option<SharedProfile>, String> {
-        if !self.is_configured() {
-            return Err("Supabase not configured".to_string());
-        }
-
         let url = format!(
             "{}/rest/v1/shared_profiles?short_code=eq.{}",
             self.base_url,
@@ -866,12 +1500,129 @@ impl SupabaseClient {
             .await
             .map_err(|e| format!("Failed to parse shared profile: {}", e))?;
 
-        if let Some(profile) = profiles.into_iter().next() {
-            // Increment download count
-            let _ = self.increment_profile_downloads(&profile.id).await;
-            Ok(Some(profile))
+        Ok(profiles.into_iter().next())
+    }
+
+    /// Get a shared profile by its short code
+    pub async fn get_shared_profile(
+        &self,
+        short_code: &str,
+    ) -> Result<Option<SharedProfile>, String> {
+        if !self.is_configured() {
+            return Err("Supabase not configured".to_string());
+        }
+
+        match self.fetch_shared_profile_row(short_code).await? {
+            Some(profile) => {
+                // Increment download count
+                let _ = self.increment_profile_downloads(&profile.id).await;
+                Ok(Some(profile))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Push a new version of an already-shared profile under the same
+    /// `short_code`, turning the one-shot share into a living profile
+    /// followers can stay in sync with. Rejects the update if
+    /// `creator_uuid` doesn't match whoever originally shared it, or if
+    /// `new_version` isn't actually newer than what's stored.
+    pub async fn update_shared_profile(
+        &self,
+        short_code: &str,
+        new_version: &str,
+        new_mods: &[String],
+        new_mod_hashes: &[String],
+        creator_uuid: &str,
+    ) -> Result<ShareProfileResult, String> {
+        if !self.is_configured() {
+            return Ok(ShareProfileResult {
+                success: false,
+                short_code: None,
+                message: "Supabase not configured".to_string(),
+            });
+        }
+
+        let existing = self
+            .fetch_shared_profile_row(short_code)
+            .await?
+            .ok_or_else(|| format!("Profile with code '{}' not found", short_code))?;
+
+        let clean_uuid = creator_uuid.replace("-", "").to_lowercase();
+        if existing.creator_uuid.as_deref() != Some(clean_uuid.as_str()) {
+            return Ok(ShareProfileResult {
+                success: false,
+                short_code: None,
+                message: "Only the original creator can update this shared profile".to_string(),
+            });
+        }
+
+        if !is_newer_version(new_version, &existing.version) {
+            return Ok(ShareProfileResult {
+                success: false,
+                short_code: None,
+                message: format!(
+                    "New version {} is not newer than the shared version {}",
+                    new_version, existing.version
+                ),
+            });
+        }
+
+        let url = format!(
+            "{}/rest/v1/shared_profiles?short_code=eq.{}",
+            self.base_url,
+            short_code.to_uppercase()
+        );
+
+        let response = self
+            .client
+            .patch(&url)
+            .header("apikey", &self.api_key)
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .header("Content-Type", "application/json")
+            .json(&serde_json::json!({
+                "version": new_version,
+                "mods": new_mods,
+                "mod_hashes": new_mod_hashes,
+                "updated_at": chrono::Utc::now().to_rfc3339()
+            }))
+            .send()
+            .await
+            .map_err(|e| format!("Failed to update shared profile: {}", e))?;
+
+        if response.status().is_success() {
+            Ok(ShareProfileResult {
+                success: true,
+                short_code: Some(short_code.to_uppercase()),
+                message: "Shared profile updated successfully".to_string(),
+            })
         } else {
-            Ok(None)
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            Err(format!(
+                "Failed to update shared profile: {} - {}",
+                status, body
+            ))
+        }
+    }
+
+    /// Tell a downloader whether the shared profile they imported (at
+    /// `installed_version`) has a newer version available, without
+    /// incrementing the download counter the way `get_shared_profile` does.
+    pub async fn check_profile_update(
+        &self,
+        short_code: &str,
+        installed_version: &str,
+    ) -> Result<Option<SharedProfile>, String> {
+        if !self.is_configured() {
+            return Ok(None);
+        }
+
+        match self.fetch_shared_profile_row(short_code).await? {
+            Some(profile) if is_newer_version(&profile.version, installed_version) => {
+                Ok(Some(profile))
+            }
+            _ => Ok(None),
         }
     }
 
@@ -901,30 +1652,105 @@ impl SupabaseClient {
     }
 }
 
-/// Compare two semver-style versions
-/// Returns true if `latest` is newer than `current`
+/// Compare two semver-style versions by precedence.
+/// Returns true if `latest` is newer than `current`.
 fn is_newer_version(latest: &str, current: &str) -> bool {
-    let parse_version = |v: &str| -> Vec<u32> {
-        v.split('.')
-            .filter_map(|part| part.parse::<u32>().ok())
-            .collect()
-    };
+    compare_versions(latest, current) == std::cmp::Ordering::Greater
+}
+
+/// Split a version into its numeric `major.minor.patch...` core and, if
+/// present, its dot-separated pre-release identifiers (the part after `-`).
+/// Build metadata (after `+`) carries no precedence per semver and is
+/// dropped entirely.
+fn split_version(v: &str) -> (Vec<u32>, Option<Vec<String>>) {
+    let without_build = v.split('+').next().unwrap_or(v);
+    let mut parts = without_build.splitn(2, '-');
+    let core = parts.next().unwrap_or("");
+    let pre_release = parts.next();
+
+    let core_parts = core
+        .split('.')
+        .map(|part| part.parse::<u32>().unwrap_or(0))
+        .collect();
+
+    let pre_release_parts =
+        pre_release.map(|p| p.split('.').map(|s| s.to_string()).collect());
+
+    (core_parts, pre_release_parts)
+}
 
-    let latest_parts = parse_version(latest);
-    let current_parts = parse_version(current);
+/// Full semver precedence: numeric core first, then pre-release (a version
+/// with a pre-release tag has lower precedence than the same version
+/// without one), per the semver spec.
+pub(crate) fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
 
-    for i in 0..latest_parts.len().max(current_parts.len()) {
-        let l = latest_parts.get(i).copied().unwrap_or(0);
-        let c = current_parts.get(i).copied().unwrap_or(0);
+    let (a_core, a_pre) = split_version(a);
+    let (b_core, b_pre) = split_version(b);
 
-        if l > c {
-            return true;
-        } else if l < c {
-            return false;
+    let core_ord = compare_core(&a_core, &b_core);
+    if core_ord != Ordering::Equal {
+        return core_ord;
+    }
+
+    match (&a_pre, &b_pre) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a_ids), Some(b_ids)) => compare_prerelease_identifiers(a_ids, b_ids),
+    }
+}
+
+/// Compare `major.minor.patch...` numerically, position by position, with a
+/// missing trailing component treated as `0` so `"1.2"` and `"1.2.0"` are
+/// equal.
+fn compare_core(a: &[u32], b: &[u32]) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    for i in 0..a.len().max(b.len()) {
+        let x = a.get(i).copied().unwrap_or(0);
+        let y = b.get(i).copied().unwrap_or(0);
+        match x.cmp(&y) {
+            Ordering::Equal => continue,
+            other => return other,
+        }
+    }
+    Ordering::Equal
+}
+
+/// Compare dot-separated pre-release identifiers left to right: numeric
+/// identifiers compare numerically, alphanumeric ones compare in ASCII
+/// order, a numeric identifier always has lower precedence than an
+/// alphanumeric one, and if all preceding identifiers are equal, the longer
+/// list has higher precedence (e.g. `rc.1.1` > `rc.1`).
+fn compare_prerelease_identifiers(a: &[String], b: &[String]) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    for i in 0..a.len().max(b.len()) {
+        match (a.get(i), b.get(i)) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) => {
+                let ord = compare_identifier(x, y);
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+            }
         }
     }
+    Ordering::Equal
+}
 
-    false
+fn compare_identifier(x: &str, y: &str) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    match (x.parse::<u64>(), y.parse::<u64>()) {
+        (Ok(xn), Ok(yn)) => xn.cmp(&yn),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => x.cmp(y),
+    }
 }
 
 #[cfg(test)]
@@ -940,4 +1766,31 @@ mod tests {
         assert!(!is_newer_version("1.0.0", "1.0.1"));
         assert!(!is_newer_version("0.9.9", "1.0.0"));
     }
+
+    #[test]
+    fn test_prerelease_ordering() {
+        // A pre-release is lower precedence than the same version without one.
+        assert!(!is_newer_version("1.2.0-beta.1", "1.2.0"));
+        assert!(is_newer_version("1.2.0", "1.2.0-beta.1"));
+
+        // Numeric pre-release identifiers compare numerically, not lexically.
+        assert!(is_newer_version("1.0.0-rc.10", "1.0.0-rc.2"));
+        assert!(!is_newer_version("1.0.0-rc.2", "1.0.0-rc.10"));
+
+        // Numeric identifiers always sort below alphanumeric ones.
+        assert!(is_newer_version("1.0.0-beta", "1.0.0-1"));
+
+        // Alphanumeric identifiers compare in ASCII order.
+        assert!(is_newer_version("1.0.0-beta", "1.0.0-alpha"));
+
+        // A longer identifier list wins if all preceding fields are equal.
+        assert!(is_newer_version("1.0.0-alpha.1", "1.0.0-alpha"));
+
+        // Build metadata never affects ordering.
+        assert!(!is_newer_version("1.0.0+build.5", "1.0.0+build.1"));
+        assert_eq!(
+            compare_versions("1.0.0+build.5", "1.0.0+build.1"),
+            std::cmp::Ordering::Equal
+        );
+    }
 }
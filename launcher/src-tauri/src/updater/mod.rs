@@ -1,9 +1,12 @@
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::Path;
 use thiserror::Error;
 
 // This would be your update server URL
 const UPDATE_CHECK_URL: &str = "https://api.miracle.gg/updates/check";
+const MOD_VERSIONS_URL: &str = "https://api.miracle.gg/updates/mods";
 
 #[derive(Error, Debug)]
 pub enum UpdateError {
@@ -11,6 +14,14 @@ pub enum UpdateError {
     HttpError(#[from] reqwest::Error),
     #[error("Update failed: {0}")]
     UpdateFailed(String),
+    #[error("I/O error: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("Hash mismatch for {url}: expected {expected}, got {actual}")]
+    HashMismatch {
+        url: String,
+        expected: String,
+        actual: String,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +39,17 @@ pub struct ModUpdate {
     pub current_version: String,
     pub latest_version: String,
     pub download_url: String,
+    pub sha256: String,
+}
+
+/// A single mod entry from the CDN manifest (`/api/updates/mods`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CdnModVersion {
+    pub mod_id: String,
+    pub version: String,
+    pub minecraft_version: String,
+    pub download_url: String,
+    pub sha256: String,
 }
 
 pub struct UpdateManager {
@@ -56,13 +78,91 @@ impl UpdateManager {
         })
     }
 
-    /// Check for mod updates
+    /// Check for mod updates by comparing installed (mod_id, version) pairs
+    /// against the CDN manifest served by the Miracle backend.
     pub async fn check_mod_updates(
         &self,
         installed_mods: &[(&str, &str)],
     ) -> Result<Vec<ModUpdate>, UpdateError> {
-        // In a real implementation, this would check your mod distribution server
-        Ok(Vec::new())
+        let manifest = self.fetch_cdn_manifest().await?;
+
+        let updates = installed_mods
+            .iter()
+            .filter_map(|(mod_id, current_version)| {
+                let cdn_mod = manifest.iter().find(|m| m.mod_id == *mod_id)?;
+                if cdn_mod.version == *current_version {
+                    return None;
+                }
+                Some(ModUpdate {
+                    mod_id: cdn_mod.mod_id.clone(),
+                    current_version: current_version.to_string(),
+                    latest_version: cdn_mod.version.clone(),
+                    download_url: cdn_mod.download_url.clone(),
+                    sha256: cdn_mod.sha256.clone(),
+                })
+            })
+            .collect();
+
+        Ok(updates)
+    }
+
+    /// Fetch the list of mods the Miracle backend currently distributes, with
+    /// their verified SHA-256 digests.
+    pub async fn fetch_cdn_manifest(&self) -> Result<Vec<CdnModVersion>, UpdateError> {
+        let response = self.client.get(MOD_VERSIONS_URL).send().await?;
+
+        if !response.status().is_success() {
+            return Err(UpdateError::UpdateFailed(format!(
+                "Failed to fetch mod manifest: HTTP {}",
+                response.status()
+            )));
+        }
+
+        Ok(response.json().await?)
+    }
+
+    /// Download a file and verify it against its expected SHA-256 digest
+    /// before it's ever written to disk, so a corrupted or tampered jar can
+    /// never land in `dest`.
+    pub async fn download_verified(
+        &self,
+        download_url: &str,
+        dest: &Path,
+        expected_sha256: &str,
+    ) -> Result<(), UpdateError> {
+        let response = self.client.get(download_url).send().await?;
+
+        if !response.status().is_success() {
+            return Err(UpdateError::UpdateFailed(format!(
+                "Download failed: HTTP {}",
+                response.status()
+            )));
+        }
+
+        let bytes = response.bytes().await?;
+
+        let mut hasher = Sha256::new();
+        hasher.update(&bytes);
+        let actual = format!("{:x}", hasher.finalize());
+
+        if !actual.eq_ignore_ascii_case(expected_sha256) {
+            return Err(UpdateError::HashMismatch {
+                url: download_url.to_string(),
+                expected: expected_sha256.to_string(),
+                actual,
+            });
+        }
+
+        if let Some(parent) = dest.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        if let Err(e) = tokio::fs::write(dest, &bytes).await {
+            let _ = tokio::fs::remove_file(dest).await;
+            return Err(UpdateError::IoError(e));
+        }
+
+        Ok(())
     }
 
     /// Download and apply launcher update